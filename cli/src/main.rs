@@ -14,10 +14,12 @@
 mod page;
 mod permissions;
 mod registries;
+mod run;
 mod summary;
 mod tabs;
 mod tasks;
 mod types;
+mod verify;
 
 use std::{
     fs,
@@ -27,7 +29,7 @@
     path::PathBuf,
 };
 
-use anstream::println;
+use anstream::stdout;
 use clap::Parser;
 use components::Components;
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
@@ -55,6 +57,7 @@
 };
 use regex::Regex;
 use registries::RegistryEntries;
+use run::RunEntries;
 use summary::Summary;
 use tabs::TabManager;
 use tasks::Tasks;
@@ -70,12 +73,20 @@ struct Args {
     #[arg(short, long)]
     debug: bool,
 
+    /// Include passwords and serial numbers in the debug representation instead of redacting them
+    #[arg(long, requires = "debug")]
+    reveal_secrets: bool,
+
     /// Extract files to the given directory
     #[arg(short, long)]
     extract: Option<PathBuf>,
 
     #[arg(short, long)]
     filter: Option<Regex>,
+
+    /// Extract every file and verify its checksum
+    #[arg(long)]
+    verify: bool,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -85,10 +96,14 @@ fn main() -> anyhow::Result<()> {
     let mut inno = Inno::new(&mut file)?;
 
     if args.debug {
-        println!("{:#?}", inno.inner);
+        inno.write_debug_with(&mut stdout().lock(), args.reveal_secrets)?;
         return Ok(());
     }
 
+    if args.verify {
+        return verify::verify(&mut inno);
+    }
+
     if let Some(ref destination) = args.extract {
         fs::create_dir_all(destination)?;
 
@@ -235,7 +250,8 @@ fn scroll_up(&mut self) {
             Page::Registry(registries) => registries.previous_row(),
             Page::DeleteInstall(delete_installs) => delete_installs.previous_row(),
             Page::DeleteUninstall(delete_uninstalls) => delete_uninstalls.previous_row(),
-            _ => {}
+            Page::RunInstall(run_installs) => run_installs.previous_row(),
+            Page::RunUninstall(run_uninstalls) => run_uninstalls.previous_row(),
         }
     }
 
@@ -256,7 +272,8 @@ fn scroll_down(&mut self) {
             Page::Registry(registries) => registries.next_row(),
             Page::DeleteInstall(delete_installs) => delete_installs.next_row(),
             Page::DeleteUninstall(delete_uninstalls) => delete_uninstalls.next_row(),
-            _ => {}
+            Page::RunInstall(run_installs) => run_installs.next_row(),
+            Page::RunUninstall(run_uninstalls) => run_uninstalls.next_row(),
         }
     }
 }
@@ -287,7 +304,8 @@ fn render(self, area: Rect, buf: &mut Buffer)
             Page::Registry(registries) => registries.render(inner_area, buf),
             Page::DeleteInstall(delete_installs) => delete_installs.render(inner_area, buf),
             Page::DeleteUninstall(delete_uninstalls) => delete_uninstalls.render(inner_area, buf),
-            _ => {}
+            Page::RunInstall(run_installs) => run_installs.render(inner_area, buf),
+            Page::RunUninstall(run_uninstalls) => run_uninstalls.render(inner_area, buf),
         }
         footer().render(footer_area, buf);
     }
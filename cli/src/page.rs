@@ -7,7 +7,7 @@
 
 use super::{
     Components, DeleteEntries, Directories, FileLocations, Files, Icons, IniFiles, Languages,
-    Messages, Permissions, RegistryEntries, Summary, Tasks, Types,
+    Messages, Permissions, RegistryEntries, RunEntries, Summary, Tasks, Types,
 };
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -26,8 +26,8 @@ pub enum Page<'a> {
     Registry(RegistryEntries<'a>),
     DeleteInstall(DeleteEntries<'a>),
     DeleteUninstall(DeleteEntries<'a>),
-    RunInstall,
-    RunUninstall,
+    RunInstall(RunEntries<'a>),
+    RunUninstall(RunEntries<'a>),
     FileLocations(FileLocations<'a>),
 }
 
@@ -49,8 +49,8 @@ pub const fn title(&self) -> &'static str {
             Self::Registry(_) => "Registry",
             Self::DeleteInstall(_) => "Delete (Install)",
             Self::DeleteUninstall(_) => "Delete (Uninstall)",
-            Self::RunInstall => "Run (Install)",
-            Self::RunUninstall => "Run (Uninstall)",
+            Self::RunInstall(_) => "Run (Install)",
+            Self::RunUninstall(_) => "Run (Uninstall)",
             Self::FileLocations(_) => "File Locations",
         }
     }
@@ -68,6 +68,8 @@ pub const fn is_empty(&self) -> bool {
             Self::Registry(registries) => registries.is_empty(),
             Self::DeleteInstall(delete_installs) => delete_installs.is_empty(),
             Self::DeleteUninstall(delete_uninstalls) => delete_uninstalls.is_empty(),
+            Self::RunInstall(run_installs) => run_installs.is_empty(),
+            Self::RunUninstall(run_uninstalls) => run_uninstalls.is_empty(),
             _ => false,
         }
     }
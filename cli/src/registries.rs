@@ -111,7 +111,7 @@ fn render(self, area: Rect, buf: &mut Buffer)
                 Cow::Borrowed(registry.permissions().unwrap_or_default()),
                 Cow::Borrowed(registry.registry_root().as_str()),
                 Cow::Owned(registry.permission().to_string()),
-                Cow::Borrowed(registry.r#type().as_str()),
+                Cow::Borrowed(registry.r#type().as_reg_type()),
                 Cow::Owned(registry.flags().to_string()),
             ])
         });
@@ -165,7 +165,7 @@ fn render(self, area: Rect, buf: &mut Buffer)
             PERMISSION,
         )),
         Max(strings_constraint(
-            registries.iter().map(|entry| entry.r#type().as_str()),
+            registries.iter().map(|entry| entry.r#type().as_reg_type()),
             TYPE,
         )),
         Max(flags_constraint(
@@ -0,0 +1,217 @@
+use std::borrow::Cow;
+
+use inno::entry::RunEntry;
+use ratatui::{
+    buffer::Buffer,
+    layout::{
+        Alignment, Constraint,
+        Constraint::{Fill, Length, Max, Min},
+        Layout, Rect,
+    },
+    prelude::{Modifier, StatefulWidget, Style, Widget},
+    style::palette::tailwind::SKY,
+    widgets::{
+        Block, BorderType, Padding, Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Table,
+        TableState,
+    },
+};
+
+use super::constraint::{int_constraint, strings_constraint};
+
+const NAME: &str = "Name";
+const PARAMETERS: &str = "Parameters";
+const WORKING_DIRECTORY: &str = "Working directory";
+const DESCRIPTION: &str = "Description";
+const WAIT_CONDITION: &str = "Wait condition";
+const FLAGS: &str = "Flags";
+
+const HEADERS: [&str; 7] = [
+    "#",
+    NAME,
+    PARAMETERS,
+    WORKING_DIRECTORY,
+    DESCRIPTION,
+    WAIT_CONDITION,
+    FLAGS,
+];
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RunEntries<'a> {
+    run_entries: &'a [RunEntry],
+    state: TableState,
+    scroll_state: ScrollbarState,
+    constraints: [Constraint; 7],
+    title: &'static str,
+}
+
+impl<'a> RunEntries<'a> {
+    pub fn new_install(run_entries: &'a [RunEntry]) -> Self {
+        Self::new(run_entries, "Run (Install)")
+    }
+
+    pub fn new_uninstall(run_entries: &'a [RunEntry]) -> Self {
+        Self::new(run_entries, "Run (Uninstall)")
+    }
+
+    fn new(run_entries: &'a [RunEntry], title: &'static str) -> Self {
+        Self {
+            run_entries,
+            state: TableState::new().with_selected(0),
+            scroll_state: ScrollbarState::new(run_entries.len()),
+            constraints: constraints(run_entries),
+            title,
+        }
+    }
+
+    pub fn next_row(&mut self) {
+        let index = self
+            .state
+            .selected()
+            .filter(|&index| index < self.run_entries.len())
+            .map(|index| index + 1)
+            .unwrap_or_default();
+
+        self.state.select(Some(index));
+        self.scroll_state = self.scroll_state.position(index);
+    }
+
+    pub fn previous_row(&mut self) {
+        let index = self
+            .state
+            .selected()
+            .map(|index| index.saturating_sub(1))
+            .unwrap_or_default();
+
+        self.state.select(Some(index));
+        self.scroll_state = self.scroll_state.position(index);
+    }
+
+    /// Returns `true` if there are no run entries.
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.run_entries.is_empty()
+    }
+}
+
+impl Widget for &mut RunEntries<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        let layout = Layout::horizontal([Fill(1), Length(1)]).split(area);
+
+        StatefulWidget::render(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight),
+            layout[1],
+            buf,
+            &mut self.scroll_state,
+        );
+
+        let rows = self
+            .run_entries
+            .iter()
+            .enumerate()
+            .map(|(index, run_entry)| {
+                Row::new([
+                    Cow::Owned((index + 1).to_string()),
+                    Cow::Borrowed(run_entry.name().unwrap_or_default()),
+                    Cow::Borrowed(run_entry.parameters().unwrap_or_default()),
+                    Cow::Borrowed(run_entry.working_directory().unwrap_or_default()),
+                    Cow::Borrowed(run_entry.description().unwrap_or_default()),
+                    Cow::Borrowed(run_entry.wait_condition().as_str()),
+                    Cow::Owned(run_entry.options().to_string()),
+                ])
+            });
+
+        StatefulWidget::render(
+            Table::new(rows, self.constraints)
+                .header(Row::new(HEADERS).style(Style::new().add_modifier(Modifier::BOLD)))
+                .column_spacing(2)
+                .block(
+                    Block::bordered()
+                        .title(self.title)
+                        .title_alignment(Alignment::Center)
+                        .border_type(BorderType::Rounded)
+                        .padding(Padding::proportional(1)),
+                )
+                .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED).fg(SKY.c400)),
+            layout[0],
+            buf,
+            &mut self.state,
+        );
+    }
+}
+
+fn constraints(run_entries: &[RunEntry]) -> [Constraint; 7] {
+    [
+        Length(int_constraint(run_entries.len())),
+        Max(strings_constraint(
+            run_entries.iter().map(RunEntry::name),
+            NAME,
+        )),
+        Max(strings_constraint(
+            run_entries.iter().map(RunEntry::parameters),
+            PARAMETERS,
+        )),
+        Max(strings_constraint(
+            run_entries.iter().map(RunEntry::working_directory),
+            WORKING_DIRECTORY,
+        )),
+        Max(strings_constraint(
+            run_entries.iter().map(RunEntry::description),
+            DESCRIPTION,
+        )),
+        Max(WAIT_CONDITION.len() as u16),
+        Min(FLAGS.len() as u16),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use inno::entry::RunEntry;
+    use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+    use rstest::rstest;
+
+    use super::RunEntries;
+
+    #[rstest]
+    #[case::install(false, 2, "Run (Install)")]
+    #[case::uninstall(true, 3, "Run (Uninstall)")]
+    fn render(#[case] uninstall: bool, #[case] count: usize, #[case] title: &str) {
+        let run_entries = vec![RunEntry::default(); count];
+        let mut page = if uninstall {
+            RunEntries::new_uninstall(&run_entries)
+        } else {
+            RunEntries::new_install(&run_entries)
+        };
+
+        let area = Rect::new(0, 0, 80, 12);
+        let mut buf = Buffer::empty(area);
+        page.render(area, &mut buf);
+
+        let lines = (0..area.height)
+            .map(|y| {
+                (0..area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+
+        // Each entry is a row numbered from 1
+        let numbers = lines
+            .iter()
+            .filter_map(|line| {
+                line.trim_start_matches(['│', ' '])
+                    .split_whitespace()
+                    .next()?
+                    .parse::<usize>()
+                    .ok()
+            })
+            .collect::<Vec<_>>();
+
+        assert!(!page.is_empty());
+        assert!(lines[0].contains(title), "{:?}", lines[0]);
+        assert_eq!(numbers, (1..=count).collect::<Vec<_>>());
+    }
+}
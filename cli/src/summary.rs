@@ -28,8 +28,8 @@ pub struct Summary<'a> {
 
 impl<'a> Summary<'a> {
     #[must_use]
-    pub fn new(header: &'a Header, version: InnoVersion) -> Self {
-        let rows = rows(header, version);
+    pub fn new(header: &'a Header, version: InnoVersion, loader_signature: String) -> Self {
+        let rows = rows(header, version, loader_signature);
         Self {
             header,
             version,
@@ -63,11 +63,17 @@ pub fn previous_row(&mut self) {
     }
 }
 
-fn rows(header: &'_ Header, version: InnoVersion) -> Vec<Row<'_>> {
-    let mut rows = Vec::from([Row::new([
-        Cow::Borrowed("Inno Setup version"),
-        Cow::Owned(version.to_string()),
-    ])]);
+fn rows(header: &'_ Header, version: InnoVersion, loader_signature: String) -> Vec<Row<'_>> {
+    let mut rows = Vec::from([
+        Row::new([
+            Cow::Borrowed("Inno Setup version"),
+            Cow::Owned(version.to_string()),
+        ]),
+        Row::new([
+            Cow::Borrowed("Loader signature"),
+            Cow::Owned(loader_signature),
+        ]),
+    ]);
     if let Some(name) = header.app_name() {
         rows.push(Row::new(["App name", name]));
     }
@@ -105,7 +111,16 @@ fn rows(header: &'_ Header, version: InnoVersion) -> Vec<Row<'_>> {
         rows.push(Row::new(["Default group name", default_group_name]));
     }
     if let Some(icon_name) = header.uninstall_icon_name() {
-        rows.push(Row::new(["Uninstall icon", icon_name]));
+        rows.push(Row::new(["Uninstall icon name", icon_name]));
+    }
+    if let Some(files_dir) = header.uninstall_files_dir() {
+        rows.push(Row::new(["Uninstall files directory", files_dir]));
+    }
+    if let Some(uninstall_name) = header.uninstall_name() {
+        rows.push(Row::new(["Uninstall display name", uninstall_name]));
+    }
+    if let Some(uninstall_icon) = header.uninstall_icon() {
+        rows.push(Row::new(["Uninstall display icon", uninstall_icon]));
     }
     if let Some(mutex) = header.app_mutex() {
         rows.push(Row::new(["Mutex", mutex]));
@@ -207,6 +222,13 @@ fn rows(header: &'_ Header, version: InnoVersion) -> Vec<Row<'_>> {
         ]));
     }
 
+    if let Some(opacity) = header.wizard_back_image_opacity() {
+        rows.push(Row::new([
+            Cow::Borrowed("Wizard background image opacity"),
+            Cow::Owned(opacity.to_string()),
+        ]));
+    }
+
     rows.push(Row::new([
         Cow::Borrowed("Wizard small image dark background color"),
         Cow::Owned(header.small_image_dynamic_background_color().to_string()),
@@ -219,7 +241,7 @@ fn rows(header: &'_ Header, version: InnoVersion) -> Vec<Row<'_>> {
 
     rows.push(Row::new([
         Cow::Borrowed("Slices per disk"),
-        Cow::Owned(header.extra_disk_space_required().to_string()),
+        Cow::Owned(header.slices_per_disk().to_string()),
     ]));
 
     rows.push(Row::new([
@@ -243,8 +265,8 @@ fn rows(header: &'_ Header, version: InnoVersion) -> Vec<Row<'_>> {
         header.privileges_required().as_str(),
     ]));
     rows.push(Row::new([
-        "Privileges required overrides",
-        header.privileges_required().as_str(),
+        Cow::Borrowed("Privileges required overrides"),
+        Cow::Owned(header.privileges_required_overrides_allowed().to_string()),
     ]));
     rows.push(Row::new([
         "Show language dialog",
@@ -315,3 +337,40 @@ fn render(self, area: Rect, buf: &mut Buffer)
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use inno::Inno;
+    use ratatui::widgets::Row;
+    use rstest::rstest;
+
+    use super::rows;
+
+    #[rstest]
+    #[case::version("Inno Setup version", "5.5.0 (u)")]
+    #[case::loader_signature("Loader signature", "rDlPtS")]
+    #[case::app_name("App name", "Fixture")]
+    #[case::app_version("Version", "1.0")]
+    #[case::versioned_name("Versioned name", "Fixture 1.0")]
+    #[case::publisher("Publisher", "inno-rs")]
+    #[case::default_directory("Default directory", r"{pf}\Fixture")]
+    #[case::extra_disk_space("Extra disk space required", "1048576")]
+    #[case::slices_per_disk("Slices per disk", "1")]
+    fn row(#[case] name: &str, #[case] value: &str) {
+        let file = File::open(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/core/tests/fixtures/synthetic-5.5.0-unicode.exe"
+        ))
+        .unwrap();
+        let inno = Inno::new(file).unwrap();
+
+        let rows = rows(inno.header(), inno.version(), String::from("rDlPtS"));
+
+        assert!(
+            rows.contains(&Row::new([name, value])),
+            "no {name} row with {value}"
+        );
+    }
+}
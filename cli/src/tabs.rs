@@ -9,7 +9,7 @@
     widgets::{Tabs, Widget},
 };
 
-use super::{DeleteEntries, Messages, Page, Summary};
+use super::{DeleteEntries, Messages, Page, RunEntries, Summary};
 
 pub struct TabManager<'a> {
     pages: Vec<Page<'a>>,
@@ -20,7 +20,17 @@ impl<'a> TabManager<'a> {
     /// Creates a new View Tab manager.
     #[must_use]
     pub fn new<R: Read + Seek>(inno: &'a Inno<R>) -> Self {
-        let mut pages = vec![Page::Header(Summary::new(inno.header(), inno.version()))];
+        let signature = inno.setup_loader().signature();
+        let loader_signature = match signature.known_name() {
+            Some(name) => format!("{} ({name})", signature.as_str()),
+            None => signature.as_str(),
+        };
+
+        let mut pages = vec![Page::Header(Summary::new(
+            inno.header(),
+            inno.version(),
+            loader_signature,
+        ))];
 
         let languages = inno.languages();
         if !languages.is_empty() {
@@ -90,7 +100,21 @@ pub fn new<R: Read + Seek>(inno: &'a Inno<R>) -> Self {
 
         let uninstall_delete_entries = inno.uninstall_delete_entries();
         if !uninstall_delete_entries.is_empty() {
-            pages.push(DeleteEntries::new_uninstall(uninstall_delete_entries).into());
+            pages.push(Page::DeleteUninstall(DeleteEntries::new_uninstall(
+                uninstall_delete_entries,
+            )));
+        }
+
+        let run_entries = inno.run_entries();
+        if !run_entries.is_empty() {
+            pages.push(Page::RunInstall(RunEntries::new_install(run_entries)));
+        }
+
+        let uninstall_run_entries = inno.uninstall_run_entries();
+        if !uninstall_run_entries.is_empty() {
+            pages.push(Page::RunUninstall(RunEntries::new_uninstall(
+                uninstall_run_entries,
+            )));
         }
 
         Self {
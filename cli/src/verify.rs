@@ -0,0 +1,94 @@
+use std::io::{Read, Seek};
+
+use anstream::println;
+use anyhow::bail;
+use indicatif::{ProgressBar, ProgressStyle};
+use inno::{Inno, entry::file::FileFlags, error::InnoError};
+
+use super::emoji::Emoji;
+
+/// Extracts every file and verifies its checksum, printing a report of the files that failed.
+///
+/// Files in encrypted chunks can't be read without a password, so they are reported as skipped.
+///
+/// Returns an error if any file failed verification, or if no file could be verified because
+/// every file is encrypted.
+pub fn verify<R: Read + Seek>(inno: &mut Inno<R>) -> anyhow::Result<()> {
+    let skipped = inno
+        .file_entries()
+        .iter()
+        .filter(|file| file.flags().contains(FileFlags::DONT_VERIFY_CHECKSUM))
+        .count();
+
+    let encrypted = inno
+        .file_entries()
+        .iter()
+        .filter(|file| !file.flags().contains(FileFlags::DONT_VERIFY_CHECKSUM))
+        .filter(|file| {
+            inno.location_of(file)
+                .is_some_and(|location| location.chunk().is_encrypted())
+        })
+        .count();
+
+    let pb = ProgressBar::new(0)
+        .with_style(
+            ProgressStyle::default_bar()
+                .template("{spinner:.cyan} [{bar:40.green/black}] {pos}/{len} {msg}")?
+                .progress_chars("───"),
+        )
+        .with_message("Verifying files");
+
+    let results = inno.verify_all_with_progress(|done, total| {
+        pb.set_length(total as u64);
+        pb.set_position(done as u64);
+    });
+
+    pb.finish_and_clear();
+
+    // Encrypted chunks can't be read, so their files are skipped rather than failed
+    let results = results
+        .into_iter()
+        .filter(|(_, result)| !matches!(result, Err(InnoError::Encrypted)))
+        .collect::<Vec<_>>();
+    let total = results.len();
+
+    let mut failed = 0;
+    for (index, result) in results {
+        if let Err(err) = result {
+            let file = &inno.file_entries()[index];
+            // Print the whole chain of causes, such as the I/O error behind a decompression error
+            println!(
+                "{} {}: {:#}",
+                false.emoji(),
+                file.destination().unwrap_or_default(),
+                anyhow::Error::from(err)
+            );
+            failed += 1;
+        }
+    }
+
+    println!(
+        "{} {} of {} files passed",
+        (failed == 0).emoji(),
+        total - failed,
+        total
+    );
+
+    if skipped > 0 {
+        println!("Skipped {skipped} files with the dontverifychecksum flag");
+    }
+
+    if encrypted > 0 {
+        println!("Skipped {encrypted} files in encrypted chunks");
+    }
+
+    if failed > 0 {
+        bail!("{failed} files failed verification");
+    }
+
+    if total == 0 && encrypted > 0 {
+        bail!("No files were verified as every file is encrypted");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+//! Compares borrowing fixed-layout structures from a byte slice with [`BorrowBytesExt`] against
+//! copying them out with the default [`ReadBytesExt`] path.
+//!
+//! ```sh
+//! cargo bench -p inno --bench borrow
+//! ```
+
+use divan::{Bencher, black_box};
+use inno::{
+    BorrowBytesExt, ReadBytesExt,
+    entry::checksum::Sha256,
+    header::Color,
+    pe::{CoffHeader, SectionHeader},
+};
+
+fn main() {
+    divan::main();
+}
+
+/// Returns a buffer of `count` values of `T`, offset by one byte so that nothing is aligned.
+fn buffer<T>(count: usize) -> Vec<u8> {
+    (0..=u8::MAX)
+        .cycle()
+        .take(1 + count * size_of::<T>())
+        .collect()
+}
+
+#[divan::bench(args = [16, 1024])]
+fn read_section_headers(bencher: Bencher, count: usize) {
+    let buffer = buffer::<SectionHeader>(count);
+
+    bencher.bench(|| {
+        let mut data = &buffer[1..];
+        (0..count)
+            .map(|_| data.read_t::<SectionHeader>())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    });
+}
+
+#[divan::bench(args = [16, 1024])]
+fn borrow_section_headers(bencher: Bencher, count: usize) {
+    let buffer = buffer::<SectionHeader>(count);
+
+    bencher.bench(|| {
+        let mut data = &buffer[1..];
+        black_box(data.borrow_slice::<SectionHeader>(count).unwrap())
+    });
+}
+
+#[divan::bench(args = [16, 1024])]
+fn read_checksums(bencher: Bencher, count: usize) {
+    let buffer = buffer::<Sha256>(count);
+
+    bencher.bench(|| {
+        let mut data = &buffer[1..];
+        (0..count)
+            .map(|_| data.read_t::<Sha256>())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    });
+}
+
+#[divan::bench(args = [16, 1024])]
+fn borrow_checksums(bencher: Bencher, count: usize) {
+    let buffer = buffer::<Sha256>(count);
+
+    bencher.bench(|| {
+        let mut data = &buffer[1..];
+        black_box(data.borrow_slice::<Sha256>(count).unwrap())
+    });
+}
+
+#[divan::bench]
+fn read_coff_header_and_color(bencher: Bencher) {
+    let buffer = buffer::<(CoffHeader, Color)>(1);
+
+    bencher.bench(|| {
+        let mut data = &buffer[1..];
+        (
+            data.read_t::<CoffHeader>().unwrap(),
+            data.read_t::<Color>().unwrap(),
+        )
+    });
+}
+
+#[divan::bench]
+fn borrow_coff_header_and_color(bencher: Bencher) {
+    let buffer = buffer::<(CoffHeader, Color)>(1);
+
+    bencher.bench(|| {
+        let mut data = &buffer[1..];
+        black_box((
+            data.borrow_t::<CoffHeader>().unwrap(),
+            data.borrow_t::<Color>().unwrap(),
+        ))
+    });
+}
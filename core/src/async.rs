@@ -0,0 +1,35 @@
+use std::io::{self, Cursor};
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
+
+use crate::{Inno, error::InnoResult};
+
+impl Inno<Cursor<Vec<u8>>> {
+    /// Parses an installer from the given asynchronous reader using the default parse options.
+    ///
+    /// Parsing an installer seeks back and forth throughout the file, which would need an await
+    /// point for every seek with an asynchronous reader. Instead, the whole installer is read into
+    /// memory asynchronously, and then parsed synchronously on a blocking task with
+    /// [`spawn_blocking`](tokio::task::spawn_blocking) so that parsing does not block the async
+    /// runtime. The reader is rewound to the start before it is read.
+    ///
+    /// This must be called from within a Tokio runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Inno::new`], and an I/O error if the reader could not be read
+    /// or the blocking task panicked.
+    pub async fn new_async<R>(mut reader: R) -> InnoResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        reader.rewind().await?;
+
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data).await?;
+
+        tokio::task::spawn_blocking(move || Self::new(Cursor::new(data)))
+            .await
+            .map_err(io::Error::other)?
+    }
+}
@@ -0,0 +1,182 @@
+use std::io::{Read, Seek};
+
+use encoding_rs::Encoding;
+
+use crate::{Inno, InnoSummary, ParsePhase, error::InnoResult, string::StringKind};
+
+/// A builder for configuring how an installer is parsed.
+///
+/// ```no_run
+/// use std::fs::File;
+/// use inno::{InnoBuilder, error::InnoResult};
+///
+/// fn main() -> InnoResult<()> {
+///     let file = File::open("path/to/setup.exe")?;
+///     let inno = InnoBuilder::new()
+///         .force_codepage(encoding_rs::SHIFT_JIS)
+///         .build(file)?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct InnoBuilder {
+    codepage: Option<&'static Encoding>,
+    kind_codepages: [Option<&'static Encoding>; StringKind::COUNT],
+    strict: bool,
+    tolerate_truncation: bool,
+}
+
+impl InnoBuilder {
+    /// Creates a new builder with the default parse options.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            codepage: None,
+            kind_codepages: [None; StringKind::COUNT],
+            strict: false,
+            tolerate_truncation: false,
+        }
+    }
+
+    /// Forces the codepage used to decode ANSI strings, overriding the codepage detected from the
+    /// language entries.
+    ///
+    /// This has no effect on Unicode installers, whose strings are always UTF-16LE.
+    #[must_use]
+    #[inline]
+    pub const fn force_codepage(mut self, codepage: &'static Encoding) -> Self {
+        self.codepage = Some(codepage);
+        self
+    }
+
+    /// Returns the forced codepage, if any.
+    #[must_use]
+    #[inline]
+    pub const fn codepage(&self) -> Option<&'static Encoding> {
+        self.codepage
+    }
+
+    /// Forces the codepage used to decode one kind of string, overriding both the detected
+    /// codepage and any codepage set with [`force_codepage`](Self::force_codepage).
+    ///
+    /// This is for the rare installers whose strings don't all share the same codepage, such as
+    /// when the license text was saved in a different encoding to the rest of the script. Only
+    /// [`StringKind::InfoText`] has an effect on Unicode installers, as the other strings are
+    /// always UTF-16LE.
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use inno::{InnoBuilder, error::InnoResult, string::StringKind};
+    ///
+    /// fn main() -> InnoResult<()> {
+    ///     let file = File::open("path/to/setup.exe")?;
+    ///     let inno = InnoBuilder::new()
+    ///         .force_codepage_for(StringKind::InfoText, encoding_rs::WINDOWS_1251)
+    ///         .build(file)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn force_codepage_for(
+        mut self,
+        kind: StringKind,
+        codepage: &'static Encoding,
+    ) -> Self {
+        self.kind_codepages[kind as usize] = Some(codepage);
+        self
+    }
+
+    /// Returns the codepage forced for the given kind of string with
+    /// [`force_codepage_for`](Self::force_codepage_for), if any.
+    ///
+    /// This doesn't include the codepage forced with [`force_codepage`](Self::force_codepage).
+    #[must_use]
+    #[inline]
+    pub const fn codepage_for(&self, kind: StringKind) -> Option<&'static Encoding> {
+        self.kind_codepages[kind as usize]
+    }
+
+    /// Sets whether to reject installers whose secondary header stream is padded with trailing
+    /// zeros.
+    ///
+    /// Some installers pad the end of the secondary header stream after the file locations. By
+    /// default, trailing bytes that are all zero are ignored and recorded in
+    /// [`Inno::warnings`](crate::Inno::warnings), and any other trailing data is rejected with
+    /// [`InnoError::UnexpectedExtraData`](crate::error::InnoError::UnexpectedExtraData). In strict
+    /// mode, the padding is rejected as well, as is a stream
+    /// [truncated](Self::tolerate_truncation) before all of its file locations.
+    #[must_use]
+    #[inline]
+    pub const fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Returns `true` if [strict mode](Self::strict) is enabled.
+    #[must_use]
+    #[inline]
+    pub const fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Sets whether to accept installers whose secondary header stream ends before all of the file
+    /// locations declared by the header.
+    ///
+    /// By default, a stream that ends before the declared number of file locations is rejected
+    /// with [`InnoError::EntryCountMismatch`](crate::error::InnoError::EntryCountMismatch). When
+    /// tolerated, a stream that ends exactly between two file locations is parsed with the file
+    /// locations that it contains and recorded in [`Inno::warnings`](crate::Inno::warnings),
+    /// unless [strict mode](Self::strict) is enabled. A stream that ends partway through a file
+    /// location is always rejected.
+    #[must_use]
+    #[inline]
+    pub const fn tolerate_truncation(mut self, tolerate_truncation: bool) -> Self {
+        self.tolerate_truncation = tolerate_truncation;
+        self
+    }
+
+    /// Returns `true` if a [truncated](Self::tolerate_truncation) secondary header stream is
+    /// accepted.
+    #[must_use]
+    #[inline]
+    pub const fn tolerates_truncation(&self) -> bool {
+        self.tolerate_truncation
+    }
+
+    /// Parses the installer from the given reader using the configured options.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Inno::new`].
+    pub fn build<R: Read + Seek>(self, reader: R) -> InnoResult<Inno<R>> {
+        Inno::read_with(reader, self, &mut |_, _, _| {})
+    }
+
+    /// Parses the installer from the given reader using the configured options, reporting
+    /// progress to the given callback.
+    ///
+    /// See [`Inno::open_with_progress`] for when the callback is invoked.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Inno::new`].
+    pub fn build_with_progress<R, P>(self, reader: R, mut progress: P) -> InnoResult<Inno<R>>
+    where
+        R: Read + Seek,
+        P: FnMut(ParsePhase, u32, u32),
+    {
+        Inno::read_with(reader, self, &mut progress)
+    }
+
+    /// Parses only the header and languages of the installer into an [`InnoSummary`], like
+    /// [`Inno::parse_summary_only`], decoding its strings with the configured codepages.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Inno::parse_summary_only`].
+    pub fn build_summary<R: Read + Seek>(self, reader: R) -> InnoResult<InnoSummary> {
+        Inno::read_summary_with(reader, self)
+    }
+}
@@ -0,0 +1,248 @@
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    io::{Read, Seek, SeekFrom},
+};
+
+use crate::{
+    Inno, encryption::EncryptionHeader, error::InnoResult, read::stream::InnoStreamReader,
+};
+
+/// Identifies the contents of an installer without reading all of it.
+///
+/// The key is made up of the length of the installer and a CRC32 of its setup loader's offset
+/// table and its compressed header streams. The header streams describe every file in the
+/// installer, including the checksum of its data, so installers with different contents have
+/// different keys. This is cheap to compute for large installers, as the header streams are read
+/// without being decompressed and the file data is not read at all.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct CacheKey {
+    len: u64,
+    header_crc32: u32,
+}
+
+impl CacheKey {
+    /// Computes the key of the installer in the given reader, then seeks the reader back to the
+    /// start.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Inno::new`] for the setup loader, the version and the headers
+    /// of the header streams, or an error if the reader could not be read or seeked.
+    pub fn from_reader<R>(mut reader: R) -> InnoResult<Self>
+    where
+        R: Read + Seek,
+    {
+        let len = reader.seek(SeekFrom::End(0))?;
+        reader.rewind()?;
+
+        let header_crc32 =
+            Inno::<R>::read_versioned(&mut reader, |reader, setup_loader, version| {
+                let mut hasher = crc32fast::Hasher::new();
+                for offset in [
+                    setup_loader.exe_offset(),
+                    setup_loader.header_offset(),
+                    setup_loader.data_offset(),
+                    i64::from(setup_loader.exe_compressed_size()),
+                    i64::from(setup_loader.exe_uncompressed_size()),
+                ] {
+                    hasher.update(&offset.to_le_bytes());
+                }
+                hasher.update(setup_loader.exe_checksum().to_string().as_bytes());
+
+                if version >= 6.5 {
+                    EncryptionHeader::read(&mut *reader, version)?;
+                }
+
+                // Find the end of the secondary stream by skipping both streams without decompressing
+                let primary = InnoStreamReader::new(&mut *reader, version)?;
+                let end = InnoStreamReader::new(primary.skip()?, version)?
+                    .skip()?
+                    .stream_position()?;
+
+                // Hash everything from the version string to the end of the secondary stream
+                let start = setup_loader.header_offset().unsigned_abs();
+                reader.seek(SeekFrom::Start(start))?;
+                let mut streams = Vec::new();
+                reader
+                    .take(end.saturating_sub(start))
+                    .read_to_end(&mut streams)?;
+                hasher.update(&streams);
+
+                Ok(hasher.finalize())
+            })?;
+
+        reader.rewind()?;
+
+        Ok(Self { len, header_crc32 })
+    }
+
+    /// Returns the length of the installer in bytes.
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns `true` if the installer is empty.
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// An in-memory cache of parsed installers, for tools that open the same installers repeatedly.
+///
+/// Installers are keyed by a [`CacheKey`], so an installer is only parsed the first time that its
+/// contents are seen.
+///
+/// ```no_run
+/// use std::fs::File;
+/// use inno::{CacheKey, InnoCache, error::InnoResult};
+///
+/// fn main() -> InnoResult<()> {
+///     let mut cache = InnoCache::new();
+///
+///     for _ in 0..2 {
+///         let mut file = File::open("path/to/setup.exe")?;
+///         let key = CacheKey::from_reader(&mut file)?;
+///         let inno = cache.get_or_parse(key, file)?;
+///         println!("{}", inno.version());
+///     }
+///
+///     assert_eq!(cache.len(), 1);
+///     Ok(())
+/// }
+/// ```
+pub struct InnoCache<R: Read + Seek> {
+    entries: HashMap<CacheKey, Inno<R>>,
+}
+
+impl<R: Read + Seek> InnoCache<R> {
+    /// Creates an empty cache.
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Returns the installer with the given key, parsing it from the reader if it is not already
+    /// cached.
+    ///
+    /// The reader is dropped without being read if the installer is already cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the installer is not cached and could not be parsed, in which case
+    /// nothing is added to the cache.
+    pub fn get_or_parse(&mut self, key: CacheKey, reader: R) -> InnoResult<&mut Inno<R>> {
+        match self.entries.entry(key) {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => Ok(entry.insert(Inno::new(reader)?)),
+        }
+    }
+
+    /// Returns the cached installer with the given key.
+    #[must_use]
+    #[inline]
+    pub fn get(&self, key: &CacheKey) -> Option<&Inno<R>> {
+        self.entries.get(key)
+    }
+
+    /// Removes the installer with the given key from the cache, returning it if it was cached.
+    #[inline]
+    pub fn remove(&mut self, key: &CacheKey) -> Option<Inno<R>> {
+        self.entries.remove(key)
+    }
+
+    /// Removes all installers from the cache.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the number of cached installers.
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no installers are cached.
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<R: Read + Seek> Default for InnoCache<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Seek};
+
+    use super::{CacheKey, InnoCache};
+    use crate::error::InnoError;
+
+    const FIXTURE: &[u8] = include_bytes!("../tests/fixtures/synthetic-5.5.0-unicode.exe");
+
+    #[test]
+    fn key() {
+        let mut reader = Cursor::new(FIXTURE);
+        let key = CacheKey::from_reader(&mut reader).unwrap();
+        assert_eq!(reader.stream_position().unwrap(), 0);
+        assert_eq!(key.len(), FIXTURE.len() as u64);
+
+        // The bytes before the setup loader's offset table are not part of the key
+        let mut changed_before_loader = FIXTURE.to_vec();
+        changed_before_loader[0] = 0xFF;
+        assert_eq!(
+            CacheKey::from_reader(Cursor::new(changed_before_loader)).unwrap(),
+            key
+        );
+
+        // A byte within the compressed primary header stream
+        let mut changed_in_stream = FIXTURE.to_vec();
+        changed_in_stream[0xD0] ^= 0xFF;
+        assert_ne!(
+            CacheKey::from_reader(Cursor::new(changed_in_stream)).unwrap(),
+            key
+        );
+
+        let mut appended = FIXTURE.to_vec();
+        appended.push(0);
+        assert_ne!(CacheKey::from_reader(Cursor::new(appended)).unwrap(), key);
+    }
+
+    #[test]
+    fn key_of_non_installer() {
+        assert!(matches!(
+            CacheKey::from_reader(Cursor::new(vec![0; 0x40])),
+            Err(InnoError::NotInnoFile)
+        ));
+    }
+
+    #[test]
+    fn parse_error_is_not_cached() {
+        let mut cache = InnoCache::new();
+        let reader = Cursor::new(vec![0; 0x40]);
+        let key = CacheKey {
+            len: 0x40,
+            header_crc32: 0,
+        };
+
+        assert!(matches!(
+            cache.get_or_parse(key, reader),
+            Err(InnoError::NotInnoFile)
+        ));
+        assert!(cache.is_empty());
+        assert!(cache.get(&key).is_none());
+    }
+}
@@ -0,0 +1,341 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+};
+
+use crate::{
+    InnoInner,
+    entry::{File, RegistryEntry},
+    iss::setup_directives,
+    version::InnoVersion,
+};
+
+/// A change to a single item between two installers.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Change<T> {
+    /// The item is only in the new installer.
+    Added(T),
+    /// The item is only in the old installer.
+    Removed(T),
+    /// The item is in both installers but differs between them.
+    Changed { old: T, new: T },
+}
+
+impl<T> Change<T> {
+    /// Maps the items of the change with the given function.
+    pub fn map<U, F>(self, mut f: F) -> Change<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        match self {
+            Self::Added(new) => Change::Added(f(new)),
+            Self::Removed(old) => Change::Removed(f(old)),
+            Self::Changed { old, new } => Change::Changed {
+                old: f(old),
+                new: f(new),
+            },
+        }
+    }
+
+    /// Returns the item from the old installer, if it was in the old installer.
+    #[must_use]
+    #[inline]
+    pub const fn before(&self) -> Option<&T> {
+        match self {
+            Self::Added(_) => None,
+            Self::Removed(old) | Self::Changed { old, .. } => Some(old),
+        }
+    }
+
+    /// Returns the item from the new installer, if it is in the new installer.
+    #[must_use]
+    #[inline]
+    pub const fn after(&self) -> Option<&T> {
+        match self {
+            Self::Removed(_) => None,
+            Self::Added(new) | Self::Changed { new, .. } => Some(new),
+        }
+    }
+}
+
+/// A structured report of the differences between two installers.
+///
+/// This struct is created by [`Inno::diff`](crate::Inno::diff).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InnoDiff<'inno> {
+    pub(crate) version: Option<Change<InnoVersion>>,
+    pub(crate) directives: Vec<(&'static str, Change<String>)>,
+    pub(crate) files: Vec<Change<&'inno File>>,
+    pub(crate) registry: Vec<Change<&'inno RegistryEntry>>,
+}
+
+impl<'inno> InnoDiff<'inno> {
+    pub(crate) fn new(old: &'inno InnoInner, new: &'inno InnoInner) -> Self {
+        let directives = |inner: &InnoInner| {
+            setup_directives(&inner.header)
+                .into_iter()
+                .map(|(name, value)| (name, value.clone(), (name, value)))
+                .collect::<Vec<_>>()
+        };
+
+        let files = |inner: &'inno InnoInner| {
+            inner
+                .files
+                .iter()
+                .filter_map(|file| {
+                    let destination = file.destination()?.to_ascii_lowercase().replace('/', "\\");
+                    let condition = file.condition();
+                    let key = (
+                        destination,
+                        condition.components(),
+                        condition.tasks(),
+                        condition.languages(),
+                        condition.check(),
+                    );
+                    let identity = usize::try_from(file.location())
+                        .ok()
+                        .and_then(|index| inner.file_locations.get(index))
+                        .map_or((None, file.external_size()), |location| {
+                            (
+                                Some(location.file().checksum()),
+                                location.uncompressed_size(),
+                            )
+                        });
+                    Some((key, identity, file))
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let registry = |inner: &'inno InnoInner| {
+            inner
+                .registry_entries
+                .iter()
+                .map(|entry| {
+                    let key = (
+                        entry.registry_root().as_str(),
+                        entry.key().unwrap_or_default().to_ascii_lowercase(),
+                        entry.name().unwrap_or_default().to_ascii_lowercase(),
+                    );
+                    let value = (entry.value(), entry.r#type(), entry.flags());
+                    (key, value, entry)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        Self {
+            version: (old.version != new.version).then_some(Change::Changed {
+                old: old.version,
+                new: new.version,
+            }),
+            directives: diff_keyed(directives(old), directives(new))
+                .into_iter()
+                .map(|change| {
+                    let name = change
+                        .before()
+                        .or(change.after())
+                        .map_or("", |(name, _)| *name);
+                    (name, change.map(|(_, value)| value))
+                })
+                .collect(),
+            files: diff_keyed(files(old), files(new)),
+            registry: diff_keyed(registry(old), registry(new)),
+        }
+    }
+
+    /// Returns the change in Inno Setup version, if the installers were built with different
+    /// versions.
+    #[must_use]
+    #[inline]
+    pub const fn version(&self) -> Option<Change<InnoVersion>> {
+        self.version
+    }
+
+    /// Returns the `[Setup]` directives that were added, removed or changed, by name.
+    #[must_use]
+    #[inline]
+    pub const fn directives(&self) -> &[(&'static str, Change<String>)] {
+        self.directives.as_slice()
+    }
+
+    /// Returns the files that were added, removed or changed.
+    ///
+    /// Files are matched by their destination, compared case-insensitively, and their components,
+    /// tasks, languages and check function, as a script can install different files to the same
+    /// destination under different conditions. Files that still share all of these are matched in
+    /// the order they appear in each installer. A file has changed if its checksum or size differs.
+    #[must_use]
+    #[inline]
+    pub const fn files(&self) -> &[Change<&'inno File>] {
+        self.files.as_slice()
+    }
+
+    /// Returns the registry entries that were added, removed or changed.
+    ///
+    /// Registry entries are matched by their root, key and value name, with the key and value name
+    /// compared case-insensitively. Entries that share these are matched in the order they appear in
+    /// each installer. An entry has changed if its value, type or flags differ.
+    #[must_use]
+    #[inline]
+    pub const fn registry(&self) -> &[Change<&'inno RegistryEntry>] {
+        self.registry.as_slice()
+    }
+
+    /// Returns `true` if no differences were found.
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.version.is_none()
+            && self.directives.is_empty()
+            && self.files.is_empty()
+            && self.registry.is_empty()
+    }
+}
+
+/// Diffs two lists of keyed items, where each item is paired with the value that is compared to
+/// determine whether it has changed.
+///
+/// Removed and changed items are returned in the order of the old list, followed by added items
+/// in the order of the new list. If multiple items share a key, they are paired in the order they
+/// appear in each list, and any that are left over in either list are removed or added.
+pub(crate) fn diff_keyed<K, V, T>(old: Vec<(K, V, T)>, new: Vec<(K, V, T)>) -> Vec<Change<T>>
+where
+    K: Eq + Hash,
+    V: PartialEq,
+    T: Clone,
+{
+    let mut new_indices = HashMap::<_, VecDeque<_>>::with_capacity(new.len());
+    for (index, (key, _, _)) in new.iter().enumerate() {
+        new_indices.entry(key).or_default().push_back(index);
+    }
+
+    let mut matched = vec![false; new.len()];
+    let mut changes = Vec::new();
+
+    for (key, value, item) in &old {
+        let Some(index) = new_indices.get_mut(key).and_then(VecDeque::pop_front) else {
+            changes.push(Change::Removed(item.clone()));
+            continue;
+        };
+
+        matched[index] = true;
+        let (_, new_value, new_item) = &new[index];
+        if new_value != value {
+            changes.push(Change::Changed {
+                old: item.clone(),
+                new: new_item.clone(),
+            });
+        }
+    }
+
+    for ((_, _, item), matched) in new.iter().zip(matched) {
+        if !matched {
+            changes.push(Change::Added(item.clone()));
+        }
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Change, InnoDiff, diff_keyed};
+    use crate::{
+        InnoInner,
+        entry::{Condition, File},
+    };
+
+    #[test]
+    fn keyed_changes() {
+        let old = vec![("a", 1, "a1"), ("b", 2, "b2"), ("c", 3, "c3")];
+        let new = vec![
+            ("c", 4, "c4"),
+            ("a", 1, "a1"),
+            ("d", 5, "d5"),
+            ("d", 6, "d6"),
+        ];
+
+        assert_eq!(
+            diff_keyed(old, new),
+            [
+                Change::Removed("b2"),
+                Change::Changed {
+                    old: "c3",
+                    new: "c4"
+                },
+                Change::Added("d5"),
+                Change::Added("d6"),
+            ]
+        );
+    }
+
+    #[test]
+    fn duplicate_keys() {
+        let old = vec![("a", 1, "a1"), ("a", 2, "a2"), ("a", 3, "a3")];
+        let new = vec![("a", 1, "a1"), ("a", 4, "a4")];
+
+        assert_eq!(
+            diff_keyed(old, new),
+            [
+                Change::Changed {
+                    old: "a2",
+                    new: "a4"
+                },
+                Change::Removed("a3"),
+            ]
+        );
+    }
+
+    /// Creates a file installed to the given destination for the given component.
+    fn file(destination: &str, components: &str, external_size: u64) -> File {
+        File {
+            destination: Some(destination.to_owned()),
+            condition: Condition {
+                components: Some(components.to_owned()),
+                ..Condition::default()
+            },
+            external_size,
+            ..File::default()
+        }
+    }
+
+    #[test]
+    fn duplicate_destinations() {
+        let old = InnoInner {
+            files: vec![
+                file("{app}\\app.dll", "x86", 1),
+                file("{app}\\app.dll", "x64", 2),
+            ],
+            ..InnoInner::default()
+        };
+        let new = InnoInner {
+            files: vec![
+                file("{app}\\app.dll", "x86", 1),
+                file("{app}\\App.dll", "x64", 3),
+                file("{app}\\app.dll", "arm64", 4),
+            ],
+            ..InnoInner::default()
+        };
+
+        let diff = InnoDiff::new(&old, &new);
+
+        assert_eq!(
+            diff.files(),
+            [
+                Change::Changed {
+                    old: &old.files[1],
+                    new: &new.files[1],
+                },
+                Change::Added(&new.files[2]),
+            ]
+        );
+    }
+
+    #[test]
+    fn map() {
+        let change = Change::Changed { old: 1, new: 2 }.map(|value| value * 10);
+
+        assert_eq!(change.before(), Some(&10));
+        assert_eq!(change.after(), Some(&20));
+        assert_eq!(Change::Added(1).before(), None);
+    }
+}
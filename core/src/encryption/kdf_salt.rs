@@ -1,9 +1,11 @@
 use std::array::TryFromSliceError;
 
-use zerocopy::{FromBytes, Immutable, KnownLayout};
+use zerocopy::{FromBytes, Immutable, KnownLayout, Unaligned};
 
 /// <https://github.com/jrsoftware/issrc/blob/is-6_5_1/Projects/Src/Shared.Struct.pas#L70>
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, FromBytes, Immutable, KnownLayout)]
+#[derive(
+    Clone, Copy, Debug, Default, Eq, PartialEq, FromBytes, Immutable, KnownLayout, Unaligned,
+)]
 #[repr(transparent)]
 pub struct KDFSalt([u8; 16]);
 
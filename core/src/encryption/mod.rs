@@ -3,7 +3,7 @@
 mod nonce;
 mod reader;
 
-use std::io::Read;
+use std::{fmt, io::Read};
 
 pub use encryption_use::EncryptionUse;
 pub use kdf_salt::KDFSalt;
@@ -15,18 +15,19 @@
     entry::checksum::ChecksumMismatchError,
     error::{InnoError, InnoResult},
     read::{ReadBytesExt, crc32::Crc32Reader},
+    secret::SecretValue,
     version::InnoVersion,
 };
 
 /// <https://github.com/jrsoftware/issrc/blob/is-6_5_1/Projects/Src/Shared.Struct.pas#L90>
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Copy, Default, Eq, PartialEq)]
 #[repr(C)]
 pub struct EncryptionHeader {
-    encryption_use: EncryptionUse,
-    kdf_salt: KDFSalt,
-    kdf_iterations: u32,
-    base_nonce: EncryptionNonce,
-    password_test: u32,
+    pub(crate) encryption_use: EncryptionUse,
+    pub(crate) kdf_salt: KDFSalt,
+    pub(crate) kdf_iterations: u32,
+    pub(crate) base_nonce: EncryptionNonce,
+    pub(crate) password_test: u32,
 }
 
 impl EncryptionHeader {
@@ -71,7 +72,7 @@ pub fn read<R>(mut reader: R, version: InnoVersion) -> InnoResult<Self>
         if crc32 != actual_crc32 {
             return Err(InnoError::ChecksumMismatch {
                 location: "Encryption header",
-                inner: ChecksumMismatchError::new_adler32(crc32, actual_crc32),
+                inner: ChecksumMismatchError::new_crc32(crc32, actual_crc32),
             });
         }
 
@@ -108,3 +109,41 @@ pub const fn password_test(&self) -> u32 {
         self.password_test
     }
 }
+
+impl fmt::Debug for EncryptionHeader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EncryptionHeader")
+            .field("encryption_use", &self.encryption_use)
+            .field("kdf_salt", &SecretValue(self.kdf_salt))
+            .field("kdf_iterations", &self.kdf_iterations)
+            .field("base_nonce", &self.base_nonce)
+            .field("password_test", &SecretValue(self.password_test))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EncryptionHeader, KDFSalt};
+    use crate::secret::Revealed;
+
+    #[test]
+    fn debug_redacts_salt_and_password_test() {
+        let header = EncryptionHeader {
+            kdf_salt: KDFSalt::new([0xAB; 16]),
+            kdf_iterations: 220_000,
+            password_test: 0x1234_5678,
+            ..EncryptionHeader::default()
+        };
+
+        let debug = format!("{header:?}");
+        assert!(debug.contains("kdf_salt: <redacted>"));
+        assert!(debug.contains("password_test: <redacted>"));
+        assert!(debug.contains("kdf_iterations: 220000"));
+        assert!(!debug.contains("171"));
+
+        let revealed = format!("{:?}", Revealed(&header));
+        assert!(revealed.contains("KDFSalt([171"));
+        assert!(revealed.contains("password_test: 305419896"));
+    }
+}
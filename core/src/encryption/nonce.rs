@@ -1,9 +1,9 @@
 use std::fmt;
 
-use zerocopy::{FromBytes, Immutable, KnownLayout, LE, U32, U64};
+use zerocopy::{FromBytes, Immutable, KnownLayout, LE, U32, U64, Unaligned};
 
 /// <https://github.com/jrsoftware/issrc/blob/is-6_5_1/Projects/Src/Shared.Struct.pas#L72>
-#[derive(Clone, Copy, Default, Eq, PartialEq, FromBytes, Immutable, KnownLayout)]
+#[derive(Clone, Copy, Default, Eq, PartialEq, FromBytes, Immutable, KnownLayout, Unaligned)]
 #[repr(C)]
 pub struct EncryptionNonce {
     random_xor_start_offset: U64<LE>,
@@ -0,0 +1,175 @@
+use std::iter::FusedIterator;
+
+use crate::{
+    InnoInner,
+    entry::{
+        Component, DeleteEntry, Directory, File, FileLocation, ISSigKey, Icon, Ini, Language,
+        MessageEntry, Permission, RegistryEntry, RunEntry, Task, Type,
+    },
+};
+
+/// An entry from any section of an installer.
+///
+/// Entries that share a type across sections, such as [`DeleteEntry`] and [`RunEntry`], have a
+/// variant for each section so that they can be told apart.
+#[derive(Clone, Copy, Debug)]
+pub enum Entry<'entry> {
+    Language(&'entry Language),
+    Message(&'entry MessageEntry),
+    Permission(&'entry Permission),
+    Type(&'entry Type),
+    Component(&'entry Component),
+    Task(&'entry Task),
+    Directory(&'entry Directory),
+    ISSigKey(&'entry ISSigKey),
+    File(&'entry File),
+    Icon(&'entry Icon),
+    Ini(&'entry Ini),
+    Registry(&'entry RegistryEntry),
+    InstallDelete(&'entry DeleteEntry),
+    UninstallDelete(&'entry DeleteEntry),
+    Run(&'entry RunEntry),
+    UninstallRun(&'entry RunEntry),
+    FileLocation(&'entry FileLocation),
+}
+
+impl Entry<'_> {
+    /// Returns the name of the section that the entry belongs to, as it would appear in an Inno
+    /// Setup script.
+    ///
+    /// Entries that have no corresponding script section, such as file locations, return a
+    /// descriptive name instead.
+    #[must_use]
+    pub const fn section(&self) -> &'static str {
+        match self {
+            Self::Language(_) => "Languages",
+            Self::Message(_) => "CustomMessages",
+            Self::Permission(_) => "Permissions",
+            Self::Type(_) => "Types",
+            Self::Component(_) => "Components",
+            Self::Task(_) => "Tasks",
+            Self::Directory(_) => "Dirs",
+            Self::ISSigKey(_) => "ISSigKeys",
+            Self::File(_) => "Files",
+            Self::Icon(_) => "Icons",
+            Self::Ini(_) => "INI",
+            Self::Registry(_) => "Registry",
+            Self::InstallDelete(_) => "InstallDelete",
+            Self::UninstallDelete(_) => "UninstallDelete",
+            Self::Run(_) => "Run",
+            Self::UninstallRun(_) => "UninstallRun",
+            Self::FileLocation(_) => "FileLocations",
+        }
+    }
+}
+
+/// An iterator over every entry in an installer.
+///
+/// Entries are yielded section by section, in the order that the sections are stored in the
+/// installer.
+///
+/// This struct is created by [`Inno::entries`](crate::Inno::entries).
+#[derive(Clone, Debug)]
+pub struct Entries<'inno> {
+    inner: &'inno InnoInner,
+    section: usize,
+    index: usize,
+}
+
+impl<'inno> Entries<'inno> {
+    pub(crate) const fn new(inner: &'inno InnoInner) -> Self {
+        Self {
+            inner,
+            section: 0,
+            index: 0,
+        }
+    }
+
+    fn get(&self) -> Option<Option<Entry<'inno>>> {
+        let inner = self.inner;
+        let index = self.index;
+
+        Some(match self.section {
+            0 => inner.languages.get(index).map(Entry::Language),
+            1 => inner.messages.get(index).map(Entry::Message),
+            2 => inner.permissions.get(index).map(Entry::Permission),
+            3 => inner.type_entries.get(index).map(Entry::Type),
+            4 => inner.components.get(index).map(Entry::Component),
+            5 => inner.tasks.get(index).map(Entry::Task),
+            6 => inner.directories.get(index).map(Entry::Directory),
+            7 => inner.is_sig_keys.get(index).map(Entry::ISSigKey),
+            8 => inner.files.get(index).map(Entry::File),
+            9 => inner.icons.get(index).map(Entry::Icon),
+            10 => inner.ini_entries.get(index).map(Entry::Ini),
+            11 => inner.registry_entries.get(index).map(Entry::Registry),
+            12 => inner.delete_entries.get(index).map(Entry::InstallDelete),
+            13 => inner
+                .uninstall_delete_entries
+                .get(index)
+                .map(Entry::UninstallDelete),
+            14 => inner.run_entries.get(index).map(Entry::Run),
+            15 => inner
+                .uninstall_run_entries
+                .get(index)
+                .map(Entry::UninstallRun),
+            16 => inner.file_locations.get(index).map(Entry::FileLocation),
+            _ => return None,
+        })
+    }
+}
+
+impl<'inno> Iterator for Entries<'inno> {
+    type Item = Entry<'inno>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(entry) = self.get()? {
+                self.index += 1;
+                return Some(entry);
+            }
+
+            self.section += 1;
+            self.index = 0;
+        }
+    }
+}
+
+impl FusedIterator for Entries<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::Entries;
+    use crate::{
+        InnoInner,
+        entry::{File, FileLocation, Language, RunEntry},
+    };
+
+    #[rstest]
+    #[case::empty(InnoInner::default(), vec![])]
+    #[case::skips_empty_sections(
+        InnoInner {
+            languages: vec![Language::default()],
+            files: vec![File::default(), File::default()],
+            uninstall_run_entries: vec![RunEntry::default()],
+            file_locations: vec![FileLocation::default()],
+            ..InnoInner::default()
+        },
+        vec!["Languages", "Files", "Files", "UninstallRun", "FileLocations"]
+    )]
+    fn sections(#[case] inner: InnoInner, #[case] expected: Vec<&str>) {
+        let mut entries = Entries::new(&inner);
+
+        assert_eq!(
+            entries
+                .by_ref()
+                .map(|entry| entry.section())
+                .collect::<Vec<_>>(),
+            expected
+        );
+
+        // The iterator is fused, so it stays exhausted
+        assert!(entries.next().is_none());
+    }
+}
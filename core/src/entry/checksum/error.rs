@@ -2,17 +2,22 @@
 
 use super::{Checksum, Md5, Sha1, Sha256};
 
+/// A checksum that did not match the data it was calculated from.
+///
+/// This only describes the checksums, as the algorithm and what was being checked are described by
+/// the [`InnoError::ChecksumMismatch`](crate::error::InnoError::ChecksumMismatch) that it is the
+/// source of.
 #[derive(Error, Debug)]
 pub enum ChecksumMismatchError {
-    #[error("Adler32 checksum mismatch. Expected {expected} but calculated {actual}")]
+    #[error("expected {expected:#010x}, got {actual:#010x}")]
     Adler32 { expected: u32, actual: u32 },
-    #[error("CRC-32 checksum mismatch. Expected {expected} but calculated {actual}")]
+    #[error("expected {expected:#010x}, got {actual:#010x}")]
     Crc32 { expected: u32, actual: u32 },
-    #[error("MD5 checksum mismatch. Expected {expected} but calculated {actual}")]
+    #[error("expected {expected}, got {actual}")]
     Md5 { expected: Md5, actual: Md5 },
-    #[error("SHA-1 checksum mismatch. Expected {expected} but calculated {actual}")]
+    #[error("expected {expected}, got {actual}")]
     Sha1 { expected: Sha1, actual: Sha1 },
-    #[error("SHA-256 checksum mismatch. Expected {expected} but calculated {actual}")]
+    #[error("expected {expected}, got {actual}")]
     Sha256 { expected: Sha256, actual: Sha256 },
 }
 
@@ -61,6 +66,18 @@ pub(crate) const fn new_crc32(expected: u32, actual: u32) -> Self {
         }
     }
 
+    /// Returns the name of the checksum algorithm, such as `CRC-32`.
+    #[must_use]
+    pub const fn algorithm(&self) -> &'static str {
+        match self {
+            Self::Adler32 { .. } => "Adler-32",
+            Self::Crc32 { .. } => "CRC-32",
+            Self::Md5 { .. } => "MD5",
+            Self::Sha1 { .. } => "SHA-1",
+            Self::Sha256 { .. } => "SHA-256",
+        }
+    }
+
     /// Returns the expected checksum.
     #[must_use]
     pub const fn expected(&self) -> Checksum {
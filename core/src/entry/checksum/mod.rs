@@ -14,7 +14,7 @@
 
 use crate::read::ReadBytesExt;
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Checksum {
     Adler32(u32),
     Crc32(u32),
@@ -1,9 +1,9 @@
 use core::fmt;
 use std::array::TryFromSliceError;
 
-use zerocopy::{FromBytes, Immutable, KnownLayout};
+use zerocopy::{FromBytes, Immutable, KnownLayout, Unaligned};
 
-#[derive(Clone, Copy, Eq, PartialEq, FromBytes, Immutable, KnownLayout)]
+#[derive(Clone, Copy, Eq, PartialEq, Hash, FromBytes, Immutable, KnownLayout, Unaligned)]
 #[repr(transparent)]
 pub struct Sha1([u8; 20]);
 
@@ -1,12 +1,12 @@
 use core::fmt;
 use std::array::TryFromSliceError;
 
-use zerocopy::{FromBytes, Immutable, KnownLayout};
+use zerocopy::{FromBytes, Immutable, KnownLayout, Unaligned};
 
 /// The length of a SHA-256 hash in bytes.
 const SHA256_LEN: usize = 256 / u8::BITS as usize;
 
-#[derive(Clone, Copy, Eq, PartialEq, FromBytes, Immutable, KnownLayout)]
+#[derive(Clone, Copy, Eq, PartialEq, Hash, FromBytes, Immutable, KnownLayout, Unaligned)]
 #[repr(transparent)]
 pub struct Sha256([u8; SHA256_LEN]);
 
@@ -146,6 +146,63 @@ pub const fn flags(&self) -> ComponentFlags {
     pub const fn size(&self) -> u64 {
         self.size
     }
+
+    /// Returns an iterator over the names of the setup types that the component belongs to.
+    pub fn type_names(&self) -> impl Iterator<Item = &str> {
+        self.types
+            .as_deref()
+            .unwrap_or_default()
+            .split(|char: char| char == ',' || char.is_whitespace())
+            .filter(|name| !name.is_empty())
+    }
+
+    /// Returns `true` if the component is checked by default when the given setup type is
+    /// selected, or `false` if there is no setup type.
+    ///
+    /// A component is checked when the setup type is one of its [types](Self::type_names),
+    /// compared case-insensitively. The `fixed`, `exclusive` and `dontinheritcheck` flags only
+    /// affect how the user can change the selection, so they do not change the default.
+    ///
+    /// This only considers the component itself. In the wizard, a parent component is also checked
+    /// if any of its subcomponents are, which
+    /// [`Inno::default_components`](crate::Inno::default_components) takes into account.
+    #[must_use]
+    pub fn default_checked(&self, default_type: Option<&str>) -> bool {
+        default_type.is_some_and(|default_type| {
+            self.type_names()
+                .any(|name| name.eq_ignore_ascii_case(default_type))
+        })
+    }
+
+    /// Returns whether each of the components is checked by default when the given setup type is
+    /// selected, including parent components that are checked because one of their subcomponents
+    /// is.
+    ///
+    /// Components are stored with each parent before its subcomponents, which are one
+    /// [level](Self::level) deeper.
+    pub(crate) fn default_selection(components: &[Self], default_type: Option<&str>) -> Vec<bool> {
+        let mut checked = components
+            .iter()
+            .map(|component| component.default_checked(default_type))
+            .collect::<Vec<_>>();
+
+        // Iterate in reverse so that checking a parent propagates to its own parent in turn.
+        for index in (0..components.len()).rev() {
+            let level = components[index].level;
+            if !checked[index] || level == 0 {
+                continue;
+            }
+
+            if let Some(parent) = components[..index]
+                .iter()
+                .rposition(|component| component.level < level)
+            {
+                checked[parent] = true;
+            }
+        }
+
+        checked
+    }
 }
 
 impl Default for Component {
@@ -181,3 +238,49 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         bitflags::parser::to_writer(self, f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::Component;
+
+    fn component(name: &str, types: &str, level: u32) -> Component {
+        Component {
+            name: Some(name.to_owned()),
+            types: Some(types.to_owned()),
+            level,
+            ..Component::default()
+        }
+    }
+
+    #[rstest]
+    #[case(Some("full"), true)]
+    #[case(Some("Compact"), true)]
+    #[case(Some("custom"), false)]
+    #[case(None, false)]
+    fn default_checked(#[case] default_type: Option<&str>, #[case] expected: bool) {
+        let component = component("program", "full,compact", 0);
+
+        assert_eq!(component.default_checked(default_type), expected);
+    }
+
+    #[rstest]
+    #[case::full("full", &[true, true, true, true, true])]
+    #[case::compact("compact", &[true, false, false, true, true])]
+    #[case::custom("custom", &[false, false, false, false, false])]
+    fn default_selection(#[case] default_type: &str, #[case] expected: &[bool]) {
+        let components = [
+            component("program", "full compact", 0),
+            component("help", "full", 0),
+            component(r"help\english", "full", 1),
+            component("plugins", "full", 0),
+            component(r"plugins\core", "full compact", 1),
+        ];
+
+        assert_eq!(
+            Component::default_selection(&components, Some(default_type)),
+            expected
+        );
+    }
+}
@@ -1,17 +1,25 @@
-use std::io;
+use std::{io, iter::Peekable};
 
 use encoding_rs::Encoding;
 
 use crate::{read::ReadBytesExt, version::InnoVersion};
 
-#[derive(Clone, Debug, Default)]
+/// The conditions under which an entry is installed, shared by most entry types.
+///
+/// These are the `Components`, `Tasks`, `Languages`, `Check`, `BeforeInstall` and `AfterInstall`
+/// parameters of the entry in the script. The components and tasks are boolean expressions of
+/// names, such as `main and (help or not docs)`, which can be evaluated with
+/// [`evaluate`](Self::evaluate).
+///
+/// <https://jrsoftware.org/ishelp/index.php?topic=scriptcommonparams>
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Condition {
-    components: Option<String>,
-    tasks: Option<String>,
-    languages: Option<String>,
-    check: Option<String>,
-    after_install: Option<String>,
-    before_install: Option<String>,
+    pub(crate) components: Option<String>,
+    pub(crate) tasks: Option<String>,
+    pub(crate) languages: Option<String>,
+    pub(crate) check: Option<String>,
+    pub(crate) after_install: Option<String>,
+    pub(crate) before_install: Option<String>,
 }
 
 impl Condition {
@@ -49,11 +57,21 @@ pub fn read<R>(
         Ok(condition)
     }
 
+    /// Returns the components expression as a string slice.
+    #[doc(alias = "Components")]
+    #[must_use]
+    #[inline]
+    pub fn components(&self) -> Option<&str> {
+        self.components.as_deref()
+    }
+
     /// Returns the components as a string slice.
+    ///
+    /// This is the same as [`components`](Self::components).
     #[must_use]
     #[inline]
     pub fn as_str(&self) -> Option<&str> {
-        self.components.as_deref()
+        self.components()
     }
 
     /// Returns the tasks as a string slice.
@@ -90,4 +108,200 @@ pub fn after_install(&self) -> Option<&str> {
     pub fn before_install(&self) -> Option<&str> {
         self.before_install.as_deref()
     }
+
+    /// Returns `true` if the entry would be installed with the given selected components and
+    /// tasks, and the given language.
+    ///
+    /// The components and tasks expressions support `and`, `or`, `not` and parentheses, and names
+    /// separated only by whitespace are treated as if they were separated by `or`. The languages
+    /// are a list of language names, of which the given language must be one. A missing or empty
+    /// expression or list is always satisfied, and all names are compared case-insensitively.
+    ///
+    /// The `Check`, `BeforeInstall` and `AfterInstall` parameters call Pascal script functions, so
+    /// they can't be evaluated and are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inno::entry::Condition;
+    ///
+    /// let condition = Condition::default();
+    ///
+    /// assert!(condition.evaluate(&["main"], &[], "english"));
+    /// ```
+    #[must_use]
+    pub fn evaluate(&self, components: &[&str], tasks: &[&str], language: &str) -> bool {
+        self.matches_components(components)
+            && matches_expression(self.tasks(), tasks)
+            && self.languages().is_none_or(|languages| {
+                let mut languages = languages.split_whitespace().peekable();
+                languages.peek().is_none()
+                    || languages.any(|name| name.eq_ignore_ascii_case(language))
+            })
+    }
+
+    /// Returns `true` if the components expression is satisfied by the given selected
+    /// components.
+    ///
+    /// An empty or missing expression is always satisfied. Component names are compared
+    /// case-insensitively.
+    pub(crate) fn matches_components(&self, components: &[&str]) -> bool {
+        matches_expression(self.components(), components)
+    }
+}
+
+/// Returns `true` if the given components or tasks expression is satisfied by the given selected
+/// names, or if there is no expression.
+fn matches_expression(expression: Option<&str>, selected: &[&str]) -> bool {
+    expression.is_none_or(|expression| {
+        expression.trim().is_empty()
+            || evaluate(expression, |name| {
+                selected
+                    .iter()
+                    .any(|selected| selected.eq_ignore_ascii_case(name))
+            })
+    })
+}
+
+/// Evaluates a components or tasks expression, such as `main and (help or not docs)`.
+///
+/// Names separated only by whitespace are treated as if they were separated by `or`, as Inno
+/// Setup allowed plain lists of names before boolean expressions were supported.
+fn evaluate<F>(expression: &str, mut is_selected: F) -> bool
+where
+    F: FnMut(&str) -> bool,
+{
+    let mut tokens = tokenize(expression).peekable();
+    parse_or(&mut tokens, &mut is_selected)
+}
+
+fn tokenize(expression: &str) -> impl Iterator<Item = &str> {
+    expression
+        .split_whitespace()
+        .flat_map(|word| word.split_inclusive(['(', ')']))
+        .flat_map(|word| match word.strip_suffix(['(', ')']) {
+            Some(name) if !name.is_empty() => [Some(name), Some(&word[name.len()..])],
+            _ => [Some(word), None],
+        })
+        .flatten()
+}
+
+fn parse_or<'a, I, F>(tokens: &mut Peekable<I>, is_selected: &mut F) -> bool
+where
+    I: Iterator<Item = &'a str>,
+    F: FnMut(&str) -> bool,
+{
+    let mut result = parse_and(tokens, is_selected);
+
+    while let Some(&token) = tokens.peek() {
+        if token == ")" {
+            break;
+        }
+
+        if token.eq_ignore_ascii_case("or") {
+            tokens.next();
+        }
+
+        let rhs = parse_and(tokens, is_selected);
+        result = result || rhs;
+    }
+
+    result
+}
+
+fn parse_and<'a, I, F>(tokens: &mut Peekable<I>, is_selected: &mut F) -> bool
+where
+    I: Iterator<Item = &'a str>,
+    F: FnMut(&str) -> bool,
+{
+    let mut result = parse_not(tokens, is_selected);
+
+    while tokens
+        .next_if(|token| token.eq_ignore_ascii_case("and"))
+        .is_some()
+    {
+        let rhs = parse_not(tokens, is_selected);
+        result = result && rhs;
+    }
+
+    result
+}
+
+fn parse_not<'a, I, F>(tokens: &mut Peekable<I>, is_selected: &mut F) -> bool
+where
+    I: Iterator<Item = &'a str>,
+    F: FnMut(&str) -> bool,
+{
+    match tokens.next() {
+        Some(token) if token.eq_ignore_ascii_case("not") => !parse_not(tokens, is_selected),
+        Some("(") => {
+            let result = parse_or(tokens, is_selected);
+            tokens.next_if_eq(&")");
+            result
+        }
+        Some(")") | None => false,
+        Some(name) => is_selected(name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{Condition, evaluate};
+
+    #[rstest]
+    #[case("main", &["main"], true)]
+    #[case("MAIN", &["main"], true)]
+    #[case("help", &["main"], false)]
+    #[case("main help", &["help"], true)]
+    #[case("main or help", &["help"], true)]
+    #[case("main and help", &["help"], false)]
+    #[case("main and help", &["main", "help"], true)]
+    #[case("not main", &["main"], false)]
+    #[case("main and not help", &["main"], true)]
+    #[case("(main or help) and docs", &["help", "docs"], true)]
+    #[case("main and (help or docs)", &["main"], false)]
+    #[case("not (main or help)", &[], true)]
+    #[case(r"main\help", &[r"main\help"], true)]
+    fn evaluate_expression(
+        #[case] expression: &str,
+        #[case] selected: &[&str],
+        #[case] expected: bool,
+    ) {
+        assert_eq!(
+            evaluate(expression, |name| selected
+                .iter()
+                .any(|selected| selected.eq_ignore_ascii_case(name))),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case::unconditional(None, None, None, true)]
+    #[case::component(Some("main"), None, None, true)]
+    #[case::missing_component(Some("help"), None, None, false)]
+    #[case::task(None, Some("desktopicon and not quicklaunch"), None, true)]
+    #[case::missing_task(None, Some("quicklaunch"), None, false)]
+    #[case::language(None, None, Some("dutch English"), true)]
+    #[case::other_language(None, None, Some("dutch german"), false)]
+    #[case::empty(Some(""), Some(" "), Some(""), true)]
+    fn evaluate_condition(
+        #[case] components: Option<&str>,
+        #[case] tasks: Option<&str>,
+        #[case] languages: Option<&str>,
+        #[case] expected: bool,
+    ) {
+        let condition = Condition {
+            components: components.map(str::to_owned),
+            tasks: tasks.map(str::to_owned),
+            languages: languages.map(str::to_owned),
+            ..Condition::default()
+        };
+
+        assert_eq!(
+            condition.evaluate(&["main"], &["desktopicon"], "english"),
+            expected
+        );
+    }
 }
@@ -12,6 +12,7 @@
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct DeleteEntry {
     name: String,
+    condition: Condition,
     target_type: TargetType,
 }
 
@@ -35,7 +36,7 @@ pub fn read<R>(
             ..Self::default()
         };
 
-        Condition::read(&mut reader, codepage, version)?;
+        delete_entry.condition = Condition::read(&mut reader, codepage, version)?;
 
         WindowsVersionRange::read_from(&mut reader, version)?;
 
@@ -51,6 +52,13 @@ pub const fn name(&self) -> &str {
         self.name.as_str()
     }
 
+    /// Returns the conditions that determine whether the delete entry is processed.
+    #[must_use]
+    #[inline]
+    pub const fn condition(&self) -> &Condition {
+        &self.condition
+    }
+
     /// Returns the target type of the delete entry.
     #[must_use]
     #[inline]
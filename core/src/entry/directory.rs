@@ -5,15 +5,16 @@
 use zerocopy::LE;
 
 use crate::{
-    ReadBytesExt,
-    entry::Condition,
+    Inno, ReadBytesExt,
+    entry::{Condition, Permission},
     string::PascalString,
     version::{InnoVersion, windows_version::WindowsVersionRange},
 };
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Directory {
-    name: Option<String>,
+    pub(crate) name: Option<String>,
+    condition: Condition,
     permissions: Option<PascalString>,
     attributes: u32,
     /// Index into the permission entry list
@@ -39,7 +40,7 @@ pub fn read<R>(
             ..Self::default()
         };
 
-        Condition::read(&mut reader, codepage, version)?;
+        directory.condition = Condition::read(&mut reader, codepage, version)?;
 
         if ((4, 0, 11)..(4, 1, 0)).contains(&version) {
             directory.permissions = reader.read_pascal_string()?;
@@ -67,6 +68,13 @@ pub fn name(&self) -> Option<&str> {
         self.name.as_deref()
     }
 
+    /// Returns the conditions that determine whether the directory is created.
+    #[must_use]
+    #[inline]
+    pub const fn condition(&self) -> &Condition {
+        &self.condition
+    }
+
     /// Returns the permissions of the directory.
     #[must_use]
     #[inline]
@@ -88,18 +96,73 @@ pub const fn permission(&self) -> i16 {
         self.permission
     }
 
+    /// Returns the [`Permission`] entry that applies to the directory, or `None` if it has no
+    /// permission entry.
+    ///
+    /// See [`Inno::permission_at`].
+    #[must_use]
+    #[inline]
+    pub fn resolved_permission<'inno, R>(&self, inno: &'inno Inno<R>) -> Option<&'inno Permission>
+    where
+        R: io::Read + io::Seek,
+    {
+        inno.permission_at(self.permission)
+    }
+
     /// Returns the flags of the directory.
     #[must_use]
     #[inline]
     pub const fn flags(&self) -> DirectoryFlags {
         self.flags
     }
+
+    /// Returns `true` if the installer enables NTFS compression on the directory.
+    #[doc(alias = "setntfscompression")]
+    #[must_use]
+    #[inline]
+    pub const fn sets_ntfs_compression(&self) -> bool {
+        self.flags.contains(DirectoryFlags::SET_NTFS_COMPRESSION)
+    }
+
+    /// Returns `true` if the installer disables NTFS compression on the directory.
+    #[doc(alias = "unsetntfscompression")]
+    #[must_use]
+    #[inline]
+    pub const fn unsets_ntfs_compression(&self) -> bool {
+        self.flags.contains(DirectoryFlags::UNSET_NTFS_COMPRESSION)
+    }
+
+    /// Returns `true` if the directory is deleted once the installation has finished.
+    #[doc(alias = "deleteafterinstall")]
+    #[must_use]
+    #[inline]
+    pub const fn is_deleted_after_install(&self) -> bool {
+        self.flags.contains(DirectoryFlags::DELETE_AFTER_INSTALL)
+    }
+
+    /// Returns `true` if the uninstaller removes the directory even if it existed before the
+    /// installation.
+    #[doc(alias = "uninsalwaysuninstall")]
+    #[must_use]
+    #[inline]
+    pub const fn is_always_uninstalled(&self) -> bool {
+        self.flags.contains(DirectoryFlags::ALWAYS_UNINSTALL)
+    }
+
+    /// Returns `true` if the uninstaller never removes the directory.
+    #[doc(alias = "uninsneveruninstall")]
+    #[must_use]
+    #[inline]
+    pub const fn is_never_uninstalled(&self) -> bool {
+        self.flags.contains(DirectoryFlags::NEVER_UNINSTALL)
+    }
 }
 
 impl Default for Directory {
     fn default() -> Self {
         Self {
             name: None,
+            condition: Condition::default(),
             permissions: None,
             attributes: 0,
             permission: -1,
@@ -109,12 +172,22 @@ fn default() -> Self {
 }
 
 bitflags! {
+    /// The flags of a `[Dirs]` entry.
+    ///
+    /// <https://jrsoftware.org/ishelp/index.php?topic=dirssection>
     #[derive(Clone, Copy, Default, Eq, PartialEq)]
     pub struct DirectoryFlags: u8 {
+        /// `uninsneveruninstall`: the directory is not removed by the uninstaller.
         const NEVER_UNINSTALL = 1;
+        /// `deleteafterinstall`: the directory is deleted once the installation has finished, if
+        /// it is empty.
         const DELETE_AFTER_INSTALL = 1 << 1;
+        /// `uninsalwaysuninstall`: the uninstaller removes the directory even if it existed before
+        /// the installation, provided that it is empty.
         const ALWAYS_UNINSTALL = 1 << 2;
+        /// `setntfscompression`: NTFS compression is enabled on the directory.
         const SET_NTFS_COMPRESSION = 1 << 3;
+        /// `unsetntfscompression`: NTFS compression is disabled on the directory.
         const UNSET_NTFS_COMPRESSION = 1 << 4;
     }
 }
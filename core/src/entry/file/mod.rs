@@ -13,36 +13,42 @@
 use zerocopy::LE;
 
 use crate::{
-    entry::Condition,
+    Inno,
+    entry::{Condition, Permission},
     header::flag_reader::read_flags::read_flags,
     read::ReadBytesExt,
+    secret::Secret,
     version::{InnoVersion, windows_version::WindowsVersionRange},
 };
 
 /// <https://github.com/jrsoftware/issrc/blob/is-6_4_3/Projects/Src/Shared.Struct.pas#L225>
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct File {
-    source: Option<String>,
-    destination: Option<String>,
-    install_font_name: Option<String>,
-    strong_assembly_name: Option<String>,
-    excludes: Option<String>,
-    download_is_sig_source: Option<String>,
-    download_user_name: Option<String>,
-    download_password: Option<String>,
-    extract_archive_password: Option<String>,
-    verification: Option<FileVerification>,
+    pub(crate) source: Option<String>,
+    pub(crate) destination: Option<String>,
+    pub(crate) install_font_name: Option<String>,
+    pub(crate) strong_assembly_name: Option<String>,
+    pub(crate) condition: Condition,
+    pub(crate) excludes: Option<String>,
+    pub(crate) download_is_sig_source: Option<String>,
+    pub(crate) download_user_name: Option<String>,
+    pub(crate) download_password: Option<Secret<String>>,
+    pub(crate) extract_archive_password: Option<Secret<String>>,
+    pub(crate) verification: Option<FileVerification>,
     /// Index into the file location entry list
-    location: u32,
-    attributes: u32,
-    external_size: u64,
+    pub(crate) location: u32,
+    pub(crate) attributes: u32,
+    pub(crate) external_size: u64,
     /// Index into the permission entry list
-    permission: i16,
-    flags: FileFlags,
-    r#type: FileType,
+    pub(crate) permission: i16,
+    pub(crate) flags: FileFlags,
+    pub(crate) r#type: FileType,
 }
 
 impl File {
+    /// The location index of files that are not embedded in the installer.
+    const EXTERNAL_LOCATION: u32 = u32::MAX;
+
     pub fn read<R>(
         mut reader: R,
         codepage: &'static Encoding,
@@ -66,14 +72,15 @@ pub fn read<R>(
             file.strong_assembly_name = reader.read_decoded_pascal_string(codepage)?;
         }
 
-        Condition::read(&mut reader, codepage, version)?;
+        file.condition = Condition::read(&mut reader, codepage, version)?;
 
         if version >= 6.5 {
             file.excludes = reader.read_decoded_pascal_string(codepage)?;
             file.download_is_sig_source = reader.read_decoded_pascal_string(codepage)?;
             file.download_user_name = reader.read_decoded_pascal_string(codepage)?;
-            file.download_password = reader.read_decoded_pascal_string(codepage)?;
-            file.extract_archive_password = reader.read_decoded_pascal_string(codepage)?;
+            file.download_password = reader.read_decoded_pascal_string(codepage)?.map(Secret);
+            file.extract_archive_password =
+                reader.read_decoded_pascal_string(codepage)?.map(Secret);
             file.verification = Some(FileVerification::read(&mut reader, codepage)?);
         }
 
@@ -182,6 +189,61 @@ pub fn normalized_destination(&self) -> Option<String> {
         Some(destination.replace('\\', "/"))
     }
 
+    /// Returns the [destination](Self::destination) with each `(name, value)` pair in `constants`
+    /// replacing the `{name}` constant, such as `("app", r"C:\Program Files\My Program")` for
+    /// `{app}`.
+    ///
+    /// Constants are matched case-insensitively, and constants without a value are left as
+    /// written.
+    #[must_use]
+    pub fn expanded_destination(&self, constants: &[(&str, &str)]) -> Option<String> {
+        let destination = self.destination()?;
+
+        let mut expanded = String::with_capacity(destination.len());
+        let mut rest = destination;
+        while let Some(start) = rest.find('{') {
+            let Some(end) = rest[start..].find('}').map(|end| start + end) else {
+                break;
+            };
+
+            expanded.push_str(&rest[..start]);
+            let name = &rest[start + 1..end];
+            match constants
+                .iter()
+                .find(|(constant, _)| constant.eq_ignore_ascii_case(name))
+            {
+                Some((_, value)) => expanded.push_str(value),
+                None => expanded.push_str(&rest[start..=end]),
+            }
+            rest = &rest[end + 1..];
+        }
+        expanded.push_str(rest);
+
+        Some(expanded)
+    }
+
+    /// Returns `true` if the file installs to the given path.
+    ///
+    /// The path is compared to the [destination](Self::destination) case-insensitively as Windows
+    /// does, and forward slashes are treated as backslashes. The constants in the destination are
+    /// [expanded](Self::expanded_destination) with `constants` before it is compared.
+    ///
+    /// The destination always includes the file name, including when the file has a custom
+    /// [`DestName`](FileFlags::CUSTOM_DEST_NAME) that differs from the name of its source.
+    #[must_use]
+    pub fn destination_matches(&self, path: &str, constants: &[(&str, &str)]) -> bool {
+        let Some(expanded) = self.expanded_destination(constants) else {
+            return false;
+        };
+
+        let normalize = |char: char| if char == '/' { '\\' } else { char };
+        expanded
+            .chars()
+            .map(normalize)
+            .flat_map(char::to_lowercase)
+            .eq(path.chars().map(normalize).flat_map(char::to_lowercase))
+    }
+
     /// Returns the install font name as a string slice.
     #[must_use]
     #[inline]
@@ -196,13 +258,81 @@ pub fn strong_assembly_name(&self) -> Option<&str> {
         self.strong_assembly_name.as_deref()
     }
 
+    /// Returns the URL of the signature file of a downloaded file, which is set when the
+    /// `issigverify` flag is used on a file with the [`DOWNLOAD`](FileFlags::DOWNLOAD) flag.
+    ///
+    /// This is only present in Inno Setup 6.5 and later.
+    #[must_use]
+    #[inline]
+    pub fn download_is_sig_source(&self) -> Option<&str> {
+        self.download_is_sig_source.as_deref()
+    }
+
+    /// Returns the user name used to authenticate the download of a file with the
+    /// [`DOWNLOAD`](FileFlags::DOWNLOAD) flag.
+    ///
+    /// This is only present in Inno Setup 6.5 and later.
+    #[must_use]
+    #[inline]
+    pub fn download_user_name(&self) -> Option<&str> {
+        self.download_user_name.as_deref()
+    }
+
+    /// Returns the password used to authenticate the download of a file with the
+    /// [`DOWNLOAD`](FileFlags::DOWNLOAD) flag.
+    ///
+    /// This is only present in Inno Setup 6.5 and later. It is redacted in the
+    /// [`Debug`](std::fmt::Debug) output unless [revealed](crate::secret::Revealed).
+    #[must_use]
+    #[inline]
+    pub fn download_password(&self) -> Option<&str> {
+        self.download_password.as_ref().map(Secret::as_str)
+    }
+
+    /// Returns the password used to extract a file with the
+    /// [`EXTRACT_ARCHIVE`](FileFlags::EXTRACT_ARCHIVE) flag.
+    ///
+    /// This is only present in Inno Setup 6.5 and later. It is redacted in the
+    /// [`Debug`](std::fmt::Debug) output unless [revealed](crate::secret::Revealed).
+    #[must_use]
+    #[inline]
+    pub fn extract_archive_password(&self) -> Option<&str> {
+        self.extract_archive_password.as_ref().map(Secret::as_str)
+    }
+
+    /// Returns the conditions that determine whether the file is installed.
+    #[must_use]
+    #[inline]
+    pub const fn condition(&self) -> &Condition {
+        &self.condition
+    }
+
     /// Returns the location index into the data entry list.
+    ///
+    /// Files that are not embedded in the installer have a location of [`u32::MAX`]. See
+    /// [`is_external`](Self::is_external).
     #[must_use]
     #[inline]
     pub const fn location(&self) -> u32 {
         self.location
     }
 
+    /// Returns true if the file is not embedded in the installer.
+    ///
+    /// External files are those declared with the `external` flag, which Setup copies from the
+    /// [`source`](Self::source) path on the user's system, and files with the [`download`] flag,
+    /// which are downloaded at install time. Neither has a corresponding [`FileLocation`], so their
+    /// location index is [`u32::MAX`] rather than an index into
+    /// [`Inno::file_locations`](crate::Inno::file_locations).
+    ///
+    /// [`download`]: FileFlags::DOWNLOAD
+    /// [`FileLocation`]: super::FileLocation
+    #[must_use]
+    #[inline]
+    pub const fn is_external(&self) -> bool {
+        self.location == Self::EXTERNAL_LOCATION
+    }
+
     /// Returns the attributes of the file.
     #[must_use]
     #[inline]
@@ -224,6 +354,19 @@ pub const fn permission(&self) -> i16 {
         self.permission
     }
 
+    /// Returns the [`Permission`] entry that applies to the file, or `None` if it has no
+    /// permission entry.
+    ///
+    /// See [`Inno::permission_at`].
+    #[must_use]
+    #[inline]
+    pub fn resolved_permission<'inno, R>(&self, inno: &'inno Inno<R>) -> Option<&'inno Permission>
+    where
+        R: io::Read + io::Seek,
+    {
+        inno.permission_at(self.permission)
+    }
+
     /// Returns the flags associated with the file.
     #[must_use]
     #[inline]
@@ -246,6 +389,7 @@ fn default() -> Self {
             destination: None,
             install_font_name: None,
             strong_assembly_name: None,
+            condition: Condition::default(),
             excludes: None,
             download_is_sig_source: None,
             download_user_name: None,
@@ -266,7 +410,8 @@ fn default() -> Self {
 mod tests {
     use rstest::rstest;
 
-    use super::File;
+    use super::{File, Secret};
+    use crate::secret::Revealed;
 
     #[rstest]
     #[case::app(r"{app}\bin\foo.dll", "bin/foo.dll")]
@@ -281,4 +426,81 @@ fn resolve_path(#[case] destination: &str, #[case] expected: &str) {
 
         assert_eq!(file.normalized_destination().as_deref(), Some(expected));
     }
+
+    #[rstest]
+    #[case::first(0, false)]
+    #[case::last_embedded(u32::MAX - 1, false)]
+    #[case::external(u32::MAX, true)]
+    fn is_external(#[case] location: u32, #[case] expected: bool) {
+        let file = File {
+            location,
+            ..File::default()
+        };
+
+        assert_eq!(file.is_external(), expected);
+    }
+
+    #[rstest]
+    #[case::expanded(r"{app}\foo.dll", &[("APP", r"C:\App")], r"C:\App\foo.dll")]
+    #[case::unknown(r"{sys}\foo.dll", &[("app", r"C:\App")], r"{sys}\foo.dll")]
+    #[case::unclosed(r"{app\foo.dll", &[("app", r"C:\App")], r"{app\foo.dll")]
+    fn expanded_destination(
+        #[case] destination: &str,
+        #[case] constants: &[(&str, &str)],
+        #[case] expected: &str,
+    ) {
+        let file = File {
+            destination: Some(destination.into()),
+            ..File::default()
+        };
+
+        assert_eq!(
+            file.expanded_destination(constants).as_deref(),
+            Some(expected)
+        );
+    }
+
+    #[rstest]
+    #[case::exact(r"{app}\bin\foo.dll", r"{app}\bin\foo.dll", &[], true)]
+    #[case::case_insensitive(r"{app}\Bin\Foo.dll", r"{APP}\bin\FOO.DLL", &[], true)]
+    #[case::slashes(r"{app}\bin\foo.dll", "{app}/bin/foo.dll", &[], true)]
+    #[case::expanded(
+        r"{app}\bin\foo.dll",
+        r"C:\Program Files\App\bin\foo.dll",
+        &[("app", r"C:\Program Files\App")],
+        true
+    )]
+    #[case::unexpanded(r"{sys}\foo.dll", r"{sys}\foo.dll", &[("app", r"C:\App")], true)]
+    #[case::different_name(r"{app}\foo.dll", r"{app}\bar.dll", &[], false)]
+    #[case::prefix(r"{app}\foo.dll", r"{app}\foo", &[], false)]
+    fn destination_matches(
+        #[case] destination: &str,
+        #[case] path: &str,
+        #[case] constants: &[(&str, &str)],
+        #[case] expected: bool,
+    ) {
+        let file = File {
+            destination: Some(destination.into()),
+            ..File::default()
+        };
+
+        assert_eq!(file.destination_matches(path, constants), expected);
+    }
+
+    #[test]
+    fn debug_redacts_passwords() {
+        let file = File {
+            download_password: Some(Secret("hunter2".into())),
+            extract_archive_password: Some(Secret(String::new())),
+            ..File::default()
+        };
+
+        let debug = format!("{file:?}");
+        assert!(debug.contains("download_password: Some(<redacted>)"));
+        assert!(debug.contains(r#"extract_archive_password: Some("")"#));
+        assert!(!debug.contains("hunter2"));
+
+        assert!(format!("{:?}", Revealed(&file)).contains("hunter2"));
+        assert_eq!(file.download_password(), Some("hunter2"));
+    }
 }
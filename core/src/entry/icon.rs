@@ -9,24 +9,35 @@
     InnoVersion, ReadBytesExt, WindowsVersionRange, header::flag_reader::read_flags::read_flags,
 };
 
+/// An entry from the `[Icons]` section, which creates a shortcut.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Icon {
-    name: Option<String>,
-    filename: Option<String>,
+    pub(crate) name: Option<String>,
+    pub(crate) filename: Option<String>,
     parameters: Option<String>,
     working_directory: Option<String>,
     file: Option<String>,
     comment: Option<String>,
+    condition: Condition,
     app_user_model_id: Option<String>,
     app_user_model_toast_activator_clsid: String,
     index: i32,
     show_command: i32,
     close_on_exit: CloseSetting,
     hotkey: u16,
-    flags: IconFlags,
+    pub(crate) flags: IconFlags,
 }
 
 impl Icon {
+    /// `SW_SHOWMINIMIZED`
+    const SHOW_MINIMIZED: i32 = 2;
+
+    /// `SW_SHOWMAXIMIZED`
+    const SHOW_MAXIMIZED: i32 = 3;
+
+    /// `SW_SHOWMINNOACTIVE`
+    const SHOW_MINIMIZED_NO_ACTIVE: i32 = 7;
+
     pub fn read<R>(
         mut reader: R,
         codepage: &'static Encoding,
@@ -49,7 +60,7 @@ pub fn read<R>(
             ..Self::default()
         };
 
-        Condition::read(&mut reader, codepage, version)?;
+        icon.condition = Condition::read(&mut reader, codepage, version)?;
 
         if version >= (5, 3, 5) {
             icon.app_user_model_id = reader.read_decoded_pascal_string(codepage)?;
@@ -132,7 +143,18 @@ pub fn comment(&self) -> Option<&str> {
         self.comment.as_deref()
     }
 
+    /// Returns the conditions that determine whether the icon is created.
+    #[must_use]
+    #[inline]
+    pub const fn condition(&self) -> &Condition {
+        &self.condition
+    }
+
     /// Returns the `AppUserModelId` of the icon as a string slice.
+    ///
+    /// This determines how the shortcut is grouped on the taskbar and in the Start Menu. It is
+    /// only stored from Inno Setup 5.3.5, so it is always `None` for older installers.
+    #[doc(alias = "AppUserModelID")]
     #[must_use]
     #[inline]
     pub fn app_user_model_id(&self) -> Option<&str> {
@@ -140,13 +162,16 @@ pub fn app_user_model_id(&self) -> Option<&str> {
     }
 
     /// Returns the `AppUserModelToastActivatorClsid` of the icon as a string slice.
+    ///
+    /// This is only stored from Inno Setup 6.1.0, so it is empty for older installers.
     #[must_use]
     #[inline]
     pub const fn app_user_model_toast_activator_clsid(&self) -> &str {
         self.app_user_model_toast_activator_clsid.as_str()
     }
 
-    /// Returns the index of the icon.
+    /// Returns the index of the icon within its [icon file](Self::file).
+    #[doc(alias = "IconIndex")]
     #[must_use]
     #[inline]
     pub const fn index(&self) -> i32 {
@@ -154,12 +179,39 @@ pub const fn index(&self) -> i32 {
     }
 
     /// Returns the show command of the icon.
+    ///
+    /// This is the `SW_*` value passed to `ShowWindow` when the shortcut is launched.
     #[must_use]
     #[inline]
     pub const fn show_command(&self) -> i32 {
         self.show_command
     }
 
+    /// Returns `true` if the shortcut launches its target minimized.
+    ///
+    /// Before Inno Setup 1.3.26, this was stored as the [`RUN_MINIMIZED`] flag. Newer installers
+    /// store it as the [show command](Self::show_command) instead.
+    ///
+    /// [`RUN_MINIMIZED`]: IconFlags::RUN_MINIMIZED
+    #[doc(alias = "runminimized")]
+    #[must_use]
+    #[inline]
+    pub const fn is_run_minimized(&self) -> bool {
+        self.flags.contains(IconFlags::RUN_MINIMIZED)
+            || matches!(
+                self.show_command,
+                Self::SHOW_MINIMIZED | Self::SHOW_MINIMIZED_NO_ACTIVE
+            )
+    }
+
+    /// Returns `true` if the shortcut launches its target maximized.
+    #[doc(alias = "runmaximized")]
+    #[must_use]
+    #[inline]
+    pub const fn is_run_maximized(&self) -> bool {
+        self.show_command == Self::SHOW_MAXIMIZED
+    }
+
     /// Returns the close on exit setting of the icon.
     #[must_use]
     #[inline]
@@ -167,7 +219,24 @@ pub const fn close_on_exit(&self) -> CloseSetting {
         self.close_on_exit
     }
 
+    /// Returns whether the console window of an MS-DOS shortcut closes when the program exits, or
+    /// `None` if the icon has no setting.
+    #[must_use]
+    #[inline]
+    pub const fn closes_on_exit(&self) -> Option<bool> {
+        match self.close_on_exit {
+            CloseSetting::NoSetting => None,
+            CloseSetting::CloseOnExit => Some(true),
+            CloseSetting::DontCloseOnExit => Some(false),
+        }
+    }
+
     /// Returns the hotkey of the icon.
+    ///
+    /// This is a Delphi `TShortCut`, where the low byte is the virtual key code and the high byte
+    /// holds the `Shift`, `Ctrl` and `Alt` modifiers. It is `0` if the icon has no hotkey, and is
+    /// only stored from Inno Setup 2.0.7.
+    #[doc(alias = "HotKey")]
     #[must_use]
     #[inline]
     pub const fn hotkey(&self) -> u16 {
@@ -191,6 +260,7 @@ fn default() -> Self {
             working_directory: None,
             file: None,
             comment: None,
+            condition: Condition::default(),
             app_user_model_id: None,
             app_user_model_toast_activator_clsid: String::new(),
             index: 0,
@@ -249,15 +319,27 @@ fn try_from(value: u8) -> Result<Self, Self::Error> {
 }
 
 bitflags! {
+    /// Flags for an [`Icon`] entry.
     #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
     pub struct IconFlags: u8 {
+        /// `uninsneveruninstall`
         const NEVER_UNINSTALL = 1;
+        /// `createonlyiffileexists`
         const CREATE_ONLY_IF_FILE_EXISTS = 1 << 1;
+        /// `useapppaths`
         const USE_APP_PATHS = 1 << 2;
+        /// `foldershortcut`, stored from Inno Setup 5.0.3 until 6.3.0.
         const FOLDER_SHORTCUT = 1 << 3;
+        /// `excludefromshowinnewinstall`, stored from Inno Setup 5.4.2.
         const EXCLUDE_FROM_SHOW_IN_NEW_INSTALL = 1 << 4;
+        /// `preventpinning`, stored from Inno Setup 5.5.0.
         const PREVENT_PINNING = 1 << 5;
+        /// Set when the icon has an `AppUserModelToastActivatorCLSID`, stored from Inno Setup
+        /// 6.1.0.
         const HAS_APP_USER_MODEL_TOAST_ACTIVATOR_CLSID = 1 << 6;
+        /// `runminimized`, stored as a flag before Inno Setup 1.3.26.
+        ///
+        /// See [`Icon::is_run_minimized`].
         const RUN_MINIMIZED = 1 << 7;
     }
 }
@@ -267,3 +349,43 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         bitflags::parser::to_writer(self, f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{CloseSetting, Icon, IconFlags};
+
+    #[rstest]
+    #[case(IconFlags::empty(), 1, false)]
+    #[case(IconFlags::RUN_MINIMIZED, 1, true)]
+    #[case(IconFlags::empty(), 2, true)]
+    #[case(IconFlags::empty(), 7, true)]
+    #[case(IconFlags::empty(), 3, false)]
+    fn is_run_minimized(
+        #[case] flags: IconFlags,
+        #[case] show_command: i32,
+        #[case] expected: bool,
+    ) {
+        let icon = Icon {
+            flags,
+            show_command,
+            ..Icon::default()
+        };
+
+        assert_eq!(icon.is_run_minimized(), expected);
+    }
+
+    #[rstest]
+    #[case(CloseSetting::NoSetting, None)]
+    #[case(CloseSetting::CloseOnExit, Some(true))]
+    #[case(CloseSetting::DontCloseOnExit, Some(false))]
+    fn closes_on_exit(#[case] close_on_exit: CloseSetting, #[case] expected: Option<bool>) {
+        let icon = Icon {
+            close_on_exit,
+            ..Icon::default()
+        };
+
+        assert_eq!(icon.closes_on_exit(), expected);
+    }
+}
@@ -13,6 +13,7 @@ pub struct Ini {
     section: Option<String>,
     key: Option<String>,
     value: Option<String>,
+    condition: Condition,
     flags: IniFlags,
 }
 
@@ -41,7 +42,7 @@ pub fn read<R>(
             ..Self::default()
         };
 
-        Condition::read(&mut reader, codepage, version)?;
+        ini.condition = Condition::read(&mut reader, codepage, version)?;
 
         WindowsVersionRange::read_from(&mut reader, version)?;
 
@@ -78,6 +79,13 @@ pub fn value(&self) -> Option<&str> {
         self.value.as_deref()
     }
 
+    /// Returns the conditions that determine whether the INI entry is written.
+    #[must_use]
+    #[inline]
+    pub const fn condition(&self) -> &Condition {
+        &self.condition
+    }
+
     /// Returns the flags for the INI entry.
     #[must_use]
     #[inline]
@@ -1,6 +1,9 @@
 use std::{borrow::Cow, io, io::Read};
 
-use flate2::read::ZlibDecoder;
+use flate2::{
+    Compression,
+    read::{ZlibDecoder, ZlibEncoder},
+};
 use zerocopy::TryFromBytes;
 
 use super::instruction::Instruction;
@@ -34,10 +37,11 @@ pub const fn is_zlib(&self) -> bool {
         matches!(self, Self::ZlibFilter)
     }
 
-    /// Converts relative addresses in x86/x64 CALL and JMP instructions to absolute addresses for older Inno Setup versions.
+    /// Converts relative addresses in x86/x64 CALL and JMP instructions to absolute addresses if
+    /// `ENCODE` is `true`, or the inverse if `ENCODE` is false, for older Inno Setup versions.
     ///
     /// This modifies `data` in-place.
-    fn decode_4108(data: &mut [u8]) {
+    fn transform_4108<const ENCODE: bool>(data: &mut [u8]) {
         let mut position = 0;
         while position + size_of::<Instruction>() < data.len() {
             let Ok(instruction) = Instruction::try_mut_from_bytes(
@@ -49,11 +53,14 @@ fn decode_4108(data: &mut [u8]) {
 
             let base_address = (position + size_of::<Instruction>()) as u32;
 
-            // Change the address to be relative to the beginning of the next instruction
-            instruction.address = instruction
-                .address()
-                .wrapping_sub(base_address)
-                .to_le_bytes();
+            instruction.address = if ENCODE {
+                // Change the address to be relative to the beginning of the buffer
+                instruction.address().wrapping_add(base_address)
+            } else {
+                // Change the address to be relative to the beginning of the next instruction
+                instruction.address().wrapping_sub(base_address)
+            }
+            .to_le_bytes();
 
             position += size_of::<Instruction>();
         }
@@ -135,11 +142,16 @@ fn transform_call_instructions<const ENCODE: bool, const FLIP_HIGH_BYTE: bool>(
     /// * If the compression filter is [`NoFilter`], `data` is not modified
     ///   and the returned [`Cow`] is a reference to the data.
     ///
+    /// [`InstructionFilter4108`]: Self::InstructionFilter4108
+    /// [`InstructionFilter5200`]: Self::InstructionFilter5200
+    /// [`InstructionFilter5309`]: Self::InstructionFilter5309
     /// [`ZlibFilter`]: Self::ZlibFilter
+    /// [`NoFilter`]: Self::NoFilter
+    #[doc(alias = "apply")]
     pub fn decode(self, data: &mut [u8]) -> io::Result<Cow<'_, [u8]>> {
         match self {
             Self::NoFilter => {}
-            Self::InstructionFilter4108 => Self::decode_4108(data),
+            Self::InstructionFilter4108 => Self::transform_4108::<false>(data),
             Self::InstructionFilter5200 => Self::transform_call_instructions::<false, false>(data),
             Self::InstructionFilter5309 => Self::transform_call_instructions::<false, true>(data),
             Self::ZlibFilter => {
@@ -152,10 +164,46 @@ pub fn decode(self, data: &mut [u8]) -> io::Result<Cow<'_, [u8]>> {
 
         Ok(Cow::Borrowed(data))
     }
+
+    /// Apply the compression filter to file data, as Inno Setup does before compression.
+    ///
+    /// This is the inverse of [`decode`](Self::decode), such that decoding encoded data returns
+    /// the original data.
+    ///
+    /// * If the compression filter is [`InstructionFilter4108`],
+    ///   [`InstructionFilter5200`], [`InstructionFilter5309`], `data` is
+    ///   modified in-place and the returned [`Cow`] is a reference to the data.
+    /// * If the compression filter is [`ZlibFilter`], `data` is not modified
+    ///   and the returned [`Cow`] is the owned compressed data.
+    /// * If the compression filter is [`NoFilter`], `data` is not modified
+    ///   and the returned [`Cow`] is a reference to the data.
+    ///
+    /// [`InstructionFilter4108`]: Self::InstructionFilter4108
+    /// [`InstructionFilter5200`]: Self::InstructionFilter5200
+    /// [`InstructionFilter5309`]: Self::InstructionFilter5309
+    /// [`ZlibFilter`]: Self::ZlibFilter
+    /// [`NoFilter`]: Self::NoFilter
+    pub fn encode(self, data: &mut [u8]) -> io::Result<Cow<'_, [u8]>> {
+        match self {
+            Self::NoFilter => {}
+            Self::InstructionFilter4108 => Self::transform_4108::<true>(data),
+            Self::InstructionFilter5200 => Self::transform_call_instructions::<true, false>(data),
+            Self::InstructionFilter5309 => Self::transform_call_instructions::<true, true>(data),
+            Self::ZlibFilter => {
+                let mut compressed = Vec::new();
+                ZlibEncoder::new(&*data, Compression::default()).read_to_end(&mut compressed)?;
+                return Ok(Cow::Owned(compressed));
+            }
+        }
+
+        Ok(Cow::Borrowed(data))
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use super::CompressionFilter;
 
     #[test]
@@ -195,4 +243,29 @@ fn filter_5200_no_transform_non_sign_extended() {
 
         assert_eq!(data, original);
     }
+
+    #[rstest]
+    #[case(CompressionFilter::NoFilter)]
+    #[case(CompressionFilter::InstructionFilter4108)]
+    #[case(CompressionFilter::InstructionFilter5200)]
+    #[case(CompressionFilter::InstructionFilter5309)]
+    #[case(CompressionFilter::ZlibFilter)]
+    fn round_trip(#[case] filter: CompressionFilter) {
+        // Forward and backward calls and jumps, separated by padding
+        let original = [
+            0x90, 0xE8, 0x10, 0x00, 0x00, 0x00, 0x90, 0xE9, 0xF0, 0xFF, 0xFF, 0xFF, 0x90, 0x90,
+            0xE8, 0x00, 0x00, 0x80, 0xFF, 0x90, 0x90, 0x90,
+        ];
+
+        let mut data = original;
+        let mut encoded = filter.encode(&mut data).unwrap().into_owned();
+
+        if !filter.is_no_filter() {
+            assert_ne!(encoded, original);
+        }
+
+        let decoded = filter.decode(&mut encoded).unwrap();
+
+        assert_eq!(*decoded, original);
+    }
 }
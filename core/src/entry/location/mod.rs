@@ -5,7 +5,7 @@
 pub(super) mod instruction;
 mod sign;
 
-use std::io;
+use std::{io, ops::Range};
 
 #[cfg(feature = "chrono")]
 use chrono::{DateTime, Utc};
@@ -30,13 +30,13 @@
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct FileLocation {
-    chunk: Chunk,
-    file: File,
-    uncompressed_size: u64,
-    file_time: FileTime,
-    file_version: u64,
-    options: FileLocationFlags,
-    sign_mode: SignMode,
+    pub(crate) chunk: Chunk,
+    pub(crate) file: File,
+    pub(crate) uncompressed_size: u64,
+    pub(crate) file_time: FileTime,
+    pub(crate) file_version: u64,
+    pub(crate) options: FileLocationFlags,
+    pub(crate) sign_mode: SignMode,
 }
 
 impl FileLocation {
@@ -189,6 +189,78 @@ pub const fn chunk(&self) -> &Chunk {
         &self.chunk
     }
 
+    /// Returns `true` if the file is known to be stored in a solid block, a chunk that is shared
+    /// with other files and must be decompressed from its start.
+    ///
+    /// A file is known to be solid if it starts partway into its chunk, or if it is marked with
+    /// [`SOLID_BREAK`](FileLocationFlags::SOLID_BREAK) as the first file of a solid block, which
+    /// is only stored from Inno Setup 5.1.13 until 6.4.3. The first file of a solid block can't
+    /// otherwise be told apart from a file with a chunk of its own, so use
+    /// [`Inno::solid_blocks`](crate::Inno::solid_blocks) to group files reliably.
+    #[must_use]
+    #[inline]
+    pub const fn is_solid(&self) -> bool {
+        self.file.offset() != 0 || self.options.contains(FileLocationFlags::SOLID_BREAK)
+    }
+
+    /// Returns the compressed size of the file's chunk, in bytes.
+    ///
+    /// If the file is [solid](Self::is_solid), its chunk is shared with other files and this is
+    /// the compressed size of the whole chunk, which must be read in full to extract the file. The
+    /// size does not include the `zlb\x1a` signature at the start of the chunk.
+    #[must_use]
+    #[inline]
+    pub const fn compressed_size(&self) -> u64 {
+        self.chunk.original_size()
+    }
+
+    /// Returns the compression ratio of the file, which is its uncompressed size divided by the
+    /// compressed size of its chunk.
+    ///
+    /// If the file is [solid](Self::is_solid), its chunk is shared with other files, so the ratio
+    /// compares the file to the whole chunk and understates how well it compressed. Use
+    /// [`Inno::compression_ratio`](crate::Inno::compression_ratio) for the overall ratio. Returns
+    /// `1.0` if the chunk is empty.
+    #[must_use]
+    pub fn compression_ratio(&self) -> f64 {
+        match self.compressed_size() {
+            0 => 1.0,
+            compressed => self.uncompressed_size as f64 / compressed as f64,
+        }
+    }
+
+    /// Returns the absolute offset of the file's data, if its chunk is neither compressed nor
+    /// encrypted so that the data can be read directly.
+    ///
+    /// `data_base_offset` is [`Inno::data_base_offset`](crate::Inno::data_base_offset) for data that
+    /// is embedded in the setup executable, or 0 for the disk slice file that the chunk starts in.
+    /// See the [`extraction`](crate::extraction) module for how the offset is computed. Returns
+    /// `None` if the offset would overflow, which only happens for malformed installers.
+    #[must_use]
+    pub const fn stored_data_offset(&self, data_base_offset: u64) -> Option<u64> {
+        if self.chunk.is_compressed() || self.chunk.is_encrypted() {
+            return None;
+        }
+
+        let Some(chunk_offset) = data_base_offset.checked_add(self.chunk.start_offset()) else {
+            return None;
+        };
+        let Some(data_offset) = chunk_offset.checked_add(Chunk::MAGIC_LEN) else {
+            return None;
+        };
+        data_offset.checked_add(self.file.offset())
+    }
+
+    /// Returns the range of disk slices that the file's chunk spans.
+    ///
+    /// Installers that are not disk spanned store all of their data in a single slice, so this is
+    /// always `0..1` for them.
+    #[must_use]
+    #[inline]
+    pub const fn slice_range(&self) -> Range<u32> {
+        self.chunk.first_slice()..self.chunk.last_slice().saturating_add(1)
+    }
+
     /// Returns the file object.
     #[must_use]
     #[inline]
@@ -230,12 +302,50 @@ pub fn timestamp(&self) -> Result<Timestamp, jiff::Error> {
     }
 
     /// Returns the file version.
+    ///
+    /// The most significant 32 bits are the `dwFileVersionMS` of the file's version info and the
+    /// least significant 32 bits are the `dwFileVersionLS`. See
+    /// [`version_tuple`](Self::version_tuple) for the four-part version.
     #[must_use]
     #[inline]
     pub const fn file_version(&self) -> u64 {
         self.file_version
     }
 
+    /// Returns the four-part Windows file version, such as `(1, 2, 3, 4)` for `1.2.3.4`, or `None`
+    /// if the file has no valid version info.
+    ///
+    /// See [`is_version_info_valid`](Self::is_version_info_valid).
+    #[must_use]
+    pub const fn version_tuple(&self) -> Option<(u16, u16, u16, u16)> {
+        if !self.is_version_info_valid() {
+            return None;
+        }
+
+        let version = self.file_version;
+        Some((
+            (version >> 48) as u16,
+            (version >> 32) as u16,
+            (version >> 16) as u16,
+            version as u16,
+        ))
+    }
+
+    /// Returns `true` if the file version was read from the file's version info when the
+    /// installer was compiled.
+    ///
+    /// Files without version info, such as text files, have a [file version] of zero.
+    ///
+    /// [file version]: Self::file_version
+    #[must_use]
+    #[inline]
+    pub const fn is_version_info_valid(&self) -> bool {
+        self.options.contains(FileLocationFlags::VERSION_INFO_VALID)
+            && !self
+                .options
+                .contains(FileLocationFlags::VERSION_INFO_NOT_VALID)
+    }
+
     /// Returns the file option flags.
     #[must_use]
     #[inline]
@@ -243,6 +353,61 @@ pub const fn file_option_flags(&self) -> FileLocationFlags {
         self.options
     }
 
+    /// Returns `true` if the file's chunk is compressed.
+    ///
+    /// Chunks of installers before Inno Setup 4.2.5 are always compressed. The compression method
+    /// is that of the [chunk](Self::chunk).
+    #[must_use]
+    #[inline]
+    pub const fn is_chunk_compressed(&self) -> bool {
+        self.options.contains(FileLocationFlags::CHUNK_COMPRESSED)
+    }
+
+    /// Returns `true` if the file's chunk is encrypted.
+    #[must_use]
+    #[inline]
+    pub const fn is_chunk_encrypted(&self) -> bool {
+        self.options.contains(FileLocationFlags::CHUNK_ENCRYPTED)
+    }
+
+    /// Returns `true` if the file's data has the x86 call instruction filter applied, which must
+    /// be reverted after decompressing it.
+    ///
+    /// See [`File::compression_filter`] for the filter.
+    #[must_use]
+    #[inline]
+    pub const fn is_call_optimized(&self) -> bool {
+        self.options
+            .contains(FileLocationFlags::CALL_INSTRUCTION_OPTIMIZED)
+    }
+
+    /// Returns `true` if the file is marked as the first file of a solid block.
+    ///
+    /// This is only stored from Inno Setup 5.1.13 until 6.4.3. See [`is_solid`](Self::is_solid).
+    #[must_use]
+    #[inline]
+    pub const fn is_solid_break(&self) -> bool {
+        self.options.contains(FileLocationFlags::SOLID_BREAK)
+    }
+
+    /// Returns `true` if the file is the uninstaller executable.
+    ///
+    /// This is only stored from Inno Setup 4.2.0 until 6.4.3.
+    #[must_use]
+    #[inline]
+    pub const fn is_uninstaller_exe(&self) -> bool {
+        self.options.contains(FileLocationFlags::IS_UNINSTALLER_EXE)
+    }
+
+    /// Returns `true` if the file's [time](Self::file_time) is in UTC rather than local time.
+    ///
+    /// Times are always in local time before Inno Setup 4.0.10.
+    #[must_use]
+    #[inline]
+    pub const fn is_timestamp_utc(&self) -> bool {
+        self.options.contains(FileLocationFlags::TIMESTAMP_IN_UTC)
+    }
+
     /// Returns the sign mode.
     #[must_use]
     #[inline]
@@ -250,3 +415,168 @@ pub const fn sign_mode(&self) -> SignMode {
         self.sign_mode
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "extract")]
+    use std::io::Cursor;
+
+    use rstest::rstest;
+
+    use super::{File, FileLocation, FileLocationFlags};
+    #[cfg(feature = "extract")]
+    use crate::test_support::{file_at, inno_with_files, stored_location};
+    use crate::{header::Compression, read::chunk::Chunk};
+
+    #[rstest]
+    #[case::single(0, 0, 0..1)]
+    #[case::second(1, 1, 1..2)]
+    #[case::spanned(2, 4, 2..5)]
+    #[case::last_possible(u32::MAX - 1, u32::MAX, u32::MAX - 1..u32::MAX)]
+    fn slice_range(
+        #[case] first_slice: u32,
+        #[case] last_slice: u32,
+        #[case] expected: std::ops::Range<u32>,
+    ) {
+        let location = FileLocation {
+            chunk: Chunk {
+                first_slice,
+                last_slice,
+                ..Chunk::default()
+            },
+            ..FileLocation::default()
+        };
+
+        assert_eq!(location.slice_range(), expected);
+    }
+
+    #[rstest]
+    #[case::own_chunk(0, FileLocationFlags::empty(), false)]
+    #[case::within_block(1024, FileLocationFlags::empty(), true)]
+    #[case::solid_break(0, FileLocationFlags::SOLID_BREAK, true)]
+    fn is_solid(#[case] offset: u64, #[case] options: FileLocationFlags, #[case] expected: bool) {
+        let location = FileLocation {
+            file: File {
+                offset,
+                ..File::default()
+            },
+            options,
+            ..FileLocation::default()
+        };
+
+        assert_eq!(location.is_solid(), expected);
+    }
+
+    #[test]
+    fn flag_accessors() {
+        let location = FileLocation {
+            options: FileLocationFlags::CHUNK_COMPRESSED | FileLocationFlags::TIMESTAMP_IN_UTC,
+            ..FileLocation::default()
+        };
+
+        assert!(location.is_chunk_compressed());
+        assert!(location.is_timestamp_utc());
+        assert!(!location.is_chunk_encrypted());
+        assert!(!location.is_call_optimized());
+        assert!(!location.is_solid_break());
+        assert!(!location.is_uninstaller_exe());
+    }
+
+    #[rstest]
+    #[case::valid(FileLocationFlags::VERSION_INFO_VALID, Some((1, 2, 3, 4)))]
+    #[case::missing(FileLocationFlags::empty(), None)]
+    #[case::not_valid(
+        FileLocationFlags::VERSION_INFO_VALID | FileLocationFlags::VERSION_INFO_NOT_VALID,
+        None
+    )]
+    fn version_tuple(
+        #[case] options: FileLocationFlags,
+        #[case] expected: Option<(u16, u16, u16, u16)>,
+    ) {
+        let location = FileLocation {
+            file_version: 0x0001_0002_0003_0004,
+            options,
+            ..FileLocation::default()
+        };
+
+        assert_eq!(location.version_tuple(), expected);
+    }
+
+    #[rstest]
+    #[case::compressed(4096, 1024, 4.0)]
+    #[case::stored(100, 100, 1.0)]
+    #[case::empty(0, 0, 1.0)]
+    fn compression_ratio(
+        #[case] uncompressed_size: u64,
+        #[case] chunk_size: u64,
+        #[case] expected: f64,
+    ) {
+        let location = FileLocation {
+            chunk: Chunk {
+                original_size: chunk_size,
+                ..Chunk::default()
+            },
+            uncompressed_size,
+            ..FileLocation::default()
+        };
+
+        assert_eq!(location.compression_ratio(), expected);
+    }
+
+    #[rstest]
+    #[case::stored(Compression::Stored, 100, 20, Some(100 + 20 + 4 + 8))]
+    #[case::compressed(Compression::LZMA1, 100, 20, None)]
+    #[case::chunk_overflow(Compression::Stored, u64::MAX, 1, None)]
+    #[case::magic_overflow(Compression::Stored, u64::MAX - 2, 0, None)]
+    #[case::file_overflow(Compression::Stored, u64::MAX - 8, 0, None)]
+    fn stored_data_offset(
+        #[case] compression: Compression,
+        #[case] data_base_offset: u64,
+        #[case] start_offset: u64,
+        #[case] expected: Option<u64>,
+    ) {
+        let location = FileLocation {
+            chunk: Chunk {
+                start_offset,
+                compression,
+                ..Chunk::default()
+            },
+            file: File {
+                offset: 8,
+                ..File::default()
+            },
+            ..FileLocation::default()
+        };
+
+        assert_eq!(location.stored_data_offset(data_base_offset), expected);
+    }
+
+    #[cfg(feature = "extract")]
+    #[test]
+    fn stored_data_offset_matches_extraction() {
+        // Reading at the stored data offset must give the same bytes as extracting the file
+        let mut inno = inno_with_files(
+            vec![file_at(0), file_at(1)],
+            vec![
+                stored_location(0, 4, 0, b"abcd"),
+                stored_location(8, 6, 2, b"efgh"),
+            ],
+        );
+        let setup = b"zlb\x1aabcdzlb\x1axyefgh";
+        inno.reader = Cursor::new(setup.to_vec());
+
+        let extracted = inno
+            .files()
+            .map(|result| result.map(|(_, data)| data))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        for (location, data) in inno.file_locations().iter().zip(extracted) {
+            let offset = location
+                .stored_data_offset(inno.data_base_offset())
+                .unwrap();
+            let offset = usize::try_from(offset).unwrap();
+            assert_eq!(&setup[offset..offset + data.len()], data.as_slice());
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::io;
+use std::{collections::HashMap, io};
 
 use encoding_rs::Encoding;
 use zerocopy::LE;
@@ -41,6 +41,15 @@ pub fn value(&self) -> Option<&str> {
     pub const fn language(&self) -> Option<&'language Language> {
         self.language
     }
+
+    /// Returns the number of arguments that the message's value refers to.
+    ///
+    /// See [`MessageEntry::placeholder_count`].
+    #[must_use]
+    #[inline]
+    pub fn placeholder_count(&self) -> usize {
+        self.entry.placeholder_count()
+    }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -97,6 +106,29 @@ pub fn value(&self) -> Option<&str> {
         self.value.as_ref().map(PascalString::as_str)
     }
 
+    /// Returns the number of arguments that the message's value refers to, which is the highest
+    /// `%1` to `%9` placeholder in it.
+    ///
+    /// The installer does not store the number of arguments of a message, so it is found by
+    /// scanning the value in the same way as Inno Setup's `FmtMessage`. A `%%` is an escaped
+    /// percent sign rather than a placeholder.
+    #[must_use]
+    pub fn placeholder_count(&self) -> usize {
+        let mut count = 0;
+        let mut chars = self.value().unwrap_or_default().chars();
+
+        while let Some(char) = chars.next() {
+            if char == '%'
+                && let Some(next) = chars.next()
+                && let Some(digit) = next.to_digit(10).filter(|&digit| digit != 0)
+            {
+                count = count.max(digit as usize);
+            }
+        }
+
+        count
+    }
+
     /// Returns the message's language index into the language entries.
     #[must_use]
     #[inline]
@@ -112,4 +144,181 @@ pub fn language<'languages>(
     ) -> Option<&'languages Language> {
         languages.get(usize::try_from(self.language_index).ok()?)
     }
+
+    /// Returns an iterator over the names and values of the messages for the language at the
+    /// given index, with each message name yielded once, in the order it first appears.
+    ///
+    /// Messages for all languages, which have a language index of -1, are used when a message has
+    /// no value for the language. If `fallback` is true, the primary language's value is used
+    /// when a message has neither.
+    pub(crate) fn for_language(
+        entries: &[Self],
+        language_index: Option<i32>,
+        fallback: bool,
+    ) -> impl Iterator<Item = (&str, &str)> {
+        // Index the values by name and then language once, keeping the first value of each pair
+        let mut names = Vec::new();
+        let mut values = HashMap::<_, HashMap<_, _>>::new();
+        for entry in entries {
+            let Some(name) = entry.name() else {
+                continue;
+            };
+
+            values
+                .entry(name)
+                .or_insert_with(|| {
+                    names.push(name);
+                    HashMap::new()
+                })
+                .entry(entry.language_index)
+                .or_insert_with(|| entry.value().unwrap_or_default());
+        }
+
+        names.into_iter().filter_map(move |name| {
+            let find = |index: i32| values.get(name)?.get(&index).copied();
+
+            let value = language_index
+                .and_then(find)
+                .or_else(|| find(-1))
+                .or_else(|| fallback.then(|| find(0)).flatten())?;
+
+            Some((name, value))
+        })
+    }
+
+    /// Returns a map of each message name to the indices of the languages, out of
+    /// `language_count`, that have no value for it.
+    ///
+    /// A message that has a value for all languages, with a language index of -1, is not missing
+    /// from any language.
+    pub(crate) fn missing_languages(
+        entries: &[Self],
+        language_count: usize,
+    ) -> HashMap<&str, Vec<usize>> {
+        let mut languages = HashMap::<_, Vec<_>>::new();
+
+        for entry in entries {
+            let Some(name) = entry.name() else {
+                continue;
+            };
+
+            let has_value = languages.entry(name).or_default();
+            if entry.language_index == -1 {
+                has_value.extend(0..language_count);
+            } else if let Ok(index) = usize::try_from(entry.language_index) {
+                has_value.push(index);
+            }
+        }
+
+        languages
+            .into_iter()
+            .map(|(name, has_value)| {
+                let missing = (0..language_count)
+                    .filter(|index| !has_value.contains(index))
+                    .collect();
+                (name, missing)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use rstest::rstest;
+
+    use super::MessageEntry;
+    use crate::string::PascalString;
+
+    fn entry(name: &str, value: &str, language_index: i32) -> MessageEntry {
+        MessageEntry {
+            name: Some(name.to_owned()),
+            value: Some(PascalString::from(value)),
+            language_index,
+        }
+    }
+
+    #[rstest]
+    #[case::primary(Some(0), false, vec![("Greeting", "Hello"), ("Farewell", "Goodbye")])]
+    #[case::secondary(Some(1), false, vec![("Greeting", "Hallo")])]
+    #[case::secondary_fallback(Some(1), true, vec![("Greeting", "Hallo"), ("Farewell", "Goodbye")])]
+    #[case::unknown(None, false, vec![])]
+    #[case::unknown_fallback(None, true, vec![("Greeting", "Hello"), ("Farewell", "Goodbye")])]
+    fn for_language(
+        #[case] language_index: Option<i32>,
+        #[case] fallback: bool,
+        #[case] expected: Vec<(&str, &str)>,
+    ) {
+        let entries = [
+            entry("Greeting", "Hello", 0),
+            entry("Greeting", "Hallo", 1),
+            entry("Farewell", "Goodbye", 0),
+        ];
+
+        assert_eq!(
+            MessageEntry::for_language(&entries, language_index, fallback).collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn for_language_all_languages() {
+        let entries = [entry("AppName", "Inno", -1), entry("AppName", "Inno DE", 1)];
+
+        assert_eq!(
+            MessageEntry::for_language(&entries, Some(0), false).collect::<Vec<_>>(),
+            [("AppName", "Inno")]
+        );
+        assert_eq!(
+            MessageEntry::for_language(&entries, Some(1), false).collect::<Vec<_>>(),
+            [("AppName", "Inno DE")]
+        );
+    }
+
+    #[test]
+    fn for_language_duplicates() {
+        let entries = [
+            entry("Greeting", "Hello", 0),
+            entry("Farewell", "Goodbye", 0),
+            entry("Greeting", "Hi", 0),
+        ];
+
+        assert_eq!(
+            MessageEntry::for_language(&entries, Some(0), false).collect::<Vec<_>>(),
+            [("Greeting", "Hello"), ("Farewell", "Goodbye")]
+        );
+    }
+
+    #[test]
+    fn missing_languages() {
+        let entries = [
+            entry("Greeting", "Hello", 0),
+            entry("Greeting", "Hallo", 1),
+            entry("Farewell", "Goodbye", 0),
+            entry("AppName", "Inno", -1),
+        ];
+
+        assert_eq!(
+            MessageEntry::missing_languages(&entries, 3),
+            HashMap::from([
+                ("Greeting", vec![2]),
+                ("Farewell", vec![1, 2]),
+                ("AppName", vec![]),
+            ])
+        );
+    }
+
+    #[rstest]
+    #[case::none("Hello", 0)]
+    #[case::one("Hello %1", 1)]
+    #[case::repeated("%1 and %1", 1)]
+    #[case::highest("%2 before %1", 2)]
+    #[case::gap("Only %3", 3)]
+    #[case::escaped("100%%1", 0)]
+    #[case::zero("%0", 0)]
+    #[case::trailing("50%", 0)]
+    fn placeholder_count(#[case] value: &str, #[case] expected: usize) {
+        assert_eq!(entry("Message", value, 0).placeholder_count(), expected);
+    }
 }
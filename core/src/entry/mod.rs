@@ -17,19 +17,19 @@
 mod r#type;
 
 pub use checksum::Checksum;
-pub use component::Component;
+pub use component::{Component, ComponentFlags};
 pub use condition::Condition;
 pub use delete::{DeleteEntry, TargetType};
-pub use directory::Directory;
+pub use directory::{Directory, DirectoryFlags};
 pub use file::File;
-pub use icon::{Icon, IconFlags};
-pub use ini::Ini;
+pub use icon::{CloseSetting, Icon, IconFlags};
+pub use ini::{Ini, IniFlags};
 pub use is_sig_key::ISSigKey;
 pub use language::Language;
 pub use location::{CompressionFilter, FileLocation, FileLocationFlags, SignMode};
 pub use message::{Message, MessageEntry};
 pub use permission::Permission;
-pub use registry::{RegRoot, RegistryEntry};
+pub use registry::{RegRoot, RegView, RegistryEntry, RegistryFlags, RegistryValueType};
 pub use run::{RunEntry, RunFlags, WaitCondition};
-pub use task::Task;
+pub use task::{Task, TaskFlags};
 pub use r#type::Type;
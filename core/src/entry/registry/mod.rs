@@ -1,6 +1,7 @@
 mod flags;
 mod root;
 mod r#type;
+mod view;
 
 use std::io;
 
@@ -8,12 +9,13 @@
 pub use flags::RegistryFlags;
 pub use root::RegRoot;
 pub use r#type::RegistryValueType;
+pub use view::RegView;
 use zerocopy::{LE, try_transmute};
 
-use super::Condition;
+use super::{Condition, Permission};
 use crate::{
-    InnoVersion, ReadBytesExt, WindowsVersionRange, header::flag_reader::read_flags::read_flags,
-    string::PascalString,
+    Inno, InnoVersion, ReadBytesExt, WindowsVersionRange,
+    header::flag_reader::read_flags::read_flags, string::PascalString,
 };
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -21,6 +23,7 @@ pub struct RegistryEntry {
     key: Option<String>,
     name: Option<String>,
     value: Option<PascalString>,
+    condition: Condition,
     permissions: Option<PascalString>,
     reg_root: RegRoot,
     permission: i16,
@@ -48,7 +51,7 @@ pub fn read<R>(
             ..Self::default()
         };
 
-        Condition::read(&mut reader, codepage, version)?;
+        registry.condition = Condition::read(&mut reader, codepage, version)?;
 
         if ((4, 0, 11)..(4, 1, 0)).contains(&version) {
             registry.permissions = reader.read_pascal_string()?;
@@ -56,6 +59,8 @@ pub fn read<R>(
 
         WindowsVersionRange::read_from(&mut reader, version)?;
 
+        // Roots are stored as predefined key handles, such as `HKEY_CLASSES_ROOT` (0x80000000), so
+        // the high bit that marks a handle as predefined is removed to get the root's index.
         registry.reg_root =
             try_transmute!(reader.read_u32::<LE>()? & !0x8000_0000).unwrap_or_default();
 
@@ -103,6 +108,13 @@ pub fn value(&self) -> Option<&str> {
         self.value.as_ref().map(PascalString::as_str)
     }
 
+    /// Returns the conditions that determine whether the registry entry is written.
+    #[must_use]
+    #[inline]
+    pub const fn condition(&self) -> &Condition {
+        &self.condition
+    }
+
     /// Returns the permissions as a string slice.
     #[must_use]
     pub fn permissions(&self) -> Option<&str> {
@@ -110,12 +122,28 @@ pub fn permissions(&self) -> Option<&str> {
     }
 
     /// Returns the registry root.
+    ///
+    /// Inno Setup stores the root as a predefined key handle, such as `HKEY_LOCAL_MACHINE`
+    /// (0x80000002), which always has its high bit set. The high bit is removed when reading, so it
+    /// carries no information about the entry.
     #[must_use]
     #[inline]
     pub const fn registry_root(&self) -> RegRoot {
         self.reg_root
     }
 
+    /// Returns the registry root along with the [view] of the registry that the entry is written
+    /// to, which is determined by the [`BITS_32`] and [`BITS_64`] flags.
+    ///
+    /// [view]: RegView
+    /// [`BITS_32`]: RegistryFlags::BITS_32
+    /// [`BITS_64`]: RegistryFlags::BITS_64
+    #[must_use]
+    #[inline]
+    pub fn effective_root(&self) -> (RegRoot, RegView) {
+        (self.reg_root, RegView::from(self.flags))
+    }
+
     /// Returns the permission index.
     #[must_use]
     #[inline]
@@ -123,6 +151,19 @@ pub const fn permission(&self) -> i16 {
         self.permission
     }
 
+    /// Returns the [`Permission`] entry that applies to the registry entry, or `None` if it has no
+    /// permission entry.
+    ///
+    /// See [`Inno::permission_at`].
+    #[must_use]
+    #[inline]
+    pub fn resolved_permission<'inno, R>(&self, inno: &'inno Inno<R>) -> Option<&'inno Permission>
+    where
+        R: io::Read + io::Seek,
+    {
+        inno.permission_at(self.permission)
+    }
+
     /// Returns the registry value type.
     #[must_use]
     #[inline]
@@ -144,6 +185,7 @@ fn default() -> Self {
             key: None,
             name: None,
             value: None,
+            condition: Condition::default(),
             permissions: None,
             reg_root: RegRoot::default(),
             permission: -1,
@@ -31,6 +31,22 @@ pub const fn as_str(&self) -> &'static str {
             Self::Unset => "Unset",
         }
     }
+
+    /// Returns the abbreviated name of the registry root, such as `HKLM` for
+    /// `HKEY_LOCAL_MACHINE`, as used in Inno Setup scripts and the `reg` command.
+    #[must_use]
+    pub const fn short(&self) -> &'static str {
+        match self {
+            Self::HKeyClassesRoot => "HKCR",
+            Self::HKeyCurrentUser => "HKCU",
+            Self::HKeyLocalMachine => "HKLM",
+            Self::HKeyUsers => "HKU",
+            Self::HKeyPerformanceData => "HKPD",
+            Self::HKeyCurrentConfig => "HKCC",
+            Self::HKeyDynamicData => "HKDD",
+            Self::Unset => "Unset",
+        }
+    }
 }
 
 impl fmt::Display for RegRoot {
@@ -40,6 +40,23 @@ pub const fn as_str(self) -> &'static str {
             Self::QWord => "QWord",
         }
     }
+
+    /// Returns the name of the Windows registry value type, such as `REG_SZ` or `REG_DWORD`.
+    ///
+    /// [`None`](Self::None) is returned as `REG_NONE`, although Inno Setup uses it for entries
+    /// that only create a key and no value.
+    #[must_use]
+    pub const fn as_reg_type(self) -> &'static str {
+        match self {
+            Self::None => "REG_NONE",
+            Self::String => "REG_SZ",
+            Self::ExpandString => "REG_EXPAND_SZ",
+            Self::DWord => "REG_DWORD",
+            Self::Binary => "REG_BINARY",
+            Self::MultiString => "REG_MULTI_SZ",
+            Self::QWord => "REG_QWORD",
+        }
+    }
 }
 
 impl fmt::Display for RegistryValueType {
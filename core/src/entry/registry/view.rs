@@ -0,0 +1,63 @@
+use std::fmt;
+
+use super::RegistryFlags;
+
+/// The view of the registry that a registry entry is written to on 64-bit Windows.
+///
+/// <https://learn.microsoft.com/en-us/windows/win32/winprog64/registry-redirector>
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum RegView {
+    /// The view is determined by the install mode, which is the 64-bit view when installing in
+    /// 64-bit mode and the 32-bit view otherwise.
+    #[default]
+    Default,
+    /// The 32-bit view, which is redirected to `Wow6432Node` on 64-bit Windows.
+    Wow6432,
+    /// The native 64-bit view.
+    Native64,
+}
+
+impl RegView {
+    /// Returns the registry view as a static string.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::Wow6432 => "32-bit",
+            Self::Native64 => "64-bit",
+        }
+    }
+}
+
+impl From<RegistryFlags> for RegView {
+    fn from(flags: RegistryFlags) -> Self {
+        if flags.contains(RegistryFlags::BITS_64) {
+            Self::Native64
+        } else if flags.contains(RegistryFlags::BITS_32) {
+            Self::Wow6432
+        } else {
+            Self::Default
+        }
+    }
+}
+
+impl fmt::Display for RegView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{RegView, RegistryFlags};
+
+    #[rstest]
+    #[case::default(RegistryFlags::empty(), RegView::Default)]
+    #[case::bits_32(RegistryFlags::BITS_32, RegView::Wow6432)]
+    #[case::bits_64(RegistryFlags::BITS_64 | RegistryFlags::NO_ERROR, RegView::Native64)]
+    fn from_flags(#[case] flags: RegistryFlags, #[case] expected: RegView) {
+        assert_eq!(RegView::from(flags), expected);
+    }
+}
@@ -3,19 +3,35 @@
 use bitflags::bitflags;
 
 bitflags! {
+    /// Flags for a [`RunEntry`](super::RunEntry), named after their script equivalents.
     #[derive(Clone, Copy, Default, Eq, PartialEq)]
     pub struct RunFlags: u16 {
+        /// `shellexec`: the program is started with `ShellExecuteEx` instead of `CreateProcess`,
+        /// so that documents and URLs can be opened.
         const SHELL_EXECUTE = 1;
+        /// `skipifdoesntexist`: no error is shown if the program doesn't exist.
         const SKIP_IF_DOESNT_EXIST = 1 << 1;
+        /// `postinstall`: the entry is shown as a checkbox on the Setup Completed page.
         const POST_INSTALL = 1 << 2;
+        /// `unchecked`: the [`POST_INSTALL`](Self::POST_INSTALL) checkbox is unchecked by
+        /// default.
         const UNCHECKED = 1 << 3;
+        /// `skipifsilent`: the entry is skipped when Setup is running silently.
         const SKIP_IF_SILENT = 1 << 4;
+        /// `skipifnotsilent`: the entry is skipped when Setup is not running silently.
         const SKIP_IF_NOT_SILENT = 1 << 5;
+        /// `hidewizard`: the wizard is hidden while the program runs.
         const HIDE_WIZARD = 1 << 6;
+        /// `32bit`: the program runs with 32-bit file system redirection.
         const BITS_32 = 1 << 7;
+        /// `64bit`: the program runs without 32-bit file system redirection.
         const BITS_64 = 1 << 8;
+        /// `runasoriginaluser`: the program runs with the credentials of the user that started
+        /// Setup, rather than as an elevated administrator.
         const RUN_AS_ORIGINAL_USER = 1 << 9;
+        /// `dontlogparameters`: the parameters are not included in the log file.
         const DONT_LOG_PARAMETERS = 1 << 10;
+        /// `logoutput`: the output of the program is included in the log file.
         const LOG_OUTPUT = 1 << 11;
     }
 }
@@ -29,3 +45,9 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         }
     }
 }
+
+impl fmt::Display for RunFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        bitflags::parser::to_writer(self, f)
+    }
+}
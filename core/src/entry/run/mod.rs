@@ -15,15 +15,16 @@
     version::{InnoVersion, windows_version::WindowsVersionRange},
 };
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct RunEntry {
-    name: Option<String>,
+    pub(crate) name: Option<String>,
     parameters: Option<String>,
     working_directory: Option<String>,
     run_once_id: Option<String>,
     status_message: Option<String>,
     verb: Option<String>,
     description: Option<String>,
+    pub(crate) condition: Condition,
     show_command: i32,
     wait_condition: WaitCondition,
     options: RunFlags,
@@ -65,7 +66,7 @@ pub fn read<R>(
             run_entry.description = reader.read_decoded_pascal_string(codepage)?;
         }
 
-        Condition::read(&mut reader, codepage, version)?;
+        run_entry.condition = Condition::read(&mut reader, codepage, version)?;
 
         WindowsVersionRange::read_from(&mut reader, version)?;
 
@@ -116,6 +117,9 @@ pub fn working_directory(&self) -> Option<&str> {
     }
 
     /// Returns the `RunOnceId` of the `RunEntry` as a string slice.
+    ///
+    /// This is only used by `[UninstallRun]` entries. Entries that share a `RunOnceId` are only
+    /// run once during uninstallation, even if the application was installed several times.
     #[must_use]
     #[inline]
     pub fn run_once_id(&self) -> Option<&str> {
@@ -144,6 +148,13 @@ pub fn description(&self) -> Option<&str> {
         self.description.as_deref()
     }
 
+    /// Returns the conditions that determine whether the entry is run.
+    #[must_use]
+    #[inline]
+    pub const fn condition(&self) -> &Condition {
+        &self.condition
+    }
+
     /// Returns show command of the `RunEntry` as a string slice.
     #[must_use]
     #[inline]
@@ -164,4 +175,53 @@ pub const fn wait_condition(&self) -> WaitCondition {
     pub const fn options(&self) -> RunFlags {
         self.options
     }
+
+    /// Returns the indices of the entries grouped by their `RunOnceId`, in order of each group's
+    /// first entry.
+    ///
+    /// Entries without a `RunOnceId` each form a group of one.
+    pub(crate) fn group_by_once_id(entries: &[Self]) -> Vec<Vec<usize>> {
+        let mut groups = Vec::<Vec<usize>>::new();
+
+        for (index, entry) in entries.iter().enumerate() {
+            let group = entry.run_once_id().and_then(|id| {
+                groups
+                    .iter_mut()
+                    .find(|group| entries[group[0]].run_once_id() == Some(id))
+            });
+
+            match group {
+                Some(group) => group.push(index),
+                None => groups.push(vec![index]),
+            }
+        }
+
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RunEntry;
+
+    #[test]
+    fn group_by_once_id() {
+        let entry = |run_once_id: Option<&str>| RunEntry {
+            run_once_id: run_once_id.map(str::to_owned),
+            ..RunEntry::default()
+        };
+
+        let entries = [
+            entry(Some("DelService")),
+            entry(None),
+            entry(Some("StopApp")),
+            entry(Some("DelService")),
+            entry(None),
+        ];
+
+        assert_eq!(
+            RunEntry::group_by_once_id(&entries),
+            [vec![0, 3], vec![1], vec![2], vec![4]]
+        );
+    }
 }
@@ -1,13 +1,17 @@
-use std::io;
+use std::{fmt, io};
 
 use zerocopy::{Immutable, KnownLayout, TryFromBytes, ValidityError, try_transmute};
 
+/// How Setup waits for a [`RunEntry`](super::RunEntry) program to finish before continuing.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Immutable, KnownLayout, TryFromBytes)]
 #[repr(u8)]
 pub enum WaitCondition {
+    /// Wait until the program has terminated. This is the default.
     #[default]
     WaitUntilTerminated = 0,
+    /// Don't wait for the program to finish (`nowait`).
     NoWait = 1,
+    /// Wait until the program is waiting for user input (`waituntilidle`).
     WaitUntilIdle = 2,
 }
 
@@ -22,6 +26,22 @@ pub fn try_read_from_io<R>(mut src: R) -> io::Result<Self>
         Self::try_read_from_bytes(&buf)
             .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))
     }
+
+    /// Returns the wait condition as a static string.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::WaitUntilTerminated => "Wait until terminated",
+            Self::NoWait => "No wait",
+            Self::WaitUntilIdle => "Wait until idle",
+        }
+    }
+}
+
+impl fmt::Display for WaitCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
 }
 
 impl TryFrom<u8> for WaitCondition {
@@ -31,3 +51,19 @@ fn try_from(value: u8) -> Result<Self, Self::Error> {
         try_transmute!(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::WaitCondition;
+
+    #[rstest]
+    #[case(WaitCondition::WaitUntilTerminated, "Wait until terminated")]
+    #[case(WaitCondition::NoWait, "No wait")]
+    #[case(WaitCondition::WaitUntilIdle, "Wait until idle")]
+    fn display(#[case] wait_condition: WaitCondition, #[case] expected: &str) {
+        assert_eq!(wait_condition.as_str(), expected);
+        assert_eq!(wait_condition.to_string(), expected);
+    }
+}
@@ -2,7 +2,10 @@
 
 use thiserror::Error;
 
-use super::{InnoVersion, MAX_SUPPORTED_VERSION, entry::checksum::ChecksumMismatchError};
+use super::{
+    InnoVersion, MAX_SUPPORTED_VERSION, MIN_SUPPORTED_VERSION,
+    entry::checksum::ChecksumMismatchError,
+};
 
 pub type InnoResult<T> = Result<T, InnoError>;
 
@@ -10,26 +13,46 @@
 pub enum InnoError {
     #[error("File is not an Inno installer")]
     NotInnoFile,
+    #[error("File is an Inno Setup uninstaller, which does not contain any setup data")]
+    UninstallerExecutable,
+    #[error("Installer is truncated: expected at least {expected} bytes, got {actual}")]
+    Truncated { expected: u64, actual: u64 },
     #[error("Unexpected data at end of {0} Inno header stream")]
     UnexpectedExtraData(HeaderStream),
+    #[error("Invalid block header for {stream} Inno header stream at byte offset {offset}")]
+    InvalidStreamHeader {
+        stream: HeaderStream,
+        offset: u64,
+        #[source]
+        source: io::Error,
+    },
     #[error(
-        "Inno Setup version {0} is newer than the maximum supported version {max_version}",
+        "Inno Setup version {0} is outside the supported range of {min_version} to {max_version}",
+        min_version = MIN_SUPPORTED_VERSION,
         max_version = MAX_SUPPORTED_VERSION
     )]
     UnsupportedVersion(InnoVersion),
     #[error("Unknown Inno setup version: {0}")]
     UnknownVersion(String),
-    #[error("Unknown Inno Setup loader signature: {0:?}")]
-    UnknownLoaderSignature([u8; 12]),
     #[error(
-        "Inno Setup checksum mismatch reading {location}. Expected {} but calculated {}",
-        inner.expected(),
-        inner.actual()
+        "Executable appears to be packed{}, so the setup loader could not be located. Unpack it first",
+        packer.map(|packer| format!(" with {packer}")).unwrap_or_default()
     )]
+    PackedExecutable { packer: Option<&'static str> },
+    #[error("Unknown Inno Setup loader signature: {0:?}")]
+    UnknownLoaderSignature([u8; 12]),
+    #[error("{algorithm} mismatch in {location}", algorithm = inner.algorithm())]
     ChecksumMismatch {
         location: &'static str,
+        #[source]
         inner: ChecksumMismatchError,
     },
+    #[error("Header declares {declared} {phase} entries but {parsed} were parsed")]
+    EntryCountMismatch {
+        phase: super::ParsePhase,
+        declared: u32,
+        parsed: usize,
+    },
     #[error("Unsupported {0} compression")]
     UnsupportedCompression(super::header::Compression),
     #[cfg(feature = "extract")]
@@ -42,12 +65,77 @@ pub enum InnoError {
     )]
     InvalidChunkMagic([u8; 4]),
     #[cfg(feature = "extract")]
+    #[error(
+        "Invalid disk slice magic: expected {magic}, got {0:02X?}",
+        magic=super::read::slice::SliceID::Bits32
+    )]
+    InvalidSliceMagic([u8; 8]),
+    #[cfg(feature = "extract")]
+    #[error("Disk slice {slice} is {actual} bytes but its header declares {expected} bytes")]
+    InvalidSliceSize {
+        slice: u32,
+        expected: u32,
+        actual: u64,
+    },
+    #[cfg(feature = "extract")]
     #[error("File location index {index} is out of bounds (max: {max})")]
     FileLocationOutOfBounds { index: u32, max: usize },
-    #[error(transparent)]
+    #[error("I/O error")]
     Io(#[from] io::Error),
 }
 
+/// A problem with an installer that was tolerated while parsing it, because
+/// [strict mode](crate::InnoBuilder::strict) is disabled.
+///
+/// In strict mode, each of these is an error instead.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ParseWarning {
+    /// The header stream was padded with zeros after its last section.
+    ZeroPadding(HeaderStream),
+    /// The header stream ended before the number of entries declared by the header had been read.
+    EntryCountMismatch {
+        phase: super::ParsePhase,
+        declared: u32,
+        parsed: usize,
+    },
+}
+
+impl fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroPadding(stream) => {
+                write!(f, "The {stream} Inno header stream is padded with zeros")
+            }
+            Self::EntryCountMismatch {
+                phase,
+                declared,
+                parsed,
+            } => write!(
+                f,
+                "Header declares {declared} {phase} entries but the stream ends after {parsed}"
+            ),
+        }
+    }
+}
+
+impl From<ParseWarning> for InnoError {
+    fn from(warning: ParseWarning) -> Self {
+        match warning {
+            ParseWarning::ZeroPadding(stream) => Self::UnexpectedExtraData(stream),
+            ParseWarning::EntryCountMismatch {
+                phase,
+                declared,
+                parsed,
+            } => Self::EntryCountMismatch {
+                phase,
+                declared,
+                parsed,
+            },
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum HeaderStream {
     Primary,
@@ -70,3 +158,47 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.as_str().fmt(f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{error::Error, io};
+
+    use super::InnoError;
+    use crate::entry::checksum::ChecksumMismatchError;
+
+    #[test]
+    fn io_source() {
+        let error = InnoError::from(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "stream ended early",
+        ));
+
+        assert_eq!(
+            error
+                .source()
+                .unwrap()
+                .downcast_ref::<io::Error>()
+                .unwrap()
+                .kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+    }
+
+    #[test]
+    fn checksum_mismatch_source() {
+        let error = InnoError::ChecksumMismatch {
+            location: "Setup Loader",
+            inner: ChecksumMismatchError::new_crc32(1, 2),
+        };
+
+        assert!(
+            error
+                .source()
+                .is_some_and(|source| source.is::<ChecksumMismatchError>())
+        );
+        assert_eq!(
+            format!("{error}: {}", error.source().unwrap()),
+            "CRC-32 mismatch in Setup Loader: expected 0x00000001, got 0x00000002"
+        );
+    }
+}
@@ -0,0 +1,170 @@
+//! How the data of each file is located within an installer.
+//!
+//! This is only needed by consumers that implement extraction themselves. With the `extract`
+//! feature, `Inno::files` does all of this.
+//!
+//! # Data stream
+//!
+//! The compressed file data of an installer is stored in a data stream, which is either embedded
+//! in the setup executable or split across external disk slice files such as `setup-1.bin`.
+//!
+//! - If the data is embedded, it starts at [`Inno::data_base_offset`], which is the `Offset1`
+//!   value of the [setup loader](crate::Inno::setup_loader).
+//! - If the installer is [disk spanned](crate::Inno::is_disk_spanned), the data base offset is 0
+//!   and each slice file starts with an 8-byte `idska32\x1a` magic and a 4-byte slice size.
+//!
+//! # Chunks
+//!
+//! The data stream is a sequence of chunks. Each [`FileLocation`] refers to a
+//! [chunk](crate::entry::location::FileLocation::chunk):
+//!
+//! - `Chunk::first_slice` is the slice that the chunk starts in, which is always 0 for embedded
+//!   data.
+//! - `Chunk::start_offset` is the offset of the chunk from the data base offset, or from the start
+//!   of the slice file for disk-spanned installers. `Chunk::sub_offset` has the same value.
+//! - Each chunk starts with the 4-byte `zlb\x1a` magic, followed by `Chunk::original_size` bytes
+//!   of data that are compressed with `Chunk::compression` and may be encrypted.
+//!
+//! Several files may share a chunk, in which case the installer is said to use solid compression.
+//! [`Inno::extraction_plan`](crate::Inno::extraction_plan) groups the files by the chunk that
+//! stores them, so that each chunk only needs to be decompressed once.
+//!
+//! # Files
+//!
+//! Once the chunk has been decrypted and decompressed, the file's data is the
+//! [`File::size`](crate::entry::location::File::size) bytes at
+//! [`File::offset`](crate::entry::location::File::offset) within the decompressed chunk. This data
+//! then has the [compression filter] reverted, and is verified against the
+//! [checksum](crate::entry::location::File::checksum).
+//!
+//! # Example
+//!
+//! Consider an installer whose embedded data starts at `0x1A2C00`, with a file in a chunk at a
+//! start offset of `0x5B0`, at an offset of `0x200` within the decompressed chunk:
+//!
+//! | Step                                      | Offset     |
+//! |-------------------------------------------|------------|
+//! | [`Inno::data_base_offset`]                | `0x1A2C00` |
+//! | \+ `Chunk::start_offset`                  | `0x1A31B0` |
+//! | \+ 4 bytes of `zlb\x1a` magic             | `0x1A31B4` |
+//! | \+ `File::offset`, if the chunk is stored | `0x1A33B4` |
+//!
+//! The compressed data of the chunk starts at `0x1A31B4` in the setup executable. If the chunk is
+//! neither compressed nor encrypted, the file's data can be read directly at `0x1A33B4`, which is
+//! what [`FileLocation::stored_data_offset`] returns. Otherwise, the chunk is decompressed from
+//! `0x1A31B4` and the file's data is at `0x200` in the decompressed output.
+//!
+//! [`Inno::data_base_offset`]: crate::Inno::data_base_offset
+//! [`FileLocation`]: crate::entry::location::FileLocation
+//! [`FileLocation::stored_data_offset`]: crate::entry::location::FileLocation::stored_data_offset
+//! [compression filter]: crate::entry::location::CompressionFilter
+
+use crate::entry::File;
+pub use crate::read::chunk::{Chunk, Encryption};
+
+/// A chunk of the data stream and the files that are stored in it, in the order that they are
+/// read from the decompressed chunk.
+///
+/// This is created by [`Inno::extraction_plan`](crate::Inno::extraction_plan). Each chunk only
+/// needs to be decompressed once to extract every file in it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChunkPlan<'a> {
+    chunk: Chunk,
+    members: Vec<ChunkMember<'a>>,
+}
+
+impl<'a> ChunkPlan<'a> {
+    pub(crate) const fn new(chunk: Chunk) -> Self {
+        Self {
+            chunk,
+            members: Vec::new(),
+        }
+    }
+
+    pub(crate) fn push(&mut self, member: ChunkMember<'a>) {
+        self.members.push(member);
+    }
+
+    pub(crate) fn sort(&mut self) {
+        self.members.sort_by_key(ChunkMember::offset);
+    }
+
+    /// Returns the chunk, which has its offset in the data stream, its compression and whether it
+    /// is encrypted.
+    #[must_use]
+    #[inline]
+    pub const fn chunk(&self) -> &Chunk {
+        &self.chunk
+    }
+
+    /// Returns the files that are stored in the chunk, sorted by their offset within the
+    /// decompressed chunk.
+    ///
+    /// Files that share the same data keep the order in which they appear in the script.
+    #[must_use]
+    #[inline]
+    pub const fn members(&self) -> &[ChunkMember<'a>] {
+        self.members.as_slice()
+    }
+}
+
+/// A file that is stored in a [`ChunkPlan`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ChunkMember<'a> {
+    file: &'a File,
+    offset: u64,
+    size: u64,
+}
+
+impl<'a> ChunkMember<'a> {
+    pub(crate) const fn new(file: &'a File, offset: u64, size: u64) -> Self {
+        Self { file, offset, size }
+    }
+
+    /// Returns the file entry.
+    #[must_use]
+    #[inline]
+    pub const fn file(&self) -> &'a File {
+        self.file
+    }
+
+    /// Returns the offset of the file's data within the decompressed chunk.
+    #[must_use]
+    #[inline]
+    pub const fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns the size of the file's data within the decompressed chunk.
+    #[must_use]
+    #[inline]
+    pub const fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChunkMember, ChunkPlan};
+    use crate::{entry::File, read::chunk::Chunk};
+
+    #[test]
+    fn members_sorted_by_offset() {
+        let file = File::default();
+
+        let mut plan = ChunkPlan::new(Chunk::default());
+        plan.push(ChunkMember::new(&file, 0x200, 0x10));
+        plan.push(ChunkMember::new(&file, 0, 0x200));
+        plan.push(ChunkMember::new(&file, 0x200, 0x20));
+        plan.sort();
+
+        let members = plan
+            .members()
+            .iter()
+            .map(|member| (member.offset(), member.size()))
+            .collect::<Vec<_>>();
+
+        // Members at the same offset keep the order they were added in
+        assert_eq!(members, [(0, 0x200), (0x200, 0x10), (0x200, 0x20)]);
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::entry::{File, FileLocation, file::FileType};
+
+/// Aggregate statistics about the files in an installer.
+///
+/// This struct is created by [`Inno::file_stats`](crate::Inno::file_stats).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FileStats {
+    count: usize,
+    type_counts: [usize; 3],
+    root_counts: BTreeMap<String, usize>,
+    uncompressed_size: u64,
+    compressed_size: u64,
+}
+
+impl FileStats {
+    pub(crate) fn new<'inno, I>(files: I) -> Self
+    where
+        I: IntoIterator<Item = (&'inno File, Option<&'inno FileLocation>)>,
+    {
+        let mut stats = Self::default();
+        let mut chunks = HashSet::new();
+
+        for (file, location) in files {
+            stats.count += 1;
+            stats.type_counts[file.r#type() as usize] += 1;
+
+            if let Some(root) = file.destination().map(root) {
+                *stats.root_counts.entry(root).or_default() += 1;
+            }
+
+            match location {
+                Some(location) => {
+                    stats.uncompressed_size = stats
+                        .uncompressed_size
+                        .saturating_add(location.uncompressed_size());
+
+                    let chunk = location.chunk();
+                    if chunks.insert((chunk.first_slice(), chunk.start_offset())) {
+                        stats.compressed_size =
+                            stats.compressed_size.saturating_add(chunk.original_size());
+                    }
+                }
+                None => {
+                    stats.uncompressed_size =
+                        stats.uncompressed_size.saturating_add(file.external_size());
+                }
+            }
+        }
+
+        stats
+    }
+
+    /// Returns the total number of file entries.
+    #[must_use]
+    #[inline]
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the number of file entries of the given type.
+    #[must_use]
+    #[inline]
+    pub const fn count_by_type(&self, r#type: FileType) -> usize {
+        self.type_counts[r#type as usize]
+    }
+
+    /// Returns the number of file entries installed under each root of their destination, such as
+    /// `{app}`, `{sys}` or `{tmp}`.
+    ///
+    /// Roots are lowercased, so `{App}` and `{app}` are counted together.
+    #[must_use]
+    #[inline]
+    pub const fn root_counts(&self) -> &BTreeMap<String, usize> {
+        &self.root_counts
+    }
+
+    /// Returns the total uncompressed size of the files, in bytes.
+    ///
+    /// Files that are not embedded in the installer count their
+    /// [external size](File::external_size).
+    #[must_use]
+    #[inline]
+    pub const fn uncompressed_size(&self) -> u64 {
+        self.uncompressed_size
+    }
+
+    /// Returns the total compressed size of the data chunks that hold the files, in bytes.
+    ///
+    /// Chunks that hold multiple files, as in solid compression, are only counted once.
+    #[must_use]
+    #[inline]
+    pub const fn compressed_size(&self) -> u64 {
+        self.compressed_size
+    }
+}
+
+/// Returns the first component of a destination path, lowercased.
+fn root(destination: &str) -> String {
+    destination
+        .split(['\\', '/'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::root;
+
+    #[rstest]
+    #[case(r"{app}\MyProg.exe", "{app}")]
+    #[case(r"{App}\bin\MyProg.exe", "{app}")]
+    #[case("{tmp}/setup.dll", "{tmp}")]
+    #[case("{sys}", "{sys}")]
+    fn destination_root(#[case] destination: &str, #[case] expected: &str) {
+        assert_eq!(root(destination), expected);
+    }
+}
@@ -3,13 +3,49 @@
 use bitflags::bitflags;
 
 bitflags! {
-    /// Used before Inno Setup 6.3 where the architecture was stored in a single byte
-    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    /// The set of architectures as stored from Inno Setup 5.1 up to, but not including, 6.3,
+    /// where `ArchitecturesAllowed` and `ArchitecturesInstallIn64BitMode` were each stored as a
+    /// single byte rather than as an expression.
+    ///
+    /// Installers built with these versions are parsed into an [`Architecture`] using its
+    /// [`From`] implementation, which maps each stored architecture to the equivalent identifier
+    /// of the expression syntax:
+    ///
+    /// | Stored    | Architecture                     |
+    /// |-----------|----------------------------------|
+    /// | `UNKNOWN` | None                             |
+    /// | `X86`     | [`X86_OS`](Architecture::X86_OS) |
+    /// | `AMD64`   | [`X64_OS`](Architecture::X64_OS) |
+    /// | `IA64`    | [`X64_OS`](Architecture::X64_OS) |
+    /// | `ARM64`   | [`ARM64`](Architecture::ARM64)   |
+    ///
+    /// An empty set of architectures in `ArchitecturesInstallIn64BitMode` means that the installer
+    /// never installs in 64-bit mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inno::header::{Architecture, StoredArchitecture};
+    ///
+    /// let stored = StoredArchitecture::AMD64 | StoredArchitecture::ARM64;
+    ///
+    /// assert_eq!(
+    ///     Architecture::from(stored),
+    ///     Architecture::X64_OS | Architecture::ARM64
+    /// );
+    /// ```
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
     pub struct StoredArchitecture: u8 {
+        /// An architecture that is not known to Inno Setup.
         const UNKNOWN = 1;
+        /// 32-bit x86, written as `x86` in the script.
         const X86 = 1 << 1;
+        /// 64-bit x64, written as `x64` in the script.
         const AMD64 = 1 << 2;
+        /// Itanium, written as `ia64` in the script. Itanium support was removed in Inno Setup
+        /// 5.6.1.
         const IA64 = 1 << 3;
+        /// 64-bit Arm, written as `arm64` in the script.
         const ARM64 = 1 << 4;
     }
 }
@@ -30,7 +66,7 @@ fn from(value: StoredArchitecture) -> Self {
 
 bitflags! {
     /// <https://jrsoftware.org/ishelp/index.php?topic=archidentifiers>
-    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
     pub struct Architecture: u8 {
         /// Matches systems capable of running 32-bit Arm binaries. Only Arm64 Windows includes such
         /// support.
@@ -72,7 +108,104 @@ enum Token<'token> {
 }
 
 impl Architecture {
+    /// The identifier of each architecture as written in an `ArchitecturesAllowed` or
+    /// `ArchitecturesInstallIn64BitMode` expression.
+    pub(crate) const IDENTIFIERS: [(Self, &'static str); 7] = [
+        (Self::ARM32_COMPATIBLE, "arm32compatible"),
+        (Self::ARM64, "arm64"),
+        (Self::WIN64, "win64"),
+        (Self::X64_COMPATIBLE, "x64compatible"),
+        (Self::X64_OS, "x64os"),
+        (Self::X86_COMPATIBLE, "x86compatible"),
+        (Self::X86_OS, "x86os"),
+    ];
+
+    /// Returns an iterator over the Inno Setup identifier of each set architecture, such as
+    /// `x64os` or `arm64`.
+    ///
+    /// Each flag is a single architecture identifier, so a compatibility flag such as
+    /// [`X86_COMPATIBLE`](Self::X86_COMPATIBLE) yields `x86compatible` rather than the
+    /// architectures that are compatible with it.
+    ///
+    /// This differs from the [`iter_names`](Self::iter_names) method generated by `bitflags`, which
+    /// yields the names of the Rust constants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inno::header::Architecture;
+    ///
+    /// let architectures = Architecture::X64_OS | Architecture::ARM64;
+    ///
+    /// assert_eq!(architectures.names().collect::<Vec<_>>().join(", "), "arm64, x64os");
+    /// ```
+    pub fn names(self) -> impl Iterator<Item = &'static str> {
+        Self::IDENTIFIERS
+            .into_iter()
+            .filter(move |&(flag, _)| self.contains(flag))
+            .map(|(_, name)| name)
+    }
+
+    /// Returns the architecture identifiers that match a system running the given Windows
+    /// architecture, which should be [`X86_OS`](Self::X86_OS), [`X64_OS`](Self::X64_OS) or
+    /// [`ARM64`](Self::ARM64).
+    ///
+    /// Arm64 systems are assumed to run Windows 11, so they match
+    /// [`X64_COMPATIBLE`](Self::X64_COMPATIBLE) through emulation. Any other architecture only
+    /// matches itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inno::header::Architecture;
+    ///
+    /// assert!(Architecture::matched_by(Architecture::ARM64).contains(Architecture::X64_COMPATIBLE));
+    /// assert!(!Architecture::matched_by(Architecture::ARM64).contains(Architecture::X64_OS));
+    /// ```
+    #[must_use]
+    pub fn matched_by(system: Self) -> Self {
+        if system == Self::X86_OS {
+            Self::X86_OS | Self::X86_COMPATIBLE
+        } else if system == Self::X64_OS {
+            Self::X64_OS | Self::X64_COMPATIBLE | Self::X86_COMPATIBLE | Self::WIN64
+        } else if system == Self::ARM64 {
+            Self::ARM64
+                | Self::WIN64
+                | Self::X64_COMPATIBLE
+                | Self::X86_COMPATIBLE
+                | Self::ARM32_COMPATIBLE
+        } else {
+            system
+        }
+    }
+
+    /// Returns `true` if the given `ArchitecturesAllowed` or `ArchitecturesInstallIn64BitMode`
+    /// expression is satisfied by a system that the given architecture identifiers match, such as
+    /// those returned by [`matched_by`](Self::matched_by).
+    ///
+    /// Unlike [`from_expression`](Self::from_expression), this evaluates the expression itself, so
+    /// `x64compatible and not arm64` is only satisfied by x64 systems. An empty expression is
+    /// treated as `x86compatible`.
+    #[must_use]
+    pub fn expression_matches(input: &str, identifiers: Self) -> bool {
+        Self::parse_expression(input).map_or_else(
+            || identifiers.contains(Self::X86_COMPATIBLE),
+            |expr| expr.matches(identifiers),
+        )
+    }
+
     pub fn from_expression(input: &str) -> (Self, Self) {
+        let (mut positive, negated) = Self::parse_expression(input)
+            .map_or_else(|| (Self::default(), Self::empty()), Expr::evaluate);
+
+        if positive.is_empty() {
+            positive |= Self::X86_COMPATIBLE;
+        }
+
+        (positive, negated)
+    }
+
+    fn parse_expression(input: &str) -> Option<Expr> {
         const ARM32_COMPATIBLE: &str = "arm32compatible";
         const ARM64: &str = "arm64";
         const WIN64: &str = "win64";
@@ -152,15 +285,7 @@ pub fn from_expression(input: &str) -> (Self, Self) {
             }
         }
 
-        let (mut positive, negated) = stack
-            .pop()
-            .map_or_else(|| (Self::default(), Self::empty()), Expr::evaluate);
-
-        if positive.is_empty() {
-            positive |= Self::X86_COMPATIBLE;
-        }
-
-        (positive, negated)
+        stack.pop()
     }
 }
 
@@ -173,6 +298,15 @@ enum Expr {
 }
 
 impl Expr {
+    fn matches(&self, identifiers: Architecture) -> bool {
+        match self {
+            Self::Flag(flag) => !flag.is_empty() && identifiers.contains(*flag),
+            Self::Not(expr) => !expr.matches(identifiers),
+            Self::And(left, right) => left.matches(identifiers) && right.matches(identifiers),
+            Self::Or(left, right) => left.matches(identifiers) || right.matches(identifiers),
+        }
+    }
+
     fn evaluate(self) -> (Architecture, Architecture) {
         match self {
             Self::Flag(flag) => (flag, Architecture::empty()),
@@ -311,4 +445,16 @@ fn stored_architecture_to_architecture(
     ) {
         assert_eq!(Architecture::from(stored_architecture), expected);
     }
+
+    #[rstest]
+    #[case(Architecture::empty(), &[])]
+    #[case(Architecture::X86_COMPATIBLE, &["x86compatible"])]
+    #[case(Architecture::X64_OS | Architecture::ARM64, &["arm64", "x64os"])]
+    #[case(
+        Architecture::all(),
+        &["arm32compatible", "arm64", "win64", "x64compatible", "x64os", "x86compatible", "x86os"]
+    )]
+    fn names(#[case] architecture: Architecture, #[case] expected: &[&str]) {
+        assert_eq!(architecture.names().collect::<Vec<_>>(), expected);
+    }
 }
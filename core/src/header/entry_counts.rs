@@ -4,25 +4,32 @@
 
 use crate::{read::ReadBytesExt, version::InnoVersion};
 
+/// The number of entries in each section of an installer, as declared by its [`Header`].
+///
+/// These are the counts that the parser reads entries up to, so they can be compared against the
+/// lengths of the parsed entry slices on [`Inno`] to detect discrepancies.
+///
+/// [`Header`]: super::Header
+/// [`Inno`]: crate::Inno
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct EntryCounts {
-    language: u32,
-    custom_message: u32,
-    permission: u32,
-    r#type: u32,
-    component: u32,
-    task: u32,
-    directory: u32,
-    is_sig_key: u32,
-    file: u32,
-    file_location: u32,
-    icon: u32,
-    ini: u32,
-    registry: u32,
-    install_delete: u32,
-    uninstall_delete: u32,
-    run: u32,
-    uninstall_run: u32,
+    pub(crate) language: u32,
+    pub(crate) custom_message: u32,
+    pub(crate) permission: u32,
+    pub(crate) r#type: u32,
+    pub(crate) component: u32,
+    pub(crate) task: u32,
+    pub(crate) directory: u32,
+    pub(crate) is_sig_key: u32,
+    pub(crate) file: u32,
+    pub(crate) file_location: u32,
+    pub(crate) icon: u32,
+    pub(crate) ini: u32,
+    pub(crate) registry: u32,
+    pub(crate) install_delete: u32,
+    pub(crate) uninstall_delete: u32,
+    pub(crate) run: u32,
+    pub(crate) uninstall_run: u32,
 }
 
 impl EntryCounts {
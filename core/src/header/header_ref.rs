@@ -0,0 +1,198 @@
+use std::{borrow::Cow, fmt, io};
+
+use encoding_rs::{Encoding, UTF_16LE, WINDOWS_1252};
+
+use super::HeaderString;
+use crate::{secret::Secret, version::InnoVersion};
+
+/// The strings of a [header](super::Header), borrowed from a decompressed header stream.
+///
+/// Reading a [`Header`](super::Header) allocates an owned string for each of its string fields.
+/// A `HeaderRef` instead stores the position of each string in the header stream, so it can be
+/// read without allocating, and only the strings that are accessed are decoded.
+#[derive(Clone, Copy)]
+pub struct HeaderRef<'a> {
+    version: InnoVersion,
+    strings: [Option<&'a [u8]>; HeaderString::COUNT],
+}
+
+impl<'a> HeaderRef<'a> {
+    /// Reads the strings at the start of a header stream, advancing `data` past them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) error if `data` ends before the
+    /// last string.
+    pub fn read(data: &mut &'a [u8], version: InnoVersion) -> io::Result<Self> {
+        let mut strings = [None; HeaderString::COUNT];
+
+        if version < 1.3 {
+            let _uncompressed_size = take(data, size_of::<u32>())?;
+        }
+
+        for field in HeaderString::layout(version) {
+            let length = take(data, size_of::<u32>())?;
+            let length = u32::from_le_bytes(length.try_into().unwrap_or_default());
+
+            if length != 0 {
+                strings[field as usize] = Some(take(data, length as usize)?);
+            }
+        }
+
+        Ok(Self { version, strings })
+    }
+
+    /// Returns the Inno Setup version of the header.
+    #[must_use]
+    #[inline]
+    pub const fn version(&self) -> InnoVersion {
+        self.version
+    }
+
+    /// Returns the raw bytes of a string field, or `None` if it is empty or isn't stored in this
+    /// version.
+    #[must_use]
+    #[inline]
+    pub const fn raw(&self, field: HeaderString) -> Option<&'a [u8]> {
+        self.strings[field as usize]
+    }
+
+    /// Returns a string field decoded with its default codepage.
+    ///
+    /// Fields are decoded as UTF-16LE in Unicode installers, unless they are
+    /// [always stored in a codepage](HeaderString::is_always_ansi), and as Windows-1252 otherwise.
+    /// Use [`get_with`](Self::get_with) to decode a field with the codepage of a language.
+    ///
+    /// This only allocates if the field needs to be converted to UTF-8.
+    #[must_use]
+    pub fn get(&self, field: HeaderString) -> Option<Cow<'a, str>> {
+        let codepage = if self.version.is_unicode() && !field.is_always_ansi() {
+            UTF_16LE
+        } else {
+            WINDOWS_1252
+        };
+
+        self.get_with(field, codepage)
+    }
+
+    /// Returns a string field decoded with the given codepage.
+    #[must_use]
+    pub fn get_with(
+        &self,
+        field: HeaderString,
+        codepage: &'static Encoding,
+    ) -> Option<Cow<'a, str>> {
+        self.raw(field)
+            .map(|bytes| codepage.decode_without_bom_handling(bytes).0)
+    }
+
+    /// Returns the name of the app.
+    #[must_use]
+    #[inline]
+    pub fn app_name(&self) -> Option<Cow<'a, str>> {
+        self.get(HeaderString::AppName)
+    }
+
+    /// Returns the version of the app.
+    #[must_use]
+    #[inline]
+    pub fn app_version(&self) -> Option<Cow<'a, str>> {
+        self.get(HeaderString::AppVersion)
+    }
+
+    /// Returns the publisher of the app.
+    #[must_use]
+    #[inline]
+    pub fn app_publisher(&self) -> Option<Cow<'a, str>> {
+        self.get(HeaderString::AppPublisher)
+    }
+
+    /// Returns the ID of the app.
+    #[must_use]
+    #[inline]
+    pub fn app_id(&self) -> Option<Cow<'a, str>> {
+        self.get(HeaderString::AppId)
+    }
+}
+
+impl fmt::Debug for HeaderRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("HeaderRef");
+        debug.field("version", &self.version);
+        for field in HeaderString::layout(self.version) {
+            match self.get(field) {
+                Some(value) if field == HeaderString::DefaultUserInfoSerial => {
+                    debug.field(field.as_str(), &Secret(value));
+                }
+                Some(value) => {
+                    debug.field(field.as_str(), &value);
+                }
+                None => {}
+            }
+        }
+        debug.finish_non_exhaustive()
+    }
+}
+
+/// Splits `len` bytes off the front of `data`.
+fn take<'a>(data: &mut &'a [u8], len: usize) -> io::Result<&'a [u8]> {
+    let Some((head, tail)) = data.split_at_checked(len) else {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    };
+    *data = tail;
+    Ok(head)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HeaderRef;
+    use crate::{
+        header::HeaderString,
+        version::{InnoVersion, VersionVariant},
+    };
+
+    const VERSION: InnoVersion = InnoVersion::new_with_variant(6, 7, 0, 0, VersionVariant::UNICODE);
+
+    /// Builds the string section of a header, where each field's value is its directive name.
+    fn string_section(version: InnoVersion) -> Vec<u8> {
+        let mut data = Vec::new();
+        for field in HeaderString::layout(version) {
+            let value = if version.is_unicode() && !field.is_always_ansi() {
+                field
+                    .as_str()
+                    .encode_utf16()
+                    .flat_map(u16::to_le_bytes)
+                    .collect::<Vec<_>>()
+            } else {
+                field.as_str().as_bytes().to_vec()
+            };
+            data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+            data.extend_from_slice(&value);
+        }
+        data
+    }
+
+    #[test]
+    fn read() {
+        let data = string_section(VERSION);
+        let mut remaining = data.as_slice();
+
+        let header = HeaderRef::read(&mut remaining, VERSION).unwrap();
+
+        assert!(remaining.is_empty());
+        for field in HeaderString::layout(VERSION) {
+            assert_eq!(header.get(field).as_deref(), Some(field.as_str()));
+        }
+        assert_eq!(header.app_name().as_deref(), Some("AppName"));
+        assert_eq!(header.raw(HeaderString::UninstallIconName), None);
+        assert!(format!("{header:?}").contains("DefaultUserInfoSerial: <redacted>"));
+    }
+
+    #[test]
+    fn read_truncated() {
+        let data = string_section(VERSION);
+        let mut truncated = &data[..data.len() - 1];
+
+        assert!(HeaderRef::read(&mut truncated, VERSION).is_err());
+    }
+}
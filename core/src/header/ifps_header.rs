@@ -0,0 +1,115 @@
+use zerocopy::{FromBytes, Immutable, KnownLayout, LE, U32};
+
+/// The header at the start of the compiled `[Code]` section, which is RemObjects Pascal Script
+/// (IFPS) bytecode.
+///
+/// <https://github.com/remobjects/pascalscript/blob/master/Source/uPSUtils.pas>
+#[derive(Clone, Copy, Debug, Eq, PartialEq, FromBytes, Immutable, KnownLayout)]
+#[repr(C)]
+pub struct IfpsHeader {
+    magic: U32<LE>,
+    build_number: U32<LE>,
+    type_count: U32<LE>,
+    proc_count: U32<LE>,
+    var_count: U32<LE>,
+    main_proc: U32<LE>,
+    import_table_size: U32<LE>,
+}
+
+impl IfpsHeader {
+    /// The magic bytes at the start of compiled Pascal Script bytecode.
+    pub const MAGIC: [u8; 4] = *b"IFPS";
+
+    /// The main procedure index used when the script has no main procedure, which is always the
+    /// case for Inno Setup scripts as they only consist of event functions.
+    pub const NO_MAIN_PROC: u32 = u32::MAX;
+
+    /// Reads the header from the start of the compiled bytecode, returning `None` if the bytecode
+    /// is too short or does not start with the [`MAGIC`](Self::MAGIC).
+    #[must_use]
+    pub fn read_from_code(code: &[u8]) -> Option<Self> {
+        let (header, _) = Self::read_from_prefix(code).ok()?;
+        (header.magic.to_bytes() == Self::MAGIC).then_some(header)
+    }
+
+    /// Returns the build number of the Pascal Script compiler that compiled the bytecode.
+    #[doc(alias = "PSBuildNo")]
+    #[must_use]
+    #[inline]
+    pub const fn build_number(&self) -> u32 {
+        self.build_number.get()
+    }
+
+    /// Returns the number of types declared in the bytecode.
+    #[must_use]
+    #[inline]
+    pub const fn type_count(&self) -> u32 {
+        self.type_count.get()
+    }
+
+    /// Returns the number of procedures declared in the bytecode, including imported ones.
+    #[must_use]
+    #[inline]
+    pub const fn proc_count(&self) -> u32 {
+        self.proc_count.get()
+    }
+
+    /// Returns the number of global variables declared in the bytecode.
+    #[must_use]
+    #[inline]
+    pub const fn var_count(&self) -> u32 {
+        self.var_count.get()
+    }
+
+    /// Returns the index of the main procedure, which is the entry point of the script.
+    ///
+    /// This is [`NO_MAIN_PROC`](Self::NO_MAIN_PROC) if the script has no main procedure.
+    #[doc(alias = "MainProcNo")]
+    #[must_use]
+    #[inline]
+    pub const fn main_proc(&self) -> u32 {
+        self.main_proc.get()
+    }
+
+    /// Returns the size of the import table, in bytes.
+    #[must_use]
+    #[inline]
+    pub const fn import_table_size(&self) -> u32 {
+        self.import_table_size.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IfpsHeader;
+
+    #[test]
+    fn read_from_code() {
+        let code = [
+            b"IFPS".as_slice(),
+            &23u32.to_le_bytes(),
+            &5u32.to_le_bytes(),
+            &12u32.to_le_bytes(),
+            &3u32.to_le_bytes(),
+            &u32::MAX.to_le_bytes(),
+            &0u32.to_le_bytes(),
+            &[0xFF; 8],
+        ]
+        .concat();
+
+        let header = IfpsHeader::read_from_code(&code).unwrap();
+
+        assert_eq!(header.build_number(), 23);
+        assert_eq!(header.type_count(), 5);
+        assert_eq!(header.proc_count(), 12);
+        assert_eq!(header.var_count(), 3);
+        assert_eq!(header.main_proc(), IfpsHeader::NO_MAIN_PROC);
+        assert_eq!(header.import_table_size(), 0);
+    }
+
+    #[test]
+    fn invalid_magic() {
+        assert_eq!(IfpsHeader::read_from_code(&[0; 28]), None);
+        assert_eq!(IfpsHeader::read_from_code(b"IFPS"), None);
+    }
+}
@@ -23,6 +23,7 @@ pub fn try_read_from_io<R>(mut src: R) -> io::Result<Self>
             .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))
     }
 
+    /// Returns the `InstallVerbosity` as a static string.
     #[must_use]
     pub const fn as_str(&self) -> &'static str {
         match self {
@@ -31,6 +32,28 @@ pub const fn as_str(&self) -> &'static str {
             Self::VerySilent => "Very Silent",
         }
     }
+
+    /// Returns `true` if the wizard is shown.
+    #[must_use]
+    #[inline]
+    pub const fn is_normal(&self) -> bool {
+        matches!(self, Self::Normal)
+    }
+
+    /// Returns `true` if the installer runs without the wizard, whether or not the progress
+    /// window is shown.
+    #[must_use]
+    #[inline]
+    pub const fn is_silent(&self) -> bool {
+        matches!(self, Self::Silent | Self::VerySilent)
+    }
+
+    /// Returns `true` if the installer runs without the wizard or the progress window.
+    #[must_use]
+    #[inline]
+    pub const fn is_very_silent(&self) -> bool {
+        matches!(self, Self::VerySilent)
+    }
 }
 
 impl fmt::Display for InstallVerbosity {
@@ -38,3 +61,19 @@ fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         self.as_str().fmt(f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::InstallVerbosity;
+
+    #[rstest]
+    #[case(InstallVerbosity::Normal, "Normal")]
+    #[case(InstallVerbosity::Silent, "Silent")]
+    #[case(InstallVerbosity::VerySilent, "Very Silent")]
+    fn as_str(#[case] value: InstallVerbosity, #[case] expected: &str) {
+        assert_eq!(value.as_str(), expected);
+        assert_eq!(value.to_string(), expected);
+    }
+}
@@ -1,4 +1,4 @@
-use std::io;
+use std::{fmt, io};
 
 use zerocopy::{Immutable, KnownLayout, TryFromBytes, ValidityError, try_transmute};
 
@@ -36,6 +36,12 @@ pub const fn as_str(&self) -> &'static str {
     }
 }
 
+impl fmt::Display for LanguageDetection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 impl From<HeaderFlags> for LanguageDetection {
     fn from(flags: HeaderFlags) -> Self {
         if flags.contains(HeaderFlags::DETECT_LANGUAGE_USING_LOCALE) {
@@ -53,3 +59,19 @@ fn try_from(value: u8) -> Result<Self, Self::Error> {
         try_transmute!(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::LanguageDetection;
+
+    #[rstest]
+    #[case(LanguageDetection::UILanguage, "UILanguage")]
+    #[case(LanguageDetection::LocaleLanguage, "LocaleLanguage")]
+    #[case(LanguageDetection::None, "None")]
+    fn as_str(#[case] value: LanguageDetection, #[case] expected: &str) {
+        assert_eq!(value.as_str(), expected);
+        assert_eq!(value.to_string(), expected);
+    }
+}
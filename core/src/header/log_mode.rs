@@ -23,6 +23,7 @@ pub fn try_read_from_io<R>(mut src: R) -> io::Result<Self>
             .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))
     }
 
+    /// Returns the `LogMode` as a static string.
     #[must_use]
     pub const fn as_str(&self) -> &'static str {
         match self {
@@ -31,6 +32,28 @@ pub const fn as_str(&self) -> &'static str {
             Self::Overwrite => "Overwrite",
         }
     }
+
+    /// Returns `true` if the uninstall log is appended to an existing log from a previous
+    /// installation.
+    #[must_use]
+    #[inline]
+    pub const fn is_append(&self) -> bool {
+        matches!(self, Self::Append)
+    }
+
+    /// Returns `true` if a new uninstall log is created for each installation.
+    #[must_use]
+    #[inline]
+    pub const fn is_new(&self) -> bool {
+        matches!(self, Self::New)
+    }
+
+    /// Returns `true` if an existing uninstall log is overwritten.
+    #[must_use]
+    #[inline]
+    pub const fn is_overwrite(&self) -> bool {
+        matches!(self, Self::Overwrite)
+    }
 }
 
 impl fmt::Display for LogMode {
@@ -46,3 +69,19 @@ fn try_from(value: u8) -> Result<Self, Self::Error> {
         try_transmute!(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::LogMode;
+
+    #[rstest]
+    #[case(LogMode::Append, "Append")]
+    #[case(LogMode::New, "New")]
+    #[case(LogMode::Overwrite, "Overwrite")]
+    fn as_str(#[case] value: LogMode, #[case] expected: &str) {
+        assert_eq!(value.as_str(), expected);
+        assert_eq!(value.to_string(), expected);
+    }
+}
@@ -4,15 +4,19 @@
 mod entry_counts;
 pub mod flag_reader;
 mod flags;
+mod header_ref;
+mod ifps_header;
 mod install_verbosity;
 mod language_detection;
 mod log_mode;
 mod privilege_level;
 mod privileges_required_overrides;
+mod signed_uninstaller;
+mod string_field;
 mod wizard;
 mod yes_no;
 
-use std::{fmt, io};
+use std::{borrow::Cow, fmt, io};
 
 pub use architecture::{Architecture, StoredArchitecture};
 pub use auto_bool::AutoBool;
@@ -21,19 +25,27 @@
 pub use entry_counts::EntryCounts;
 use flag_reader::read_flags::read_flags;
 pub use flags::HeaderFlags;
+pub use header_ref::HeaderRef;
+pub use ifps_header::IfpsHeader;
 pub use install_verbosity::InstallVerbosity;
 pub use language_detection::LanguageDetection;
 pub use log_mode::LogMode;
 pub use privilege_level::PrivilegeLevel;
 pub use privileges_required_overrides::PrivilegesRequiredOverrides;
+pub use signed_uninstaller::SignedUninstaller;
+pub use string_field::HeaderString;
 pub use wizard::{Color, ImageAlphaFormat, WizardSettings, WizardSizePercent, WizardStyle};
 use yes_no::YesNoStr;
 use zerocopy::LE;
 
 use super::{InnoVersion, WindowsVersionRange, read::ReadBytesExt};
 use crate::{
-    encryption::EncryptionHeader, entry::Checksum, error::InnoResult,
-    header::wizard::LightControlStyling, string::PascalString,
+    encryption::{EncryptionHeader, EncryptionUse},
+    entry::Checksum,
+    error::InnoResult,
+    header::wizard::LightControlStyling,
+    secret::Secret,
+    string::PascalString,
 };
 
 // https://github.com/jrsoftware/issrc/blob/main/Projects/Src/Shared.Struct.pas
@@ -86,19 +98,19 @@ pub struct Header {
     use_previous_setup_type: Option<PascalString>,
     use_previous_tasks: Option<PascalString>,
     use_previous_user_info: Option<PascalString>,
-    license_text: Option<String>,
-    info_before: Option<String>,
-    info_after: Option<String>,
+    license_text: Option<PascalString>,
+    info_before: Option<PascalString>,
+    info_after: Option<PascalString>,
     uninstaller_signature: Option<String>,
     compiled_code: Option<String>,
     lead_bytes: [u8; 256 / u8::BITS as usize],
-    entry_counts: EntryCounts,
+    pub(crate) entry_counts: EntryCounts,
     background_color: Color,
     background_color2: Color,
     wizard: WizardSettings,
     encryption_header: Option<EncryptionHeader>,
     extra_disk_space_required: u64,
-    slices_per_disk: u32,
+    pub(crate) slices_per_disk: u32,
     install_verbosity: InstallVerbosity,
     uninstall_log_mode: LogMode,
     uninstall_style: WizardStyle,
@@ -126,105 +138,8 @@ pub fn read<R>(mut reader: R, version: InnoVersion) -> InnoResult<Self>
             let _uncompressed_size = reader.read_u32::<LE>()?;
         }
 
-        header.app_name = reader.read_pascal_string()?;
-        header.app_versioned_name = reader.read_pascal_string()?;
-        if version >= 1.3 {
-            header.app_id = reader.read_pascal_string()?;
-        }
-        header.app_copyright = reader.read_pascal_string()?;
-        if version >= 1.3 {
-            header.app_publisher = reader.read_pascal_string()?;
-            header.app_publisher_url = reader.read_pascal_string()?;
-        }
-        if version >= (5, 1, 13) {
-            header.app_support_phone = reader.read_pascal_string()?;
-        }
-        if version >= 1.3 {
-            header.app_support_url = reader.read_pascal_string()?;
-            header.app_updates_url = reader.read_pascal_string()?;
-            header.app_version = reader.read_pascal_string()?;
-        }
-        header.default_dir_name = reader.read_pascal_string()?;
-        header.default_group_name = reader.read_pascal_string()?;
-        if version < 3 {
-            header.uninstall_icon_name = reader.read_decoded_pascal_string(WINDOWS_1252)?;
-        }
-        header.base_filename = reader.read_pascal_string()?;
-        if ((1, 3, 0)..(5, 2, 5)).contains(&version) {
-            header.license_text = reader.read_decoded_pascal_string(WINDOWS_1252)?;
-            header.info_before = reader.read_decoded_pascal_string(WINDOWS_1252)?;
-            header.info_after = reader.read_decoded_pascal_string(WINDOWS_1252)?;
-        }
-        if version >= (1, 3, 3) {
-            header.uninstall_files_dir = reader.read_pascal_string()?;
-        }
-        if version >= (1, 3, 6) {
-            header.uninstall_name = reader.read_pascal_string()?;
-            header.uninstall_icon = reader.read_pascal_string()?;
-        }
-        if version >= (1, 3, 14) {
-            header.app_mutex = reader.read_pascal_string()?;
-        }
-        if version >= 3 {
-            header.default_user_name = reader.read_pascal_string()?;
-            header.default_user_organisation = reader.read_pascal_string()?;
-        }
-        if version >= 4 || (version.is_isx() && version >= (3, 0, 6, 1)) {
-            header.default_serial = reader.read_pascal_string()?;
-        }
-        if ((4, 0, 0)..(5, 2, 5)).contains(&version) || (version.is_isx() && version >= (1, 3, 24))
-        {
-            header.compiled_code = reader.read_decoded_pascal_string(WINDOWS_1252)?;
-        }
-        if version >= (4, 2, 4) {
-            header.app_readme_file = reader.read_pascal_string()?;
-            header.app_contact = reader.read_pascal_string()?;
-            header.app_comments = reader.read_pascal_string()?;
-            header.app_modify_path = reader.read_pascal_string()?;
-        }
-        if version >= (5, 3, 8) {
-            header.create_uninstall_registry_key = reader.read_pascal_string()?;
-        }
-        if version >= (5, 3, 10) {
-            header.uninstallable = reader.read_pascal_string()?;
-        }
-        if version >= 5.5 {
-            header.close_applications_filter = reader.read_pascal_string()?;
-        }
-        if version >= (5, 5, 6) {
-            header.setup_mutex = reader.read_pascal_string()?;
-        }
-        if version >= (5, 6, 1) {
-            header.changes_environment = reader.read_pascal_string()?;
-            header.changes_associations = reader.read_pascal_string()?;
-        }
-        if version >= 6.3 {
-            header.architectures_allowed_expr = reader.read_pascal_string()?;
-            header.architectures_install_in_64_bit_mode_expr = reader.read_pascal_string()?;
-        }
-        if version >= (6, 4, 2) {
-            header.close_applications_filter_excludes = reader.read_pascal_string()?;
-        }
-        if version >= 6.5 {
-            header.seven_zip_library_name = reader.read_pascal_string()?;
-        }
-        if version >= 6.7 {
-            header.use_previous_app_dir = reader.read_pascal_string()?;
-            header.use_previous_group = reader.read_pascal_string()?;
-            header.use_previous_setup_type = reader.read_pascal_string()?;
-            header.use_previous_tasks = reader.read_pascal_string()?;
-            header.use_previous_user_info = reader.read_pascal_string()?;
-        }
-        if version >= (5, 2, 5) {
-            header.license_text = reader.read_decoded_pascal_string(WINDOWS_1252)?;
-            header.info_before = reader.read_decoded_pascal_string(WINDOWS_1252)?;
-            header.info_after = reader.read_decoded_pascal_string(WINDOWS_1252)?;
-        }
-        if ((5, 2, 1)..(5, 3, 10)).contains(&version) {
-            header.uninstaller_signature = reader.read_decoded_pascal_string(WINDOWS_1252)?;
-        }
-        if version >= (5, 2, 5) {
-            header.compiled_code = reader.read_decoded_pascal_string(WINDOWS_1252)?;
+        for field in HeaderString::layout(version) {
+            header.set_string(field, reader.read_pascal_string()?);
         }
         if version >= (2, 0, 6) && !version.is_unicode() {
             let mut buf = [0; 256 / u8::BITS as usize];
@@ -319,6 +234,8 @@ pub fn read<R>(mut reader: R, version: InnoVersion) -> InnoResult<Self>
             header.uninstall_style = WizardStyle::Modern;
         } else if version >= 2 || (version.is_isx() && version >= (1, 3, 13)) {
             header.uninstall_style = WizardStyle::try_read_from(&mut reader, version)?;
+        } else {
+            header.uninstall_style = WizardStyle::Classic;
         }
         if version >= (1, 3, 6) {
             header.dir_exists_warning = AutoBool::try_read_from_io(&mut reader)?;
@@ -368,8 +285,8 @@ pub fn read<R>(mut reader: R, version: InnoVersion) -> InnoResult<Self>
             header.architectures_install_in_64_bit_mode =
                 StoredArchitecture::from_bits_retain(reader.read_u8()?).into();
         } else if version < 5.1 {
+            // There was no 64-bit install mode before 5.1, so it is left empty
             header.architectures_allowed = StoredArchitecture::all().into();
-            header.architectures_install_in_64_bit_mode = StoredArchitecture::all().into();
         }
         if ((5, 2, 1)..(5, 3, 10)).contains(&version) {
             header.signed_uninstaller_original_size = reader.read_u32::<LE>()?;
@@ -409,12 +326,9 @@ pub fn read<R>(mut reader: R, version: InnoVersion) -> InnoResult<Self>
                 AutoBool::from_header_flags(&header.flags, HeaderFlags::DISABLE_PROGRAM_GROUP_PAGE);
         }
         if version < 1.3 {
-            header.license_text =
-                reader.read_sized_decoded_pascal_string(license_size, WINDOWS_1252)?;
-            header.info_before =
-                reader.read_sized_decoded_pascal_string(info_before_size, WINDOWS_1252)?;
-            header.info_after =
-                reader.read_sized_decoded_pascal_string(info_after_size, WINDOWS_1252)?;
+            header.license_text = PascalString::read_sized(&mut reader, license_size)?;
+            header.info_before = PascalString::read_sized(&mut reader, info_before_size)?;
+            header.info_after = PascalString::read_sized(&mut reader, info_after_size)?;
         }
 
         Ok(header)
@@ -523,6 +437,83 @@ fn read_flags<R>(reader: &mut R, version: InnoVersion) -> io::Result<HeaderFlags
         })
     }
 
+    /// Decodes the license text and the info before and after texts using the specified codepage.
+    ///
+    /// These are stored in a codepage even in Unicode installers, so they are decoded separately
+    /// from the [other strings](Self::decode).
+    /// Sets the string field that was read from the header stream.
+    fn set_string(&mut self, field: HeaderString, value: Option<PascalString>) {
+        match field {
+            HeaderString::AppName => self.app_name = value,
+            HeaderString::AppVerName => self.app_versioned_name = value,
+            HeaderString::AppId => self.app_id = value,
+            HeaderString::AppCopyright => self.app_copyright = value,
+            HeaderString::AppPublisher => self.app_publisher = value,
+            HeaderString::AppPublisherURL => self.app_publisher_url = value,
+            HeaderString::AppSupportPhone => self.app_support_phone = value,
+            HeaderString::AppSupportURL => self.app_support_url = value,
+            HeaderString::AppUpdatesURL => self.app_updates_url = value,
+            HeaderString::AppVersion => self.app_version = value,
+            HeaderString::DefaultDirName => self.default_dir_name = value,
+            HeaderString::DefaultGroupName => self.default_group_name = value,
+            HeaderString::BaseFilename => self.base_filename = value,
+            HeaderString::LicenseText => self.license_text = value,
+            HeaderString::InfoBefore => self.info_before = value,
+            HeaderString::InfoAfter => self.info_after = value,
+            HeaderString::UninstallFilesDir => self.uninstall_files_dir = value,
+            HeaderString::UninstallDisplayName => self.uninstall_name = value,
+            HeaderString::UninstallDisplayIcon => self.uninstall_icon = value,
+            HeaderString::AppMutex => self.app_mutex = value,
+            HeaderString::DefaultUserInfoName => self.default_user_name = value,
+            HeaderString::DefaultUserInfoOrg => self.default_user_organisation = value,
+            HeaderString::DefaultUserInfoSerial => self.default_serial = value,
+            HeaderString::AppReadmeFile => self.app_readme_file = value,
+            HeaderString::AppContact => self.app_contact = value,
+            HeaderString::AppComments => self.app_comments = value,
+            HeaderString::AppModifyPath => self.app_modify_path = value,
+            HeaderString::CreateUninstallRegKey => self.create_uninstall_registry_key = value,
+            HeaderString::Uninstallable => self.uninstallable = value,
+            HeaderString::CloseApplicationsFilter => self.close_applications_filter = value,
+            HeaderString::SetupMutex => self.setup_mutex = value,
+            HeaderString::ChangesEnvironment => self.changes_environment = value,
+            HeaderString::ChangesAssociations => self.changes_associations = value,
+            HeaderString::ArchitecturesAllowed => self.architectures_allowed_expr = value,
+            HeaderString::ArchitecturesInstallIn64BitMode => {
+                self.architectures_install_in_64_bit_mode_expr = value
+            }
+            HeaderString::CloseApplicationsFilterExcludes => {
+                self.close_applications_filter_excludes = value
+            }
+            HeaderString::SevenZipLibraryName => self.seven_zip_library_name = value,
+            HeaderString::UsePreviousAppDir => self.use_previous_app_dir = value,
+            HeaderString::UsePreviousGroup => self.use_previous_group = value,
+            HeaderString::UsePreviousSetupType => self.use_previous_setup_type = value,
+            HeaderString::UsePreviousTasks => self.use_previous_tasks = value,
+            HeaderString::UsePreviousUserInfo => self.use_previous_user_info = value,
+            HeaderString::UninstallIconName => self.uninstall_icon_name = decoded(value),
+            HeaderString::CompiledCode => self.compiled_code = decoded(value),
+            HeaderString::UninstallerSignature => self.uninstaller_signature = decoded(value),
+        }
+
+        /// These fields are always stored in Windows-1252.
+        fn decoded(value: Option<PascalString>) -> Option<String> {
+            value.map(|value| value.decoded(WINDOWS_1252).into_string())
+        }
+    }
+
+    pub fn decode_info_text(&mut self, codepage: &'static Encoding) {
+        for text in [
+            &mut self.license_text,
+            &mut self.info_before,
+            &mut self.info_after,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            text.decode(codepage);
+        }
+    }
+
     pub fn decode(&mut self, codepage: &'static Encoding) {
         macro_rules! decode {
             ( $( $field:ident ),* $(,)? ) => {
@@ -617,6 +608,25 @@ pub fn app_id(&self) -> Option<&str> {
         self.app_id.as_ref().map(PascalString::as_str)
     }
 
+    /// Returns the ID of the application parsed as a GUID, or `None` if the ID is not a GUID.
+    ///
+    /// App IDs are commonly GUIDs written as `{{GUID}`, where the leading `{{` escapes the brace
+    /// in the script. Any surrounding braces are removed before parsing. App IDs that are not
+    /// GUIDs are valid, so this returns `None` rather than an error for them.
+    #[cfg(feature = "uuid")]
+    #[doc(alias = "AppId")]
+    #[must_use]
+    pub fn app_id_guid(&self) -> Option<uuid::Uuid> {
+        let app_id = self.app_id()?.trim();
+        let guid = app_id
+            .strip_prefix("{{")
+            .or_else(|| app_id.strip_prefix('{'));
+        let guid = guid
+            .and_then(|guid| guid.strip_suffix('}'))
+            .unwrap_or(app_id);
+        uuid::Uuid::try_parse(guid).ok()
+    }
+
     /// Returns the copyright of the application.
     #[doc(alias = "AppCopyright")]
     #[must_use]
@@ -848,6 +858,24 @@ pub fn architectures_disallowed(&self) -> Architecture {
             })
     }
 
+    /// Returns `true` if the installer is allowed to install on a system running the given Windows
+    /// architecture, which should be [`X86_OS`](Architecture::X86_OS),
+    /// [`X64_OS`](Architecture::X64_OS) or [`ARM64`](Architecture::ARM64).
+    ///
+    /// From Inno Setup 6.3, the `ArchitecturesAllowed` expression is evaluated against the
+    /// identifiers that the system [matches](Architecture::matched_by), so an Arm64 system
+    /// satisfies `x64compatible` through emulation. Before 6.3, the system must be one of the
+    /// [allowed architectures](Self::architectures_allowed).
+    #[must_use]
+    pub fn runs_on(&self, system: Architecture) -> bool {
+        let identifiers = Architecture::matched_by(system);
+
+        self.architectures_allowed_expr.as_ref().map_or_else(
+            || self.architectures_allowed.intersects(identifiers),
+            |expr| Architecture::expression_matches(expr.as_str(), identifiers),
+        )
+    }
+
     /// Returns the architectures on which Setup should enable [64-bit install mode].
     ///
     /// By default, Setup will always use [32-bit install mode].
@@ -865,6 +893,36 @@ pub fn architectures_install_in_64_bit_mode(&self) -> Architecture {
             })
     }
 
+    /// Returns `true` if Setup enables 64-bit install mode on a system running the given Windows
+    /// architecture, which should be [`X86_OS`](Architecture::X86_OS),
+    /// [`X64_OS`](Architecture::X64_OS) or [`ARM64`](Architecture::ARM64).
+    ///
+    /// 64-bit install mode is never enabled on 32-bit systems, or if no architectures are set,
+    /// which is always the case before Inno Setup 5.1. From Inno Setup 6.3, the
+    /// `ArchitecturesInstallIn64BitMode` expression is evaluated in the same way as
+    /// [`runs_on`](Self::runs_on).
+    #[must_use]
+    pub fn installs_in_64_bit_mode_on(&self, system: Architecture) -> bool {
+        let identifiers = Architecture::matched_by(system);
+
+        if !identifiers.contains(Architecture::WIN64) {
+            return false;
+        }
+
+        self.architectures_install_in_64_bit_mode_expr
+            .as_ref()
+            .map_or_else(
+                || {
+                    self.architectures_install_in_64_bit_mode
+                        .intersects(identifiers)
+                },
+                |expr| {
+                    !expr.as_str().trim().is_empty()
+                        && Architecture::expression_matches(expr.as_str(), identifiers)
+                },
+            )
+    }
+
     /// Returns the close applications filter excludes.
     #[doc(alias = "CloseApplicationsFilterExcludes")]
     #[must_use]
@@ -926,21 +984,21 @@ pub fn use_previous_user_info(&self) -> Option<&str> {
     #[doc(alias = "LicenseText")]
     #[must_use]
     pub fn license_text(&self) -> Option<&str> {
-        self.license_text.as_deref()
+        self.license_text.as_ref().map(PascalString::as_str)
     }
 
     /// Returns the info before text.
     #[doc(alias = "InfoBeforeText")]
     #[must_use]
     pub fn info_before(&self) -> Option<&str> {
-        self.info_before.as_deref()
+        self.info_before.as_ref().map(PascalString::as_str)
     }
 
     /// Returns the info after text.
     #[doc(alias = "InfoAfterText")]
     #[must_use]
     pub fn info_after(&self) -> Option<&str> {
-        self.info_after.as_deref()
+        self.info_after.as_ref().map(PascalString::as_str)
     }
 
     /// Returns the uninstaller signature.
@@ -957,6 +1015,49 @@ pub fn compiled_code_text(&self) -> Option<&str> {
         self.compiled_code.as_deref()
     }
 
+    /// Returns the compiled `[Code]` section as raw Pascal Script bytecode.
+    ///
+    /// The bytecode is stored as a Windows-1252 string, which is re-encoded to recover the
+    /// original bytes.
+    #[must_use]
+    pub fn compiled_code(&self) -> Option<Cow<'_, [u8]>> {
+        self.compiled_code_text()
+            .map(|code| WINDOWS_1252.encode(code).0)
+    }
+
+    /// Returns `true` if the installer has a compiled `[Code]` section.
+    #[must_use]
+    #[inline]
+    pub fn has_code(&self) -> bool {
+        self.compiled_code
+            .as_ref()
+            .is_some_and(|code| !code.is_empty())
+    }
+
+    /// Returns the size of the compiled `[Code]` section in bytes, or `0` if the installer has
+    /// no code.
+    #[must_use]
+    pub fn compiled_code_size(&self) -> usize {
+        self.compiled_code().map_or(0, |code| code.len())
+    }
+
+    /// Returns the header of the compiled `[Code]` section, or `None` if the installer has no
+    /// code or the code does not start with a valid Pascal Script header.
+    ///
+    /// This can be used to confirm that the code is valid bytecode before passing it to an
+    /// external disassembler.
+    #[must_use]
+    pub fn ifps_header(&self) -> Option<IfpsHeader> {
+        IfpsHeader::read_from_code(&self.compiled_code()?)
+    }
+
+    /// Returns the number of entries declared for every section at once.
+    #[must_use]
+    #[inline]
+    pub const fn entry_counts(&self) -> EntryCounts {
+        self.entry_counts
+    }
+
     /// Returns the number of language entries.
     #[doc(alias = "NumLanguageEntries")]
     #[must_use]
@@ -1135,13 +1236,26 @@ pub const fn small_image_dynamic_background_color(&self) -> Color {
         self.wizard.small_image_back_color_dynamic_dark()
     }
 
-    /// Returns the wizard image opacity.
+    /// Returns the wizard image opacity, from 0 (transparent) to 255 (opaque).
+    ///
+    /// Added in Inno Setup 6.6.1, so this is `None` for older installers.
+    #[doc(alias = "WizardImageOpacity")]
     #[must_use]
     #[inline]
     pub const fn wizard_image_opacity(&self) -> Option<u8> {
         self.wizard.image_opacity()
     }
 
+    /// Returns the wizard background image opacity, from 0 (transparent) to 255 (opaque).
+    ///
+    /// Added in Inno Setup 6.7.0, so this is `None` for older installers.
+    #[doc(alias = "WizardBackImageOpacity")]
+    #[must_use]
+    #[inline]
+    pub const fn wizard_back_image_opacity(&self) -> Option<u8> {
+        self.wizard.back_image_opacity()
+    }
+
     /// Returns the wizard style.
     #[doc(alias = "WizardStyle")]
     #[must_use]
@@ -1169,13 +1283,19 @@ pub const fn wizard_image_alpha_format(&self) -> ImageAlphaFormat {
     /// Returns the encryption header.
     ///
     /// This was removed from the Setup Header in Inno Setup 6.5.0 and is instead placed before the
-    /// Inno Stream.
+    /// Inno Stream, from where it is also stored in the header when the installer is parsed.
     #[must_use]
     #[inline]
     pub const fn encryption_header(&self) -> Option<&EncryptionHeader> {
         self.encryption_header.as_ref()
     }
 
+    /// Sets the encryption header that is read from before the Inno Stream in Inno Setup 6.5.0 and
+    /// later.
+    pub(crate) const fn set_encryption_header(&mut self, encryption_header: EncryptionHeader) {
+        self.encryption_header = Some(encryption_header);
+    }
+
     /// Returns the extra disk space required for the installation.
     #[doc(alias = "ExtraDiskSpaceRequired")]
     #[must_use]
@@ -1210,7 +1330,9 @@ pub const fn uninstall_log_mode(&self) -> LogMode {
 
     /// Returns the uninstallation style.
     ///
-    /// In Inno Setup v5 and above, this is always `Modern`.
+    /// In Inno Setup v5 and above, this is always [`Modern`](WizardStyle::Modern), and before v2,
+    /// this is always [`Classic`](WizardStyle::Classic). See [`WizardStyle`] for details.
+    #[doc(alias = "UninstallStyle")]
     #[must_use]
     #[inline]
     pub const fn uninstall_style(&self) -> WizardStyle {
@@ -1272,6 +1394,114 @@ pub const fn compression(&self) -> Compression {
         self.compression
     }
 
+    /// Returns the raw flags of the header.
+    ///
+    /// The predicates such as [`creates_app_dir`](Self::creates_app_dir) should be preferred for
+    /// the most commonly queried flags.
+    #[must_use]
+    #[inline]
+    pub const fn flags(&self) -> HeaderFlags {
+        self.flags
+    }
+
+    /// Returns `true` if the installer does not ask the user whether they wish to continue before
+    /// the wizard is shown.
+    #[doc(alias = "DisableStartupPrompt")]
+    #[must_use]
+    #[inline]
+    pub const fn disables_startup_prompt(&self) -> bool {
+        self.flags.contains(HeaderFlags::DISABLE_STARTUP_PROMPT)
+    }
+
+    /// Returns `true` if the installer creates the application directory.
+    ///
+    /// When this is `false`, the installer has no application directory and the `{app}` constant
+    /// cannot be used.
+    #[doc(alias = "CreateAppDir")]
+    #[must_use]
+    #[inline]
+    pub const fn creates_app_dir(&self) -> bool {
+        self.flags.contains(HeaderFlags::CREATE_APP_DIR)
+    }
+
+    /// Returns `true` if the installer asks the user for a password before installing.
+    #[doc(alias = "Password")]
+    #[must_use]
+    #[inline]
+    pub const fn is_password_protected(&self) -> bool {
+        self.flags.contains(HeaderFlags::PASSWORD)
+    }
+
+    /// Returns `true` if the installer's files are encrypted, in which case the password is
+    /// required to extract them.
+    ///
+    /// Before Inno Setup 6.5.0, this is the `ENCRYPTION_USED` header flag. That flag was replaced by
+    /// the encryption use of the [encryption header](Self::encryption_header) in 6.5.0. See also
+    /// [`Inno::has_encrypted_chunks`](crate::Inno::has_encrypted_chunks), which checks the chunks
+    /// that the files are actually stored in.
+    #[doc(alias = "Encryption")]
+    #[must_use]
+    #[inline]
+    pub const fn uses_encryption(&self) -> bool {
+        if self.flags.contains(HeaderFlags::ENCRYPTION_USED) {
+            return true;
+        }
+
+        match &self.encryption_header {
+            Some(encryption_header) => {
+                !matches!(encryption_header.encryption_use(), EncryptionUse::None)
+            }
+            None => false,
+        }
+    }
+
+    /// Returns `true` if the user can cancel the installer while files are being installed.
+    #[doc(alias = "AllowCancelDuringInstall")]
+    #[must_use]
+    #[inline]
+    pub const fn allows_cancel_during_install(&self) -> bool {
+        self.flags
+            .contains(HeaderFlags::ALLOW_CANCEL_DURING_INSTALL)
+    }
+
+    /// Returns `true` if the installer always asks to restart the computer once it has finished.
+    #[doc(alias = "AlwaysRestart")]
+    #[must_use]
+    #[inline]
+    pub const fn always_restarts(&self) -> bool {
+        self.flags.contains(HeaderFlags::ALWAYS_RESTART)
+    }
+
+    /// Returns `true` if the installer writes a log file, as if the `/LOG` command line parameter
+    /// was given.
+    #[doc(alias = "SetupLogging")]
+    #[must_use]
+    #[inline]
+    pub const fn is_setup_logging(&self) -> bool {
+        self.flags.contains(HeaderFlags::SETUP_LOGGING)
+    }
+
+    /// Returns `true` if the installer was compiled with a signed uninstaller.
+    #[must_use]
+    #[inline]
+    pub const fn has_signed_uninstaller(&self) -> bool {
+        self.flags.contains(HeaderFlags::SIGNED_UNINSTALLER)
+    }
+
+    /// Returns the signed uninstaller's original size and header checksum, or `None` if the
+    /// installer wasn't compiled with a signed uninstaller.
+    #[must_use]
+    pub const fn signed_uninstaller(&self) -> Option<SignedUninstaller> {
+        if self.has_signed_uninstaller() {
+            Some(SignedUninstaller::new(
+                self.signed_uninstaller_original_size,
+                self.signed_uninstaller_header_checksum,
+            ))
+        } else {
+            None
+        }
+    }
+
     /// Returns the signed uninstaller original size.
     #[doc(alias = "SignedUninstallerOriginalSize")]
     #[must_use]
@@ -1341,7 +1571,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             .field("AppMutex", &self.app_mutex())
             .field("DefaultUserInfoName", &self.default_user_name())
             .field("DefaultUserInfoOrg", &self.default_user_organization())
-            .field("DefaultUserInfoSerial", &self.default_serial())
+            .field("DefaultUserInfoSerial", &self.default_serial().map(Secret))
             .field("AppReadmeFile", &self.app_readme_file())
             .field("AppContact", &self.app_contact())
             .field("AppComments", &self.app_comments())
@@ -1384,21 +1614,22 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             .field("NumComponentEntries", &self.component_count())
             .field("NumTaskEntries", &self.task_count())
             .field("NumDirEntries", &self.directory_count())
+            .field("NumISSigKeyEntries", &self.is_sig_keys_count())
             .field("NumFileEntries", &self.file_count())
             .field("NumFileLocationEntries", &self.file_location_entry_count())
             .field("NumIconEntries", &self.icon_count())
             .field("NumIniEntries", &self.ini_entry_count())
             .field("NumRegistryEntries", &self.registry_entry_count())
             .field(
-                "NumUninstallDeleteEntries",
-                &self.uninstall_delete_entry_count(),
+                "NumInstallDeleteEntries",
+                &self.install_delete_entry_count(),
             )
             .field(
-                "NumUninstallRunEntries",
+                "NumUninstallDeleteEntries",
                 &self.uninstall_delete_entry_count(),
             )
-            .field("RunEntryCount", &self.run_entry_count())
-            .field("UninstallRunEntryCount", &self.uninstall_run_entry_count())
+            .field("NumRunEntries", &self.run_entry_count())
+            .field("NumUninstallRunEntries", &self.uninstall_run_entry_count())
             .field("BackColor", &self.background_color())
             .field("BackColor2", &self.background_color2())
             .field("WizardImageBackColor", &self.image_background_color())
@@ -1417,6 +1648,8 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             .field("WizardStyle", &self.wizard_style())
             .field("WizardSizePercent", &self.wizard_size_percent())
             .field("ImageAlphaFormat", &self.wizard_image_alpha_format())
+            .field("WizardImageOpacity", &self.wizard_image_opacity())
+            .field("WizardBackImageOpacity", &self.wizard_back_image_opacity())
             // Skip password salt
             .field("ExtraDiskSpaceRequired", &self.extra_disk_space_required())
             .field("SlicesPerDisk", &self.slices_per_disk())
@@ -1452,8 +1685,48 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 
 #[cfg(test)]
 mod tests {
-    use super::Header;
-    use crate::string::PascalString;
+    use encoding_rs::{WINDOWS_1251, WINDOWS_1252};
+
+    use super::{Architecture, EntryCounts, Header, HeaderFlags, HeaderString, SignedUninstaller};
+    use crate::{
+        encryption::{EncryptionHeader, EncryptionUse},
+        string::PascalString,
+        version::InnoVersion,
+    };
+
+    #[test]
+    fn read_1_2_10() {
+        let version = InnoVersion::new(1, 2, 10, 0);
+
+        // The uncompressed size of the header, which is only stored before Inno Setup 1.3
+        let mut data = vec![0; 4];
+        for field in HeaderString::layout(version) {
+            let value: &[u8] = if field == HeaderString::AppName {
+                b"Fixture"
+            } else {
+                b""
+            };
+            data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+            data.extend_from_slice(value);
+        }
+        // The entry counts, followed by the sizes of the license, info before and info after texts
+        data.extend_from_slice(&[0; 40]);
+        data.extend_from_slice(&7u32.to_le_bytes());
+        data.extend_from_slice(&[0; 8]);
+        // The rest of the header, up to and including the flags
+        data.extend_from_slice(&[0; 28]);
+        // The texts themselves are stored after the flags rather than as strings
+        data.extend_from_slice(b"License");
+
+        let mut reader = data.as_slice();
+        let header = Header::read(&mut reader, version).unwrap();
+
+        assert!(reader.is_empty());
+        assert_eq!(header.app_name(), Some("Fixture"));
+        assert_eq!(header.license_text(), Some("License"));
+        assert_eq!(header.info_before(), None);
+        assert!(!header.installs_in_64_bit_mode_on(Architecture::X64_OS));
+    }
 
     #[test]
     fn product_code() {
@@ -1469,4 +1742,200 @@ fn product_code() {
             Some("{31AA9DE2-36A2-4FB7-921F-865D4B0657D5}_is1")
         );
     }
+
+    #[rstest::rstest]
+    #[case::x64_on_x64(Some("x64compatible"), Architecture::X64_OS, true)]
+    #[case::x64_on_arm64(Some("x64compatible"), Architecture::ARM64, true)]
+    #[case::x64_on_x86(Some("x64compatible"), Architecture::X86_OS, false)]
+    #[case::not_arm64(Some("x64compatible and not arm64"), Architecture::ARM64, false)]
+    #[case::empty(Some(""), Architecture::X86_OS, true)]
+    #[case::stored_x64_on_arm64(None, Architecture::ARM64, false)]
+    #[case::stored_x64_on_x64(None, Architecture::X64_OS, true)]
+    fn runs_on(#[case] expr: Option<&str>, #[case] system: Architecture, #[case] expected: bool) {
+        let header = Header {
+            architectures_allowed_expr: expr.map(PascalString::from),
+            architectures_allowed: Architecture::X64_OS,
+            ..Header::default()
+        };
+
+        assert_eq!(header.runs_on(system), expected);
+    }
+
+    #[rstest::rstest]
+    #[case::x64_on_x64(Some("x64compatible"), Architecture::X64_OS, true)]
+    #[case::x64_on_x86(Some("x64compatible"), Architecture::X86_OS, false)]
+    #[case::x64os_on_arm64(Some("x64os"), Architecture::ARM64, false)]
+    #[case::empty(Some(""), Architecture::X64_OS, false)]
+    #[case::stored(None, Architecture::ARM64, true)]
+    fn installs_in_64_bit_mode_on(
+        #[case] expr: Option<&str>,
+        #[case] system: Architecture,
+        #[case] expected: bool,
+    ) {
+        let header = Header {
+            architectures_install_in_64_bit_mode_expr: expr.map(PascalString::from),
+            architectures_install_in_64_bit_mode: Architecture::X64_OS | Architecture::ARM64,
+            ..Header::default()
+        };
+
+        assert_eq!(header.installs_in_64_bit_mode_on(system), expected);
+    }
+
+    #[test]
+    fn decode_info_text() {
+        let mut header = Header {
+            license_text: Some(PascalString::Bytes(
+                b"\xcb\xe8\xf6\xe5\xed\xe7\xe8\xff".to_vec(),
+            )),
+            ..Header::default()
+        };
+
+        header.decode_info_text(WINDOWS_1251);
+
+        assert_eq!(header.license_text(), Some("Лицензия"));
+        assert_eq!(header.info_before(), None);
+    }
+
+    #[cfg(feature = "uuid")]
+    #[rstest::rstest]
+    #[case::escaped("{{31AA9DE2-36A2-4FB7-921F-865D4B0657D5}", true)]
+    #[case::braced("{31AA9DE2-36A2-4FB7-921F-865D4B0657D5}", true)]
+    #[case::bare("31AA9DE2-36A2-4FB7-921F-865D4B0657D5", true)]
+    #[case::name("MyProgram", false)]
+    #[case::braced_name("{{MyProgram}", false)]
+    fn app_id_guid(#[case] app_id: &str, #[case] is_guid: bool) {
+        let header = Header {
+            app_id: Some(PascalString::from(app_id)),
+            ..Header::default()
+        };
+
+        assert_eq!(
+            header.app_id_guid(),
+            is_guid.then(|| uuid::uuid!("31AA9DE2-36A2-4FB7-921F-865D4B0657D5"))
+        );
+    }
+
+    #[test]
+    fn flag_predicates() {
+        let mut header = Header::default();
+
+        assert!(!header.creates_app_dir());
+        assert!(!header.disables_startup_prompt());
+
+        header.flags |= HeaderFlags::CREATE_APP_DIR | HeaderFlags::DISABLE_STARTUP_PROMPT;
+
+        assert!(header.creates_app_dir());
+        assert!(header.disables_startup_prompt());
+        assert!(!header.is_password_protected());
+        assert_eq!(
+            header.flags(),
+            HeaderFlags::CREATE_APP_DIR | HeaderFlags::DISABLE_STARTUP_PROMPT
+        );
+    }
+
+    #[test]
+    fn signed_uninstaller() {
+        let mut header = Header {
+            signed_uninstaller_original_size: 0x1234,
+            signed_uninstaller_header_checksum: 0xDEAD_BEEF,
+            ..Header::default()
+        };
+
+        assert!(!header.has_signed_uninstaller());
+        assert_eq!(header.signed_uninstaller(), None);
+
+        header.flags |= HeaderFlags::SIGNED_UNINSTALLER;
+
+        assert!(header.has_signed_uninstaller());
+        assert_eq!(
+            header.signed_uninstaller(),
+            Some(SignedUninstaller::new(0x1234, 0xDEAD_BEEF))
+        );
+    }
+
+    #[test]
+    fn compiled_code_round_trip() {
+        let code = (u8::MIN..=u8::MAX).collect::<Vec<_>>();
+
+        let header = Header {
+            compiled_code: Some(WINDOWS_1252.decode(&code).0.into_owned()),
+            ..Header::default()
+        };
+
+        assert!(header.has_code());
+        assert_eq!(header.compiled_code_size(), code.len());
+        assert_eq!(header.compiled_code().as_deref(), Some(code.as_slice()));
+        assert_eq!(header.ifps_header(), None);
+    }
+
+    #[test]
+    fn debug_entry_counts() {
+        // Give every entry count a distinct value so that mismatched fields are caught
+        let counts = (1..=17u32).flat_map(u32::to_le_bytes).collect::<Vec<_>>();
+
+        let header = Header {
+            entry_counts: EntryCounts::read(counts.as_slice(), InnoVersion::new(6, 5, 0, 0))
+                .unwrap(),
+            ..Header::default()
+        };
+
+        let debug = format!("{header:?}");
+
+        for (field, count) in [
+            (
+                "NumInstallDeleteEntries",
+                header.install_delete_entry_count(),
+            ),
+            (
+                "NumUninstallDeleteEntries",
+                header.uninstall_delete_entry_count(),
+            ),
+            ("NumRunEntries", header.run_entry_count()),
+            ("NumUninstallRunEntries", header.uninstall_run_entry_count()),
+        ] {
+            assert!(debug.contains(&format!("{field}: {count},")), "{field}");
+        }
+
+        assert_eq!(header.uninstall_run_entry_count(), 17);
+        assert_eq!(header.entry_counts(), header.entry_counts);
+        assert_eq!(header.entry_counts().language(), 1);
+    }
+
+    #[rstest::rstest]
+    #[case::none(HeaderFlags::empty(), None, false)]
+    #[case::flag(HeaderFlags::ENCRYPTION_USED, None, true)]
+    #[case::encryption_header_none(HeaderFlags::empty(), Some(EncryptionUse::None), false)]
+    #[case::encryption_header_files(HeaderFlags::empty(), Some(EncryptionUse::Files), true)]
+    #[case::encryption_header_full(HeaderFlags::empty(), Some(EncryptionUse::Full), true)]
+    fn uses_encryption(
+        #[case] flags: HeaderFlags,
+        #[case] encryption_use: Option<EncryptionUse>,
+        #[case] expected: bool,
+    ) {
+        let header = Header {
+            flags,
+            encryption_header: encryption_use.map(|encryption_use| EncryptionHeader {
+                encryption_use,
+                ..EncryptionHeader::default()
+            }),
+            ..Header::default()
+        };
+
+        assert_eq!(header.uses_encryption(), expected);
+    }
+
+    #[test]
+    fn debug_redacts_default_serial() {
+        let header = Header {
+            default_serial: Some("1234-5678".into()),
+            ..Header::default()
+        };
+
+        assert!(format!("{header:?}").contains("DefaultUserInfoSerial: Some(<redacted>)"));
+        assert!(
+            format!("{:?}", crate::secret::Revealed(&header))
+                .contains(r#"DefaultUserInfoSerial: Some("1234-5678")"#)
+        );
+        assert_eq!(header.default_serial(), Some("1234-5678"));
+    }
 }
@@ -39,6 +39,7 @@ pub fn try_read_from_io<R>(mut src: R) -> io::Result<Self>
             .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))
     }
 
+    /// Returns the `PrivilegeLevel` as a static string.
     #[must_use]
     pub const fn as_str(&self) -> &'static str {
         match self {
@@ -73,3 +74,20 @@ fn try_from(value: u8) -> Result<Self, Self::Error> {
         try_transmute!(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::PrivilegeLevel;
+
+    #[rstest]
+    #[case(PrivilegeLevel::None, "None")]
+    #[case(PrivilegeLevel::PowerUser, "Power User")]
+    #[case(PrivilegeLevel::Admin, "Admin")]
+    #[case(PrivilegeLevel::Lowest, "Lowest")]
+    fn as_str(#[case] value: PrivilegeLevel, #[case] expected: &str) {
+        assert_eq!(value.as_str(), expected);
+        assert_eq!(value.to_string(), expected);
+    }
+}
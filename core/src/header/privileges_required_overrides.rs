@@ -30,6 +30,22 @@ pub struct PrivilegesRequiredOverrides: u8 {
     }
 }
 
+impl PrivilegesRequiredOverrides {
+    /// Returns the names of the enabled overrides, as they would be written in the
+    /// `PrivilegesRequiredOverridesAllowed` directive.
+    #[must_use]
+    pub fn names(&self) -> Vec<&'static str> {
+        [
+            (Self::COMMAND_LINE, "commandline"),
+            (Self::DIALOG, "dialog"),
+        ]
+        .into_iter()
+        .filter(|&(flag, _)| self.contains(flag))
+        .map(|(_, name)| name)
+        .collect()
+    }
+}
+
 impl fmt::Debug for PrivilegesRequiredOverrides {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.is_empty() {
@@ -39,3 +55,25 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         }
     }
 }
+
+impl fmt::Display for PrivilegesRequiredOverrides {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.names().join(" ").fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::PrivilegesRequiredOverrides;
+
+    #[rstest]
+    #[case(PrivilegesRequiredOverrides::empty(), "")]
+    #[case(PrivilegesRequiredOverrides::COMMAND_LINE, "commandline")]
+    #[case(PrivilegesRequiredOverrides::DIALOG, "dialog")]
+    #[case(PrivilegesRequiredOverrides::all(), "commandline dialog")]
+    fn display(#[case] overrides: PrivilegesRequiredOverrides, #[case] expected: &str) {
+        assert_eq!(overrides.to_string(), expected);
+    }
+}
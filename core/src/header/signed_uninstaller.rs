@@ -0,0 +1,34 @@
+/// The details of an uninstaller that was signed at compile time using `SignTool`.
+///
+/// Only stored in the header from Inno Setup 5.2.1 until 5.3.10, after which the uninstaller is
+/// signed separately.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct SignedUninstaller {
+    original_size: u32,
+    header_checksum: u32,
+}
+
+impl SignedUninstaller {
+    pub(crate) const fn new(original_size: u32, header_checksum: u32) -> Self {
+        Self {
+            original_size,
+            header_checksum,
+        }
+    }
+
+    /// Returns the size of the uninstaller before it was signed.
+    #[doc(alias = "SignedUninstallerOriginalSize")]
+    #[must_use]
+    #[inline]
+    pub const fn original_size(&self) -> u32 {
+        self.original_size
+    }
+
+    /// Returns the checksum of the uninstaller's header before it was signed.
+    #[doc(alias = "SignedUninstallerHeaderChecksum")]
+    #[must_use]
+    #[inline]
+    pub const fn header_checksum(&self) -> u32 {
+        self.header_checksum
+    }
+}
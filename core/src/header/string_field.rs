@@ -0,0 +1,249 @@
+use std::fmt;
+
+use crate::version::InnoVersion;
+
+/// A string field of the [header](super::Header), named after its `[Setup]` directive.
+///
+/// This identifies a string of a [`HeaderRef`](super::HeaderRef), which borrows the strings of the
+/// header instead of reading them into owned strings.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum HeaderString {
+    AppName,
+    AppVerName,
+    AppId,
+    AppCopyright,
+    AppPublisher,
+    AppPublisherURL,
+    AppSupportPhone,
+    AppSupportURL,
+    AppUpdatesURL,
+    AppVersion,
+    DefaultDirName,
+    DefaultGroupName,
+    UninstallIconName,
+    BaseFilename,
+    LicenseText,
+    InfoBefore,
+    InfoAfter,
+    UninstallFilesDir,
+    UninstallDisplayName,
+    UninstallDisplayIcon,
+    AppMutex,
+    DefaultUserInfoName,
+    DefaultUserInfoOrg,
+    DefaultUserInfoSerial,
+    CompiledCode,
+    AppReadmeFile,
+    AppContact,
+    AppComments,
+    AppModifyPath,
+    CreateUninstallRegKey,
+    Uninstallable,
+    CloseApplicationsFilter,
+    SetupMutex,
+    ChangesEnvironment,
+    ChangesAssociations,
+    ArchitecturesAllowed,
+    ArchitecturesInstallIn64BitMode,
+    CloseApplicationsFilterExcludes,
+    SevenZipLibraryName,
+    UsePreviousAppDir,
+    UsePreviousGroup,
+    UsePreviousSetupType,
+    UsePreviousTasks,
+    UsePreviousUserInfo,
+    UninstallerSignature,
+}
+
+/// Returns `true` if a string field is stored in the header of the given version.
+type IsPresent = fn(InnoVersion) -> bool;
+
+impl HeaderString {
+    /// The number of header string fields.
+    pub const COUNT: usize = 45;
+
+    /// The string fields in the order that they are stored in the header, along with whether
+    /// they are present in a given version.
+    ///
+    /// Some fields moved between versions, so they are listed at each of their positions with
+    /// disjoint version ranges.
+    const LAYOUT: [(Self, IsPresent); 49] = [
+        (Self::AppName, |_| true),
+        (Self::AppVerName, |_| true),
+        (Self::AppId, |version| version >= 1.3),
+        (Self::AppCopyright, |_| true),
+        (Self::AppPublisher, |version| version >= 1.3),
+        (Self::AppPublisherURL, |version| version >= 1.3),
+        (Self::AppSupportPhone, |version| version >= (5, 1, 13)),
+        (Self::AppSupportURL, |version| version >= 1.3),
+        (Self::AppUpdatesURL, |version| version >= 1.3),
+        (Self::AppVersion, |version| version >= 1.3),
+        (Self::DefaultDirName, |_| true),
+        (Self::DefaultGroupName, |_| true),
+        (Self::UninstallIconName, |version| version < 3),
+        (Self::BaseFilename, |_| true),
+        (Self::LicenseText, |version| {
+            ((1, 3, 0)..(5, 2, 5)).contains(&version)
+        }),
+        (Self::InfoBefore, |version| {
+            ((1, 3, 0)..(5, 2, 5)).contains(&version)
+        }),
+        (Self::InfoAfter, |version| {
+            ((1, 3, 0)..(5, 2, 5)).contains(&version)
+        }),
+        (Self::UninstallFilesDir, |version| version >= (1, 3, 3)),
+        (Self::UninstallDisplayName, |version| version >= (1, 3, 6)),
+        (Self::UninstallDisplayIcon, |version| version >= (1, 3, 6)),
+        (Self::AppMutex, |version| version >= (1, 3, 14)),
+        (Self::DefaultUserInfoName, |version| version >= 3),
+        (Self::DefaultUserInfoOrg, |version| version >= 3),
+        (Self::DefaultUserInfoSerial, |version| {
+            version >= 4 || (version.is_isx() && version >= (3, 0, 6, 1))
+        }),
+        (Self::CompiledCode, |version| {
+            ((4, 0, 0)..(5, 2, 5)).contains(&version) || (version.is_isx() && version >= (1, 3, 24))
+        }),
+        (Self::AppReadmeFile, |version| version >= (4, 2, 4)),
+        (Self::AppContact, |version| version >= (4, 2, 4)),
+        (Self::AppComments, |version| version >= (4, 2, 4)),
+        (Self::AppModifyPath, |version| version >= (4, 2, 4)),
+        (Self::CreateUninstallRegKey, |version| version >= (5, 3, 8)),
+        (Self::Uninstallable, |version| version >= (5, 3, 10)),
+        (Self::CloseApplicationsFilter, |version| version >= 5.5),
+        (Self::SetupMutex, |version| version >= (5, 5, 6)),
+        (Self::ChangesEnvironment, |version| version >= (5, 6, 1)),
+        (Self::ChangesAssociations, |version| version >= (5, 6, 1)),
+        (Self::ArchitecturesAllowed, |version| version >= 6.3),
+        (Self::ArchitecturesInstallIn64BitMode, |version| {
+            version >= 6.3
+        }),
+        (Self::CloseApplicationsFilterExcludes, |version| {
+            version >= (6, 4, 2)
+        }),
+        (Self::SevenZipLibraryName, |version| version >= 6.5),
+        (Self::UsePreviousAppDir, |version| version >= 6.7),
+        (Self::UsePreviousGroup, |version| version >= 6.7),
+        (Self::UsePreviousSetupType, |version| version >= 6.7),
+        (Self::UsePreviousTasks, |version| version >= 6.7),
+        (Self::UsePreviousUserInfo, |version| version >= 6.7),
+        (Self::LicenseText, |version| version >= (5, 2, 5)),
+        (Self::InfoBefore, |version| version >= (5, 2, 5)),
+        (Self::InfoAfter, |version| version >= (5, 2, 5)),
+        (Self::UninstallerSignature, |version| {
+            ((5, 2, 1)..(5, 3, 10)).contains(&version)
+        }),
+        (Self::CompiledCode, |version| version >= (5, 2, 5)),
+    ];
+
+    /// Returns the string fields that are stored in the header of the given version, in the order
+    /// that they are stored.
+    pub fn layout(version: InnoVersion) -> impl Iterator<Item = Self> {
+        Self::LAYOUT
+            .into_iter()
+            .filter(move |(_, is_present)| is_present(version))
+            .map(|(field, _)| field)
+    }
+
+    /// Returns `true` if the field is stored in a codepage even in Unicode installers.
+    ///
+    /// The license and info texts are stored as they were in the files they were read from, and
+    /// the uninstall icon name, compiled code and uninstaller signature are always Windows-1252.
+    #[must_use]
+    pub const fn is_always_ansi(self) -> bool {
+        matches!(
+            self,
+            Self::UninstallIconName
+                | Self::LicenseText
+                | Self::InfoBefore
+                | Self::InfoAfter
+                | Self::CompiledCode
+                | Self::UninstallerSignature
+        )
+    }
+
+    /// Returns the name of the field's `[Setup]` directive as a static string.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::AppName => "AppName",
+            Self::AppVerName => "AppVerName",
+            Self::AppId => "AppId",
+            Self::AppCopyright => "AppCopyright",
+            Self::AppPublisher => "AppPublisher",
+            Self::AppPublisherURL => "AppPublisherURL",
+            Self::AppSupportPhone => "AppSupportPhone",
+            Self::AppSupportURL => "AppSupportURL",
+            Self::AppUpdatesURL => "AppUpdatesURL",
+            Self::AppVersion => "AppVersion",
+            Self::DefaultDirName => "DefaultDirName",
+            Self::DefaultGroupName => "DefaultGroupName",
+            Self::UninstallIconName => "UninstallIconName",
+            Self::BaseFilename => "OutputBaseFilename",
+            Self::LicenseText => "LicenseFile",
+            Self::InfoBefore => "InfoBeforeFile",
+            Self::InfoAfter => "InfoAfterFile",
+            Self::UninstallFilesDir => "UninstallFilesDir",
+            Self::UninstallDisplayName => "UninstallDisplayName",
+            Self::UninstallDisplayIcon => "UninstallDisplayIcon",
+            Self::AppMutex => "AppMutex",
+            Self::DefaultUserInfoName => "DefaultUserInfoName",
+            Self::DefaultUserInfoOrg => "DefaultUserInfoOrg",
+            Self::DefaultUserInfoSerial => "DefaultUserInfoSerial",
+            Self::CompiledCode => "CompiledCode",
+            Self::AppReadmeFile => "AppReadmeFile",
+            Self::AppContact => "AppContact",
+            Self::AppComments => "AppComments",
+            Self::AppModifyPath => "AppModifyPath",
+            Self::CreateUninstallRegKey => "CreateUninstallRegKey",
+            Self::Uninstallable => "Uninstallable",
+            Self::CloseApplicationsFilter => "CloseApplicationsFilter",
+            Self::SetupMutex => "SetupMutex",
+            Self::ChangesEnvironment => "ChangesEnvironment",
+            Self::ChangesAssociations => "ChangesAssociations",
+            Self::ArchitecturesAllowed => "ArchitecturesAllowed",
+            Self::ArchitecturesInstallIn64BitMode => "ArchitecturesInstallIn64BitMode",
+            Self::CloseApplicationsFilterExcludes => "CloseApplicationsFilterExcludes",
+            Self::SevenZipLibraryName => "SevenZipLibraryName",
+            Self::UsePreviousAppDir => "UsePreviousAppDir",
+            Self::UsePreviousGroup => "UsePreviousGroup",
+            Self::UsePreviousSetupType => "UsePreviousSetupType",
+            Self::UsePreviousTasks => "UsePreviousTasks",
+            Self::UsePreviousUserInfo => "UsePreviousUserInfo",
+            Self::UninstallerSignature => "UninstallerSignature",
+        }
+    }
+}
+
+impl fmt::Display for HeaderString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::HeaderString;
+    use crate::version::{InnoVersion, VersionVariant};
+
+    #[rstest]
+    #[case::v1_2_10(InnoVersion::new(1, 2, 10, 0), 7)]
+    #[case::v5_2_5(InnoVersion::new(5, 2, 5, 0), 29)]
+    #[case::v6_7_0(InnoVersion::new_with_variant(6, 7, 0, 0, VersionVariant::UNICODE), 43)]
+    fn layout(#[case] version: InnoVersion, #[case] expected: usize) {
+        let layout = HeaderString::layout(version).collect::<Vec<_>>();
+
+        assert_eq!(layout.len(), expected);
+        assert_eq!(layout.first(), Some(&HeaderString::AppName));
+    }
+
+    #[test]
+    fn every_field_is_stored() {
+        for (field, _) in HeaderString::LAYOUT {
+            assert!((field as usize) < HeaderString::COUNT);
+        }
+        let last = HeaderString::UninstallerSignature as usize;
+        assert_eq!(last + 1, HeaderString::COUNT);
+    }
+}
@@ -1,9 +1,9 @@
 use std::fmt;
 
-use zerocopy::{FromBytes, Immutable, KnownLayout, LittleEndian, U32};
+use zerocopy::{FromBytes, Immutable, KnownLayout, LittleEndian, U32, Unaligned};
 
 /// An Inno Color stored in Little-Endian byte order.
-#[derive(Clone, Copy, Default, Eq, PartialEq, FromBytes, Immutable, KnownLayout)]
+#[derive(Clone, Copy, Default, Eq, PartialEq, FromBytes, Immutable, KnownLayout, Unaligned)]
 #[repr(transparent)]
 pub struct Color(U32<LittleEndian>);
 
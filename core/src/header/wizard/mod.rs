@@ -100,6 +100,8 @@ pub const fn small_image_back_color_dynamic_dark(&self) -> Color {
     }
 
     /// Returns the image opacity.
+    ///
+    /// Added in Inno Setup 6.6.1.
     #[must_use]
     #[inline]
     pub const fn image_opacity(&self) -> Option<u8> {
@@ -4,18 +4,39 @@
 
 use crate::{read::ReadBytesExt, version::InnoVersion};
 
+/// The style of the Setup wizard, or of the uninstaller.
+///
+/// The values that are stored depend on the version of the installer:
+///
+/// | Version | Values |
+/// |---|---|
+/// | 6.6.0 and above | [`Light`](Self::Light), [`Dark`](Self::Dark), [`Dynamic`](Self::Dynamic) |
+/// | Below 6.6.0 | [`Classic`](Self::Classic), [`Modern`](Self::Modern) |
+///
+/// The uninstall style is only stored from Inno Setup 2.0.0 up to, but not including, 5.0.0. From
+/// 5.0.0, the uninstaller always uses the [`Modern`](Self::Modern) style, and before 2.0.0, it
+/// always used the [`Classic`](Self::Classic) style.
+///
 /// <https://jrsoftware.org/ishelp/index.php?topic=setup_wizardstyle>
 ///
 /// <https://github.com/jrsoftware/issrc/blob/is-6_6_0/Projects/Src/Shared.Struct.pas#L84>
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Immutable, KnownLayout, TryFromBytes)]
 #[repr(u8)]
 pub enum WizardStyle {
+    /// The wizard uses a light theme.
     #[default]
     Light,
+
+    /// The wizard uses a dark theme.
     Dark,
+
+    /// The wizard follows the system theme, switching between light and dark.
     Dynamic,
 
+    /// The wizard uses the classic look, with a smaller window and no scaling.
     Classic,
+
+    /// The wizard uses the modern look, with a larger, resizable window.
     Modern,
 }
 
@@ -48,7 +69,7 @@ pub fn try_read_from<R>(mut reader: R, version: InnoVersion) -> io::Result<Self>
         ))
     }
 
-    /// Returns the `InnoStyle` as a static string.
+    /// Returns the `WizardStyle` as a static string.
     #[must_use]
     pub const fn as_str(&self) -> &'static str {
         match self {
@@ -66,3 +87,46 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.as_str().fmt(f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::WizardStyle;
+    use crate::version::InnoVersion;
+
+    #[rstest]
+    #[case(InnoVersion::new(6, 6, 0, 0), 0, WizardStyle::Light)]
+    #[case(InnoVersion::new(6, 6, 0, 0), 1, WizardStyle::Dark)]
+    #[case(InnoVersion::new(6, 6, 0, 0), 2, WizardStyle::Dynamic)]
+    #[case(InnoVersion::new(6, 5, 0, 0), 0, WizardStyle::Classic)]
+    #[case(InnoVersion::new(6, 5, 0, 0), 1, WizardStyle::Modern)]
+    fn try_read_from(
+        #[case] version: InnoVersion,
+        #[case] value: u8,
+        #[case] expected: WizardStyle,
+    ) {
+        assert_eq!(
+            WizardStyle::try_read_from([value].as_slice(), version).unwrap(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(InnoVersion::new(6, 6, 0, 0), 3)]
+    #[case(InnoVersion::new(6, 5, 0, 0), 2)]
+    fn try_read_from_invalid(#[case] version: InnoVersion, #[case] value: u8) {
+        assert!(WizardStyle::try_read_from([value].as_slice(), version).is_err());
+    }
+
+    #[rstest]
+    #[case(WizardStyle::Light, "Light")]
+    #[case(WizardStyle::Dark, "Dark")]
+    #[case(WizardStyle::Dynamic, "Dynamic")]
+    #[case(WizardStyle::Classic, "Classic")]
+    #[case(WizardStyle::Modern, "Modern")]
+    fn as_str(#[case] style: WizardStyle, #[case] expected: &str) {
+        assert_eq!(style.as_str(), expected);
+        assert_eq!(style.to_string(), expected);
+    }
+}
@@ -0,0 +1,199 @@
+use std::fmt;
+
+use crate::entry::{Directory, File, Icon, IconFlags};
+
+/// A problem with the target of an [`Icon`], found by
+/// [`Inno::validate_icons`](crate::Inno::validate_icons).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IconIssue<'inno> {
+    /// The icon has no target filename.
+    MissingTarget(&'inno Icon),
+    /// The icon's target is not installed by the installer and is not a known system location.
+    UnresolvedTarget(&'inno Icon),
+}
+
+impl<'inno> IconIssue<'inno> {
+    /// Constants that resolve to locations outside of those managed by the installer.
+    const SYSTEM_CONSTANTS: [&'static str; 14] = [
+        "{win}",
+        "{sys}",
+        "{sysnative}",
+        "{syswow64}",
+        "{cmd}",
+        "{uninstallexe}",
+        "{hh}",
+        "{dotnet11}",
+        "{dotnet20}",
+        "{dotnet2032}",
+        "{dotnet2064}",
+        "{dotnet40}",
+        "{dotnet4032}",
+        "{dotnet4064}",
+    ];
+
+    /// Constants whose values are only known at install time.
+    const RUNTIME_CONSTANTS: [&'static str; 5] = ["{code:", "{reg:", "{ini:", "{param:", "{%"];
+
+    /// Returns the icon that the issue was found in.
+    #[must_use]
+    #[inline]
+    pub const fn icon(&self) -> &'inno Icon {
+        match self {
+            Self::MissingTarget(icon) | Self::UnresolvedTarget(icon) => icon,
+        }
+    }
+
+    /// Checks the target of each icon against the installed files and directories.
+    pub(crate) fn find(
+        icons: &'inno [Icon],
+        files: &[File],
+        directories: &[Directory],
+    ) -> Vec<Self> {
+        let files = files
+            .iter()
+            .filter_map(File::destination)
+            .map(normalize)
+            .collect::<Vec<_>>();
+
+        let directories = directories
+            .iter()
+            .filter_map(Directory::name)
+            .map(normalize)
+            .collect::<Vec<_>>();
+
+        icons
+            .iter()
+            .filter(|icon| !icon.flags().contains(IconFlags::CREATE_ONLY_IF_FILE_EXISTS))
+            .filter_map(|icon| {
+                let Some(target) = icon.filename().filter(|target| !target.trim().is_empty())
+                else {
+                    return Some(Self::MissingTarget(icon));
+                };
+
+                // URLs are checked before normalizing, which would merge the slashes of `://`
+                let is_url = target.contains("://");
+                let target = normalize(target);
+
+                let resolved = is_url
+                    || Self::RUNTIME_CONSTANTS
+                        .iter()
+                        .any(|constant| target.contains(constant))
+                    || Self::SYSTEM_CONSTANTS
+                        .iter()
+                        .any(|constant| is_within(&target, constant))
+                    || files
+                        .iter()
+                        .any(|file| is_within(&target, file) || is_within(file, &target))
+                    || directories
+                        .iter()
+                        .any(|directory| is_within(directory, &target));
+
+                (!resolved).then_some(Self::UnresolvedTarget(icon))
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for IconIssue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.icon().name().unwrap_or_default();
+        match self {
+            Self::MissingTarget(_) => write!(f, "Icon {name} has no target"),
+            Self::UnresolvedTarget(icon) => write!(
+                f,
+                "Icon {name} targets {}, which is not installed",
+                icon.filename().unwrap_or_default()
+            ),
+        }
+    }
+}
+
+/// Normalizes a path for comparison by lowercasing it, using backslashes as separators, and
+/// removing duplicate and trailing separators.
+fn normalize(path: &str) -> String {
+    path.trim()
+        .to_ascii_lowercase()
+        .replace('/', "\\")
+        .split('\\')
+        .filter(|component| !component.is_empty())
+        .collect::<Vec<_>>()
+        .join("\\")
+}
+
+/// Returns `true` if `path` is `parent` or is inside of it.
+fn is_within(path: &str, parent: &str) -> bool {
+    path.strip_prefix(parent)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('\\'))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{IconIssue, is_within, normalize};
+    use crate::entry::{Directory, File, Icon, IconFlags};
+
+    fn icon(name: &str, filename: Option<&str>, flags: IconFlags) -> Icon {
+        let mut icon = Icon::default();
+        icon.name = Some(name.to_owned());
+        icon.filename = filename.map(str::to_owned);
+        icon.flags = flags;
+        icon
+    }
+
+    #[test]
+    fn find() {
+        let files = [File {
+            destination: Some(r"{app}\MyProg.exe".to_owned()),
+            ..File::default()
+        }];
+        let mut directory = Directory::default();
+        directory.name = Some(r"{app}\Data".to_owned());
+        let icons = [
+            icon("Program", Some(r"{app}\myprog.exe"), IconFlags::empty()),
+            icon("NoTarget", None, IconFlags::empty()),
+            icon("Blank", Some(" "), IconFlags::empty()),
+            icon("Notepad", Some(r"{win}\notepad.exe"), IconFlags::empty()),
+            icon("Website", Some("https://example.com"), IconFlags::empty()),
+            icon("Runtime", Some("{code:GetPath}"), IconFlags::empty()),
+            icon("Data", Some(r"{app}\Data"), IconFlags::empty()),
+            icon("Other", Some(r"{app}\Other.exe"), IconFlags::empty()),
+            icon(
+                "Optional",
+                Some(r"{app}\Other.exe"),
+                IconFlags::CREATE_ONLY_IF_FILE_EXISTS,
+            ),
+        ];
+
+        let issues = IconIssue::find(&icons, &files, &[directory])
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            issues,
+            [
+                "Icon NoTarget has no target",
+                "Icon Blank has no target",
+                r"Icon Other targets {app}\Other.exe, which is not installed",
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(r"{app}\MyProg.exe", r"{app}\myprog.exe")]
+    #[case(r"{app}/Sub//MyProg.exe", r"{app}\sub\myprog.exe")]
+    #[case(r"{app}\", "{app}")]
+    fn normalize_path(#[case] path: &str, #[case] expected: &str) {
+        assert_eq!(normalize(path), expected);
+    }
+
+    #[rstest]
+    #[case(r"{app}\myprog.exe", "{app}", true)]
+    #[case("{app}", "{app}", true)]
+    #[case(r"{application}\myprog.exe", "{app}", false)]
+    #[case(r"{sys}\notepad.exe", r"{app}\notepad.exe", false)]
+    fn within(#[case] path: &str, #[case] parent: &str, #[case] expected: bool) {
+        assert_eq!(is_within(path, parent), expected);
+    }
+}
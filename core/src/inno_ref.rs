@@ -0,0 +1,105 @@
+use std::io::{Read, Seek};
+
+use crate::{
+    Inno, encryption::EncryptionHeader, error::InnoResult, header::HeaderRef,
+    read::stream::InnoStreamReader, version::InnoVersion,
+};
+
+/// The decompressed header stream of an installer, retained so that it can be parsed into an
+/// [`InnoRef`] that borrows from it.
+///
+/// ```
+/// use inno::HeaderStreamBuffer;
+///
+/// # fn run<R: std::io::Read + std::io::Seek>(reader: R) -> inno::error::InnoResult<()> {
+/// let buffer = HeaderStreamBuffer::read(reader)?;
+/// let inno = buffer.parse()?;
+/// println!("{:?}", inno.header().app_name());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct HeaderStreamBuffer {
+    version: InnoVersion,
+    data: Vec<u8>,
+}
+
+impl HeaderStreamBuffer {
+    /// Reads and decompresses the header stream of an installer.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Inno::new`] for the setup loader, the version and the header
+    /// stream, and an error if the stream is too short to contain the strings of the header.
+    pub fn read<R>(mut reader: R) -> InnoResult<Self>
+    where
+        R: Read + Seek,
+    {
+        Inno::<R>::read_versioned(&mut reader, |reader, _, version| {
+            if version >= 6.5 {
+                EncryptionHeader::read(&mut *reader, version)?;
+            }
+
+            let mut data = Vec::new();
+            InnoStreamReader::new(&mut *reader, version)?.read_to_end(&mut data)?;
+
+            // Validate the stream so that an ambiguous version is retried
+            HeaderRef::read(&mut data.as_slice(), version)?;
+
+            Ok(Self { version, data })
+        })
+    }
+
+    /// Returns the Inno Setup version of the installer.
+    #[must_use]
+    #[inline]
+    pub const fn version(&self) -> InnoVersion {
+        self.version
+    }
+
+    /// Returns the decompressed header stream.
+    #[must_use]
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.data.as_slice()
+    }
+
+    /// Parses the header stream into borrowed views of its contents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream is too short to contain the strings of the header.
+    pub fn parse(&self) -> InnoResult<InnoRef<'_>> {
+        Ok(InnoRef {
+            version: self.version,
+            header: HeaderRef::read(&mut self.as_bytes(), self.version)?,
+        })
+    }
+}
+
+/// A borrowed view of an installer, whose strings point into a [`HeaderStreamBuffer`] instead of
+/// being copied into owned strings.
+///
+/// This is intended for scanning many installers, where only a few fields of each are read. Only
+/// the strings of the [header](HeaderRef) are currently borrowed; use [`Inno`] for the entries.
+#[derive(Clone, Copy, Debug)]
+pub struct InnoRef<'a> {
+    version: InnoVersion,
+    header: HeaderRef<'a>,
+}
+
+impl<'a> InnoRef<'a> {
+    /// Returns the Inno Setup version of the installer.
+    #[must_use]
+    #[inline]
+    pub const fn version(&self) -> InnoVersion {
+        self.version
+    }
+
+    /// Returns the borrowed strings of the header.
+    #[must_use]
+    #[inline]
+    pub const fn header(&self) -> &HeaderRef<'a> {
+        &self.header
+    }
+}
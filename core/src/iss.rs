@@ -0,0 +1,822 @@
+//! Reconstruction of an approximate Inno Setup script (.iss) from a parsed installer.
+//!
+//! The compiled installer does not retain everything needed to regenerate the original script, so
+//! the output is a best-effort skeleton. Constants such as `{app}` are stored unexpanded and are
+//! re-emitted as-is.
+
+use std::fmt;
+
+use bitflags::Flags;
+
+use crate::{
+    InnoInner,
+    entry::{
+        CloseSetting, ComponentFlags, Condition, DeleteEntry, DirectoryFlags, IconFlags, IniFlags,
+        RegRoot, RegistryFlags, RegistryValueType, RunEntry, RunFlags, TargetType, TaskFlags,
+        WaitCondition,
+        file::{FileFlags, FileType},
+    },
+    header::{Architecture, Compression, Header, PrivilegeLevel},
+};
+
+const TASK_FLAGS: &[(TaskFlags, &str)] = &[
+    (TaskFlags::EXCLUSIVE, "exclusive"),
+    (TaskFlags::UNCHECKED, "unchecked"),
+    (TaskFlags::RESTART, "restart"),
+    (TaskFlags::CHECKED_ONCE, "checkedonce"),
+    (TaskFlags::DONT_INHERIT_CHECK, "dontinheritcheck"),
+];
+
+const COMPONENT_FLAGS: &[(ComponentFlags, &str)] = &[
+    (ComponentFlags::FIXED, "fixed"),
+    (ComponentFlags::RESTART, "restart"),
+    (
+        ComponentFlags::DISABLE_NO_UNINSTALL_WARNING,
+        "disablenouninstallwarning",
+    ),
+    (ComponentFlags::EXCLUSIVE, "exclusive"),
+    (ComponentFlags::DONT_INHERIT_CHECK, "dontinheritcheck"),
+];
+
+const DIRECTORY_FLAGS: &[(DirectoryFlags, &str)] = &[
+    (DirectoryFlags::NEVER_UNINSTALL, "uninsneveruninstall"),
+    (DirectoryFlags::DELETE_AFTER_INSTALL, "deleteafterinstall"),
+    (DirectoryFlags::ALWAYS_UNINSTALL, "uninsalwaysuninstall"),
+    (DirectoryFlags::SET_NTFS_COMPRESSION, "setntfscompression"),
+    (
+        DirectoryFlags::UNSET_NTFS_COMPRESSION,
+        "unsetntfscompression",
+    ),
+];
+
+const FILE_FLAGS: &[(FileFlags, &str)] = &[
+    (FileFlags::CONFIRM_OVERWRITE, "confirmoverwrite"),
+    (FileFlags::NEVER_UNINSTALL, "uninsneveruninstall"),
+    (FileFlags::RESTART_REPLACE, "restartreplace"),
+    (FileFlags::DELETE_AFTER_INSTALL, "deleteafterinstall"),
+    (FileFlags::REGISTER_SERVER, "regserver"),
+    (FileFlags::REGISTER_TYPE_LIB, "regtypelib"),
+    (FileFlags::SHARED_FILE, "sharedfile"),
+    (FileFlags::COMPARE_TIME_STAMP, "comparetimestamp"),
+    (FileFlags::FONT_IS_NOT_TRUE_TYPE, "fontisnttruetype"),
+    (
+        FileFlags::SKIP_IF_SOURCE_DOESNT_EXIST,
+        "skipifsourcedoesntexist",
+    ),
+    (FileFlags::OVERWRITE_READ_ONLY, "overwritereadonly"),
+    (FileFlags::ONLY_IF_DEST_FILE_EXISTS, "onlyifdestfileexists"),
+    (FileFlags::NO_REG_ERROR, "noregerror"),
+    (FileFlags::UNINS_RESTART_DELETE, "uninsrestartdelete"),
+    (FileFlags::ONLY_IF_DOESNT_EXIST, "onlyifdoesntexist"),
+    (FileFlags::IGNORE_VERSION, "ignoreversion"),
+    (FileFlags::PROMPT_IF_OLDER, "promptifolder"),
+    (FileFlags::DONT_COPY, "dontcopy"),
+    (FileFlags::UNINS_REMOVE_READ_ONLY, "uninsremovereadonly"),
+    (FileFlags::RECURSE_SUB_DIRS_EXTERNAL, "recursesubdirs"),
+    (
+        FileFlags::REPLACE_SAME_VERSION_IF_CONTENTS_DIFFER,
+        "replacesameversion",
+    ),
+    (FileFlags::DONT_VERIFY_CHECKSUM, "dontverifychecksum"),
+    (
+        FileFlags::UNINS_NO_SHARED_FILE_PROMPT,
+        "uninsnosharedfileprompt",
+    ),
+    (FileFlags::CREATE_ALL_SUB_DIRS, "createallsubdirs"),
+    (FileFlags::BITS_32, "32bit"),
+    (FileFlags::BITS_64, "64bit"),
+    (FileFlags::SET_NTFS_COMPRESSION, "setntfscompression"),
+    (FileFlags::UNSET_NTFS_COMPRESSION, "unsetntfscompression"),
+    (FileFlags::GAC_INSTALL, "gacinstall"),
+    (FileFlags::DOWNLOAD, "download"),
+    (FileFlags::EXTRACT_ARCHIVE, "extractarchive"),
+    (FileFlags::IS_README_FILE, "isreadme"),
+];
+
+const ICON_FLAGS: &[(IconFlags, &str)] = &[
+    (IconFlags::NEVER_UNINSTALL, "uninsneveruninstall"),
+    (
+        IconFlags::CREATE_ONLY_IF_FILE_EXISTS,
+        "createonlyiffileexists",
+    ),
+    (IconFlags::USE_APP_PATHS, "useapppaths"),
+    (IconFlags::FOLDER_SHORTCUT, "foldershortcut"),
+    (
+        IconFlags::EXCLUDE_FROM_SHOW_IN_NEW_INSTALL,
+        "excludefromshowinnewinstall",
+    ),
+    (IconFlags::PREVENT_PINNING, "preventpinning"),
+    (IconFlags::RUN_MINIMIZED, "runminimized"),
+];
+
+const INI_FLAGS: &[(IniFlags, &str)] = &[
+    (
+        IniFlags::CREATE_KEY_IF_DOESNT_EXIST,
+        "createkeyifdoesntexist",
+    ),
+    (IniFlags::UNINSTALL_DELETE_ENTRY, "uninsdeleteentry"),
+    (
+        IniFlags::UNINSTALL_DELETE_ENTIRE_SECTION,
+        "uninsdeletesection",
+    ),
+    (
+        IniFlags::UNINSTALL_DELETE_SECTION_IF_EMPTY,
+        "uninsdeletesectionifempty",
+    ),
+];
+
+const REGISTRY_FLAGS: &[(RegistryFlags, &str)] = &[
+    (
+        RegistryFlags::CREATE_VALUE_IF_DOESNT_EXIST,
+        "createvalueifdoesntexist",
+    ),
+    (RegistryFlags::UNINSTALL_DELETE_VALUE, "uninsdeletevalue"),
+    (RegistryFlags::UNINSTALL_CLEAR_VALUE, "uninsclearvalue"),
+    (RegistryFlags::UNINSTALL_DELETE_ENTIRE_KEY, "uninsdeletekey"),
+    (
+        RegistryFlags::UNINSTALL_DELETE_ENTIRE_KEY_IF_EMPTY,
+        "uninsdeletekeyifempty",
+    ),
+    (RegistryFlags::PRESERVE_STRING_TYPE, "preservestringtype"),
+    (RegistryFlags::DELETE_KEY, "deletekey"),
+    (RegistryFlags::DELETE_VALUE, "deletevalue"),
+    (RegistryFlags::NO_ERROR, "noerror"),
+    (RegistryFlags::DONT_CREATE_KEY, "dontcreatekey"),
+    (RegistryFlags::BITS_32, "32bit"),
+    (RegistryFlags::BITS_64, "64bit"),
+];
+
+const RUN_FLAGS: &[(RunFlags, &str)] = &[
+    (RunFlags::SHELL_EXECUTE, "shellexec"),
+    (RunFlags::SKIP_IF_DOESNT_EXIST, "skipifdoesntexist"),
+    (RunFlags::POST_INSTALL, "postinstall"),
+    (RunFlags::UNCHECKED, "unchecked"),
+    (RunFlags::SKIP_IF_SILENT, "skipifsilent"),
+    (RunFlags::SKIP_IF_NOT_SILENT, "skipifnotsilent"),
+    (RunFlags::HIDE_WIZARD, "hidewizard"),
+    (RunFlags::BITS_32, "32bit"),
+    (RunFlags::BITS_64, "64bit"),
+    (RunFlags::RUN_AS_ORIGINAL_USER, "runasoriginaluser"),
+    (RunFlags::DONT_LOG_PARAMETERS, "dontlogparameters"),
+    (RunFlags::LOG_OUTPUT, "logoutput"),
+];
+
+/// `SW_HIDE`
+const SHOW_COMMAND_HIDDEN: i32 = 0;
+
+/// `SW_SHOWMINIMIZED`
+const SHOW_COMMAND_MINIMIZED: i32 = 2;
+
+/// `SW_SHOWMAXIMIZED`
+const SHOW_COMMAND_MAXIMIZED: i32 = 3;
+
+/// `SW_SHOWMINNOACTIVE`
+const SHOW_COMMAND_MINIMIZED_NO_ACTIVE: i32 = 7;
+
+/// Displays an approximate Inno Setup script for a parsed installer.
+pub struct Iss<'inno> {
+    inner: &'inno InnoInner,
+}
+
+impl<'inno> Iss<'inno> {
+    pub const fn new(inner: &'inno InnoInner) -> Self {
+        Self { inner }
+    }
+
+    fn fmt_setup(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "[Setup]")?;
+
+        for (name, value) in setup_directives(&self.inner.header) {
+            writeln!(f, "{name}={value}")?;
+        }
+
+        Ok(())
+    }
+
+    fn fmt_languages(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        section(f, "Languages", &self.inner.languages, |language| {
+            let name = language.name();
+            let messages_file =
+                if name.eq_ignore_ascii_case("english") || name.eq_ignore_ascii_case("default") {
+                    "compiler:Default.isl".to_owned()
+                } else {
+                    let mut chars = name.chars();
+                    let capitalized = chars.next().map_or_else(String::new, |first| {
+                        first.to_uppercase().chain(chars).collect()
+                    });
+                    format!("compiler:Languages\\{capitalized}.isl")
+                };
+
+            Params::new()
+                .quoted("Name", Some(name))
+                .quoted("MessagesFile", Some(&messages_file))
+        })
+    }
+
+    fn fmt_types(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        section(f, "Types", &self.inner.type_entries, |r#type| {
+            Params::new()
+                .quoted("Name", r#type.name())
+                .quoted("Description", r#type.description())
+                .quoted("Languages", r#type.languages())
+                .quoted("Check", r#type.check())
+                .flags(if r#type.is_custom() {
+                    vec!["iscustom"]
+                } else {
+                    Vec::new()
+                })
+        })
+    }
+
+    fn fmt_components(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        section(f, "Components", &self.inner.components, |component| {
+            let params = Params::new()
+                .quoted("Name", component.name())
+                .quoted("Description", component.description())
+                .quoted("Types", component.types())
+                .quoted("Languages", component.languages())
+                .quoted("Check", component.check_once());
+
+            if component.extra_disk_space_required() == 0 {
+                params
+            } else {
+                params.raw(
+                    "ExtraDiskSpaceRequired",
+                    component.extra_disk_space_required(),
+                )
+            }
+            .flags(flag_names(component.flags(), COMPONENT_FLAGS))
+        })
+    }
+
+    fn fmt_tasks(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        section(f, "Tasks", &self.inner.tasks, |task| {
+            Params::new()
+                .quoted("Name", task.name())
+                .quoted("Description", task.description())
+                .quoted("GroupDescription", task.group_description())
+                .quoted("Components", task.components())
+                .quoted("Languages", task.languages())
+                .quoted("Check", task.check())
+                .flags(flag_names(task.flags(), TASK_FLAGS))
+        })
+    }
+
+    fn fmt_directories(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        section(f, "Dirs", &self.inner.directories, |directory| {
+            Params::new()
+                .quoted("Name", directory.name())
+                .condition(directory.condition())
+                .flags(flag_names(directory.flags(), DIRECTORY_FLAGS))
+        })
+    }
+
+    fn fmt_files(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let files = self
+            .inner
+            .files
+            .iter()
+            .filter(|file| file.r#type() == FileType::UserFile)
+            .collect::<Vec<_>>();
+
+        section(f, "Files", &files, |file| {
+            let destination = file.destination().unwrap_or_default();
+            let (destination_dir, destination_name) = destination
+                .rsplit_once('\\')
+                .unwrap_or(("{app}", destination));
+
+            let source = file
+                .source()
+                .filter(|source| !source.is_empty())
+                .unwrap_or(destination_name);
+
+            let params = Params::new().quoted("Source", Some(source));
+
+            let params = params.quoted("DestDir", Some(destination_dir));
+
+            let params = if file.flags().contains(FileFlags::CUSTOM_DEST_NAME) {
+                params.quoted("DestName", Some(destination_name))
+            } else {
+                params
+            };
+
+            let mut flags = flag_names(file.flags(), FILE_FLAGS);
+            if file.is_external() {
+                flags.insert(0, "external");
+            }
+
+            params
+                .quoted("FontInstall", file.install_font_name())
+                .quoted("StrongAssemblyName", file.strong_assembly_name())
+                .condition(file.condition())
+                .flags(flags)
+        })
+    }
+
+    fn fmt_icons(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        section(f, "Icons", &self.inner.icons, |icon| {
+            let params = Params::new()
+                .quoted("Name", icon.name())
+                .quoted("Filename", icon.filename())
+                .quoted("Parameters", icon.parameters())
+                .quoted("WorkingDir", icon.working_directory())
+                .quoted("IconFilename", icon.file());
+
+            let params = if icon.index() == 0 {
+                params
+            } else {
+                params.raw("IconIndex", icon.index())
+            };
+
+            let mut flags = flag_names(icon.flags(), ICON_FLAGS);
+            match icon.close_on_exit() {
+                CloseSetting::NoSetting => {}
+                CloseSetting::CloseOnExit => flags.push("closeonexit"),
+                CloseSetting::DontCloseOnExit => flags.push("dontcloseonexit"),
+            }
+            if let Some(show_command) = show_command(icon.show_command()) {
+                flags.push(show_command);
+            }
+
+            params
+                .quoted("Comment", icon.comment())
+                .quoted("AppUserModelID", icon.app_user_model_id())
+                .condition(icon.condition())
+                .flags(flags)
+        })
+    }
+
+    fn fmt_ini(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        section(f, "INI", &self.inner.ini_entries, |ini| {
+            Params::new()
+                .quoted("Filename", Some(ini.file_path()))
+                .quoted("Section", ini.section_name())
+                .quoted("Key", ini.key_name())
+                .quoted("String", ini.value())
+                .condition(ini.condition())
+                .flags(flag_names(ini.flags(), INI_FLAGS))
+        })
+    }
+
+    fn fmt_registry(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        section(f, "Registry", &self.inner.registry_entries, |registry| {
+            Params::new()
+                .raw("Root", registry_root(registry.registry_root()))
+                .quoted("Subkey", registry.key())
+                .raw("ValueType", registry_value_type(registry.r#type()))
+                .quoted("ValueName", registry.name())
+                .quoted("ValueData", registry.value())
+                .condition(registry.condition())
+                .flags(flag_names(registry.flags(), REGISTRY_FLAGS))
+        })
+    }
+
+    fn fmt_delete(f: &mut fmt::Formatter<'_>, name: &str, entries: &[DeleteEntry]) -> fmt::Result {
+        section(f, name, entries, |entry| {
+            Params::new()
+                .raw("Type", delete_type(entry.target_type()))
+                .quoted("Name", Some(entry.name()))
+                .condition(entry.condition())
+        })
+    }
+
+    fn fmt_run(f: &mut fmt::Formatter<'_>, name: &str, entries: &[RunEntry]) -> fmt::Result {
+        section(f, name, entries, |run| {
+            let mut flags = flag_names(run.options(), RUN_FLAGS);
+            match run.wait_condition() {
+                WaitCondition::WaitUntilTerminated => {}
+                WaitCondition::NoWait => flags.push("nowait"),
+                WaitCondition::WaitUntilIdle => flags.push("waituntilidle"),
+            }
+            if let Some(show_command) = show_command(run.show_command()) {
+                flags.push(show_command);
+            }
+
+            Params::new()
+                .quoted("Filename", run.name())
+                .quoted("Parameters", run.parameters())
+                .quoted("WorkingDir", run.working_directory())
+                .quoted("StatusMsg", run.status_message())
+                .quoted("Description", run.description())
+                .quoted("Verb", run.verb())
+                .quoted("RunOnceId", run.run_once_id())
+                .condition(run.condition())
+                .flags(flags)
+        })
+    }
+}
+
+impl fmt::Display for Iss<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "; Reconstructed from an Inno Setup {} installer",
+            self.inner.version
+        )?;
+        writeln!(f)?;
+
+        self.fmt_setup(f)?;
+        self.fmt_languages(f)?;
+        self.fmt_types(f)?;
+        self.fmt_components(f)?;
+        self.fmt_tasks(f)?;
+        self.fmt_directories(f)?;
+        self.fmt_files(f)?;
+        self.fmt_icons(f)?;
+        self.fmt_ini(f)?;
+        self.fmt_registry(f)?;
+        Self::fmt_delete(f, "InstallDelete", &self.inner.delete_entries)?;
+        Self::fmt_delete(f, "UninstallDelete", &self.inner.uninstall_delete_entries)?;
+        Self::fmt_run(f, "Run", &self.inner.run_entries)?;
+        Self::fmt_run(f, "UninstallRun", &self.inner.uninstall_run_entries)
+    }
+}
+
+/// The parameters of a single line within a script section.
+#[derive(Default)]
+struct Params(Vec<(&'static str, String)>);
+
+impl Params {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a quoted parameter if the value is present and non-empty.
+    fn quoted(mut self, name: &'static str, value: Option<&str>) -> Self {
+        if let Some(value) = value.filter(|value| !value.is_empty()) {
+            self.0
+                .push((name, format!("\"{}\"", value.replace('"', "\"\""))));
+        }
+        self
+    }
+
+    /// Adds an unquoted parameter.
+    fn raw<T: fmt::Display>(mut self, name: &'static str, value: T) -> Self {
+        self.0.push((name, value.to_string()));
+        self
+    }
+
+    /// Adds the parameters of the conditions under which the entry is installed.
+    fn condition(self, condition: &Condition) -> Self {
+        self.quoted("Components", condition.components())
+            .quoted("Tasks", condition.tasks())
+            .quoted("Languages", condition.languages())
+            .quoted("Check", condition.check())
+            .quoted("BeforeInstall", condition.before_install())
+            .quoted("AfterInstall", condition.after_install())
+    }
+
+    /// Adds the `Flags` parameter if there are any flags.
+    fn flags(mut self, flags: Vec<&str>) -> Self {
+        if !flags.is_empty() {
+            self.0.push(("Flags", flags.join(" ")));
+        }
+        self
+    }
+}
+
+impl fmt::Display for Params {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (name, value)) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{name}: {value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes a section with a line per entry. Nothing is written if there are no entries.
+fn section<T>(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    entries: &[T],
+    params: impl Fn(&T) -> Params,
+) -> fmt::Result {
+    if entries.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(f)?;
+    writeln!(f, "[{name}]")?;
+    for entry in entries {
+        writeln!(f, "{}", params(entry))?;
+    }
+
+    Ok(())
+}
+
+fn flag_names<F: Flags + Copy>(flags: F, names: &[(F, &'static str)]) -> Vec<&'static str> {
+    names
+        .iter()
+        .filter(|(flag, _)| flags.contains(*flag))
+        .map(|&(_, name)| name)
+        .collect()
+}
+
+fn architecture_expression(allowed: Architecture, disallowed: Architecture) -> String {
+    fn join(architectures: Architecture) -> String {
+        let names = architectures.names().collect::<Vec<_>>();
+        if names.len() > 1 {
+            format!("({})", names.join(" or "))
+        } else {
+            names.join("")
+        }
+    }
+
+    match (allowed.is_empty(), disallowed.is_empty()) {
+        (true, true) => String::new(),
+        (false, true) => allowed.names().collect::<Vec<_>>().join(" or "),
+        (true, false) => format!("not {}", join(disallowed)),
+        (false, false) => format!("{} and not {}", join(allowed), join(disallowed)),
+    }
+}
+
+const fn compression(compression: Compression) -> Option<&'static str> {
+    match compression {
+        Compression::Stored => Some("none"),
+        Compression::Zlib => Some("zip"),
+        Compression::BZip2 => Some("bzip"),
+        Compression::LZMA1 => Some("lzma"),
+        Compression::LZMA2 => Some("lzma2"),
+        Compression::Unknown => None,
+    }
+}
+
+const fn privilege_level(privilege_level: PrivilegeLevel) -> &'static str {
+    match privilege_level {
+        PrivilegeLevel::None => "none",
+        PrivilegeLevel::PowerUser => "poweruser",
+        PrivilegeLevel::Admin => "admin",
+        PrivilegeLevel::Lowest => "lowest",
+    }
+}
+
+const fn registry_root(root: RegRoot) -> &'static str {
+    match root {
+        RegRoot::Unset => "HKA",
+        root => root.short(),
+    }
+}
+
+const fn registry_value_type(value_type: RegistryValueType) -> &'static str {
+    match value_type {
+        RegistryValueType::None => "none",
+        RegistryValueType::String => "string",
+        RegistryValueType::ExpandString => "expandsz",
+        RegistryValueType::DWord => "dword",
+        RegistryValueType::Binary => "binary",
+        RegistryValueType::MultiString => "multisz",
+        RegistryValueType::QWord => "qword",
+    }
+}
+
+const fn delete_type(target_type: TargetType) -> &'static str {
+    match target_type {
+        TargetType::File => "files",
+        TargetType::FilesAndSubDirectories => "filesandordirs",
+        TargetType::DirectoryIfEmpty => "dirifempty",
+    }
+}
+
+/// Returns the `[Setup]` directives that can be recovered from the header, in script order.
+///
+/// Directives that are empty or that have their default value are omitted, except for those that
+/// are always written out.
+pub fn setup_directives(header: &Header) -> Vec<(&'static str, String)> {
+    let string_directives = [
+        ("AppId", header.app_id()),
+        ("AppName", header.app_name()),
+        ("AppVersion", header.app_version()),
+        ("AppVerName", header.app_versioned_name()),
+        ("AppPublisher", header.app_publisher()),
+        ("AppPublisherURL", header.app_publisher_url()),
+        ("AppSupportURL", header.app_support_url()),
+        ("AppUpdatesURL", header.app_updates_url()),
+        ("AppSupportPhone", header.app_support_phone()),
+        ("AppContact", header.app_contact()),
+        ("AppComments", header.app_comments()),
+        ("AppCopyright", header.app_copyright()),
+        ("AppReadmeFile", header.app_readme_file()),
+        ("AppModifyPath", header.app_modify_path()),
+        ("AppMutex", header.app_mutex()),
+        ("SetupMutex", header.setup_mutex()),
+        ("DefaultDirName", header.default_dir_name()),
+        ("DefaultGroupName", header.default_group_name()),
+        ("DefaultUserInfoName", header.default_user_name()),
+        ("DefaultUserInfoOrg", header.default_user_organization()),
+        ("DefaultUserInfoSerial", header.default_serial()),
+        ("UninstallDisplayName", header.uninstall_name()),
+        ("UninstallDisplayIcon", header.uninstall_icon()),
+        ("UninstallFilesDir", header.uninstall_files_dir()),
+        ("OutputBaseFilename", header.base_filename()),
+        (
+            "CloseApplicationsFilterExcludes",
+            header.close_applications_filter_excludes(),
+        ),
+    ];
+
+    let mut directives = string_directives
+        .into_iter()
+        .filter_map(|(name, value)| {
+            value
+                .filter(|value| !value.is_empty())
+                .map(|value| (name, value.to_owned()))
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(compression) = compression(header.compression()) {
+        directives.push(("Compression", compression.to_owned()));
+    }
+
+    directives.push((
+        "PrivilegesRequired",
+        privilege_level(header.privileges_required()).to_owned(),
+    ));
+
+    let overrides = header.privileges_required_overrides_allowed();
+    if !overrides.is_empty() {
+        directives.push(("PrivilegesRequiredOverridesAllowed", overrides.to_string()));
+    }
+
+    let architectures_allowed = architecture_expression(
+        header.architectures_allowed(),
+        header.architectures_disallowed(),
+    );
+    if !architectures_allowed.is_empty() {
+        directives.push(("ArchitecturesAllowed", architectures_allowed));
+    }
+
+    let install_in_64_bit_mode = architecture_expression(
+        header.architectures_install_in_64_bit_mode(),
+        Architecture::empty(),
+    );
+    if !install_in_64_bit_mode.is_empty() {
+        directives.push(("ArchitecturesInstallIn64BitMode", install_in_64_bit_mode));
+    }
+
+    directives.push((
+        "WizardStyle",
+        header.wizard_style().as_str().to_ascii_lowercase(),
+    ));
+
+    directives.push((
+        "DisableDirPage",
+        header
+            .is_directory_page_disabled()
+            .as_str()
+            .to_ascii_lowercase(),
+    ));
+
+    directives.push((
+        "DisableProgramGroupPage",
+        header
+            .is_program_group_page_disabled()
+            .as_str()
+            .to_ascii_lowercase(),
+    ));
+
+    if !header.is_uninstallable() {
+        directives.push(("Uninstallable", "no".to_owned()));
+    }
+
+    if header.changes_environment() {
+        directives.push(("ChangesEnvironment", "yes".to_owned()));
+    }
+
+    if header.changes_associations() {
+        directives.push(("ChangesAssociations", "yes".to_owned()));
+    }
+
+    directives
+}
+
+const fn show_command(show_command: i32) -> Option<&'static str> {
+    match show_command {
+        SHOW_COMMAND_HIDDEN => Some("runhidden"),
+        SHOW_COMMAND_MINIMIZED | SHOW_COMMAND_MINIMIZED_NO_ACTIVE => Some("runminimized"),
+        SHOW_COMMAND_MAXIMIZED => Some("runmaximized"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{Iss, Params, architecture_expression};
+    use crate::{
+        InnoInner,
+        entry::{
+            Condition, File, RunEntry,
+            file::{FileFlags, FileType},
+        },
+        header::Architecture,
+        version::InnoVersion,
+    };
+
+    #[rstest]
+    #[case(Architecture::empty(), Architecture::empty(), "")]
+    #[case(Architecture::X64_COMPATIBLE, Architecture::empty(), "x64compatible")]
+    #[case(
+        Architecture::X64_OS | Architecture::ARM64,
+        Architecture::empty(),
+        "arm64 or x64os"
+    )]
+    #[case(
+        Architecture::X64_COMPATIBLE,
+        Architecture::ARM64,
+        "x64compatible and not arm64"
+    )]
+    #[case(
+        Architecture::X86_COMPATIBLE,
+        Architecture::ARM64 | Architecture::X64_OS,
+        "x86compatible and not (arm64 or x64os)"
+    )]
+    fn architecture(
+        #[case] allowed: Architecture,
+        #[case] disallowed: Architecture,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(architecture_expression(allowed, disallowed), expected);
+    }
+
+    #[test]
+    fn params() {
+        let params = Params::new()
+            .quoted("Name", Some("say \"hi\""))
+            .quoted("Description", None)
+            .raw("Root", "HKLM")
+            .flags(vec!["uninsdeletekey", "64bit"]);
+
+        assert_eq!(
+            params.to_string(),
+            r#"Name: "say ""hi"""; Root: HKLM; Flags: uninsdeletekey 64bit"#
+        );
+    }
+
+    #[test]
+    fn script() {
+        let mut run = RunEntry::default();
+        run.name = Some("{app}\\app.exe".to_owned());
+        run.condition = Condition {
+            tasks: Some("launch".to_owned()),
+            ..Condition::default()
+        };
+
+        let inner = InnoInner {
+            files: vec![
+                File {
+                    source: Some("bin\\app.exe".to_owned()),
+                    destination: Some("{app}\\app.exe".to_owned()),
+                    flags: FileFlags::IGNORE_VERSION,
+                    condition: Condition {
+                        components: Some("main".to_owned()),
+                        tasks: Some("desktopicon and not quicklaunch".to_owned()),
+                        languages: Some("en de".to_owned()),
+                        check: Some("IsWin64".to_owned()),
+                        ..Condition::default()
+                    },
+                    ..File::default()
+                },
+                File {
+                    source: Some("readme-en.txt".to_owned()),
+                    destination: Some("{app}\\docs\\readme.txt".to_owned()),
+                    flags: FileFlags::CUSTOM_DEST_NAME | FileFlags::IS_README_FILE,
+                    ..File::default()
+                },
+                File {
+                    r#type: FileType::UninstallExe,
+                    ..File::default()
+                },
+            ],
+            run_entries: vec![run],
+            version: InnoVersion::new(6, 7, 0, 0),
+            ..InnoInner::default()
+        };
+
+        assert_eq!(
+            Iss::new(&inner).to_string(),
+            [
+                "; Reconstructed from an Inno Setup 6.7.0 installer",
+                "",
+                "[Setup]",
+                "PrivilegesRequired=none",
+                "WizardStyle=light",
+                "DisableDirPage=auto",
+                "DisableProgramGroupPage=auto",
+                "Uninstallable=no",
+                "",
+                "[Files]",
+                r#"Source: "bin\app.exe"; DestDir: "{app}"; Components: "main"; Tasks: "desktopicon and not quicklaunch"; Languages: "en de"; Check: "IsWin64"; Flags: ignoreversion"#,
+                r#"Source: "readme-en.txt"; DestDir: "{app}\docs"; DestName: "readme.txt"; Flags: isreadme"#,
+                "",
+                "[Run]",
+                r#"Filename: "{app}\app.exe"; Tasks: "launch"; Flags: runhidden"#,
+                "",
+            ]
+            .join("\n")
+        );
+    }
+}
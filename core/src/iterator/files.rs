@@ -1,4 +1,4 @@
-use std::io::{Read, Seek};
+use std::io::{self, Read, Seek};
 
 use super::ExtractEntry;
 use crate::{Inno, error::InnoResult, iterator::FilteredFilesIterator};
@@ -11,6 +11,19 @@ impl<'reader, R: Read + Seek> FilesIterator<'reader, R> {
     pub fn new(inno: &'reader mut Inno<R>) -> Self {
         Self(FilteredFilesIterator::new(inno, |_| true))
     }
+
+    /// Creates a new [`FilesIterator`] that reads file data from external disk slices.
+    pub fn with_slices<F, S>(inno: &Inno<R>, slice_reader: F) -> Self
+    where
+        F: FnMut(u32) -> io::Result<S> + 'reader,
+        S: Read + Seek + 'reader,
+    {
+        Self(FilteredFilesIterator::with_slices(
+            inno,
+            slice_reader,
+            |_| true,
+        ))
+    }
 }
 
 impl<R: Read + Seek> Iterator for FilesIterator<'_, R> {
@@ -1,65 +1,116 @@
 use std::{
     borrow::Cow,
+    cell::RefCell,
     collections::{BTreeMap, BTreeSet},
     io,
-    io::{Read, Seek},
+    io::{Read, Seek, SeekFrom},
+    rc::Rc,
 };
 
 use crate::{
     Inno,
     error::{InnoError, InnoResult},
     iterator::ExtractEntry,
-    read::{chunk::Chunk, data_chunk::DataChunkReader},
+    read::{
+        chunk::Chunk,
+        data_chunk::DataChunkReader,
+        slice::{SliceReader, SliceSource},
+    },
 };
 
-enum FilesReader<'reader, R: Read + Seek> {
-    Source(Option<&'reader mut R>),
-    Chunk(Option<DataChunkReader<&'reader mut R>>),
+/// The source of the installer's data stream.
+pub enum DataSource<'reader, R: Read + Seek> {
+    /// The data is embedded in the setup executable, starting at the given offset.
+    Embedded { reader: &'reader mut R, offset: u64 },
+
+    /// The data is stored in external disk slice files.
+    Slices(SliceReader<'reader, Box<dyn SliceSource + 'reader>>),
 }
 
-impl<R: Read + Seek> FilesReader<'_, R> {
-    pub fn to_source_mut(&mut self) -> &mut Self {
-        if let Self::Chunk(reader) = self
-            && let Some(reader) = reader.take()
-        {
-            let reader = reader.into_inner().into_inner();
-            *self = FilesReader::Source(Some(reader));
+impl<R: Read + Seek> DataSource<'_, R> {
+    /// Positions the source at the start of the given chunk.
+    fn seek_chunk(&mut self, chunk: &Chunk) -> InnoResult<()> {
+        match self {
+            Self::Embedded { reader, offset } => {
+                let start = offset.checked_add(chunk.start_offset()).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "chunk offset {} is beyond the end of the data stream",
+                            chunk.start_offset()
+                        ),
+                    )
+                })?;
+                reader.seek(SeekFrom::Start(start))?;
+            }
+            Self::Slices(reader) => reader.seek(chunk.first_slice(), chunk.start_offset())?,
         }
 
-        self
+        Ok(())
     }
+}
 
-    pub fn to_chunk_mut(&mut self, data_offset: u64, chunk: &Chunk) -> InnoResult<&mut Self> {
-        if let Self::Source(reader) = self
-            && let Some(reader) = reader.take()
-        {
-            let chunk_reader = DataChunkReader::new(reader, data_offset, chunk)?;
-            *self = FilesReader::Chunk(Some(chunk_reader));
+impl<R: Read + Seek> Read for DataSource<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Self::Embedded { reader, .. } => reader.read(buf),
+            Self::Slices(reader) => reader.read(buf),
         }
+    }
+}
+
+/// A shared handle to the data source, so that the source isn't lost when a chunk reader that owns
+/// a handle fails to open.
+struct SharedSource<'reader, R: Read + Seek>(Rc<RefCell<DataSource<'reader, R>>>);
 
-        Ok(self)
+impl<R: Read + Seek> Read for SharedSource<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.borrow_mut().read(buf)
     }
+}
 
-    pub fn reinitialize(&mut self, data_offset: u64, chunk: &Chunk) -> InnoResult<&mut Self> {
-        self.to_source_mut();
-        self.to_chunk_mut(data_offset, chunk)
+struct FilesReader<'reader, R: Read + Seek> {
+    source: Rc<RefCell<DataSource<'reader, R>>>,
+    chunk: Option<DataChunkReader<SharedSource<'reader, R>>>,
+}
+
+impl<'reader, R: Read + Seek> FilesReader<'reader, R> {
+    fn new(source: DataSource<'reader, R>) -> Self {
+        Self {
+            source: Rc::new(RefCell::new(source)),
+            chunk: None,
+        }
+    }
+
+    /// Opens the given chunk for reading, closing the current chunk.
+    ///
+    /// If the chunk can't be opened, such as because it is encrypted, no chunk is open until
+    /// another chunk is opened successfully. The data source is kept either way.
+    fn open_chunk(&mut self, chunk: &Chunk) -> InnoResult<()> {
+        self.chunk = None;
+
+        self.source.borrow_mut().seek_chunk(chunk)?;
+        self.chunk = Some(DataChunkReader::from_reader(
+            SharedSource(Rc::clone(&self.source)),
+            chunk,
+        )?);
+
+        Ok(())
     }
 }
 
 impl<R: Read + Seek> Read for FilesReader<'_, R> {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
-        match self {
-            Self::Source(Some(reader)) => reader.read(buf),
-            Self::Chunk(Some(reader)) => reader.read(buf),
-            _ => unreachable!(),
+        match &mut self.chunk {
+            Some(chunk) => chunk.read(buf),
+            None => Err(io::Error::other("No data chunk is open")),
         }
     }
 }
 
 pub struct FilteredFilesIterator<'reader, R: Read + Seek> {
     reader: FilesReader<'reader, R>,
-    data_offset: u64,
-    chunks: BTreeMap<u64, BTreeSet<ExtractEntry>>,
+    chunks: BTreeMap<(u32, u64), BTreeSet<ExtractEntry>>,
     entries: BTreeSet<ExtractEntry>,
     current_position: u64,
     previous_location_index: Option<u32>,
@@ -67,42 +118,86 @@ pub struct FilteredFilesIterator<'reader, R: Read + Seek> {
 }
 
 impl<'reader, R: Read + Seek> FilteredFilesIterator<'reader, R> {
-    pub fn new<P>(inno: &'reader mut Inno<R>, mut predicate: P) -> Self
+    pub fn new<P>(inno: &'reader mut Inno<R>, predicate: P) -> Self
+    where
+        P: FnMut(&ExtractEntry) -> bool,
+    {
+        let chunks = Self::chunks(inno, predicate);
+
+        let offset = inno.data_base_offset();
+
+        Self::with_source(
+            DataSource::Embedded {
+                reader: &mut inno.reader,
+                offset,
+            },
+            chunks,
+        )
+    }
+
+    pub fn with_slices<F, S, P>(inno: &Inno<R>, mut slice_reader: F, predicate: P) -> Self
+    where
+        F: FnMut(u32) -> io::Result<S> + 'reader,
+        S: Read + Seek + 'reader,
+        P: FnMut(&ExtractEntry) -> bool,
+    {
+        let chunks = Self::chunks(inno, predicate);
+
+        let slices = SliceReader::new(move |slice| {
+            slice_reader(slice).map(|reader| Box::new(reader) as Box<dyn SliceSource>)
+        });
+
+        Self::with_source(DataSource::Slices(slices), chunks)
+    }
+
+    fn with_source(
+        source: DataSource<'reader, R>,
+        chunks: BTreeMap<(u32, u64), BTreeSet<ExtractEntry>>,
+    ) -> Self {
+        Self {
+            reader: FilesReader::new(source),
+            entries: BTreeSet::new(),
+            chunks,
+            current_position: 0,
+            previous_location_index: None,
+            data: Vec::new(),
+        }
+    }
+
+    /// Groups the entries that match the predicate by their chunk's first slice and start offset
+    /// to allow for sequential extraction.
+    fn chunks<P>(inno: &Inno<R>, mut predicate: P) -> BTreeMap<(u32, u64), BTreeSet<ExtractEntry>>
     where
         P: FnMut(&ExtractEntry) -> bool,
     {
-        // Group entries by their chunk start offset to allow for sequential extraction
         let mut chunks = BTreeMap::<_, BTreeSet<_>>::new();
 
-        for file in inno.file_entries() {
-            let Some(location) = inno.file_locations().get(file.location() as usize) else {
+        for (file, location) in inno.file_with_location() {
+            let Some(location) = location else {
                 continue;
             };
 
             let extract_entry = ExtractEntry::new(file.clone(), *location);
 
             if predicate(&extract_entry) {
+                let chunk = location.chunk();
                 chunks
-                    .entry(location.chunk().start_offset())
+                    .entry((chunk.first_slice(), chunk.start_offset()))
                     .or_default()
                     .insert(extract_entry);
             }
         }
 
-        Self {
-            reader: FilesReader::Source(Some(&mut inno.reader)),
-            data_offset: inno
-                .inner
-                .setup_loader
-                .data_offset()
-                .try_into()
-                .unwrap_or_else(|_| unreachable!()),
-            entries: BTreeSet::new(),
-            chunks,
-            current_position: 0,
-            previous_location_index: None,
-            data: Vec::new(),
-        }
+        chunks
+    }
+
+    /// Returns the entry that the next call to [`next`](Iterator::next) will read.
+    pub(crate) fn peek_entry(&self) -> Option<&ExtractEntry> {
+        self.entries.first().or_else(|| {
+            self.chunks
+                .first_key_value()
+                .and_then(|(_, entries)| entries.first())
+        })
     }
 }
 
@@ -117,12 +212,13 @@ fn next(&mut self) -> Option<Self::Item> {
 
             let entry = self.entries.pop_first()?;
 
-            if let Err(err) = self
-                .reader
-                .reinitialize(self.data_offset, entry.file_location().chunk())
-            {
+            if let Err(err) = self.reader.open_chunk(entry.file_location().chunk()) {
+                // Skip the rest of the chunk, as none of its files can be read
+                self.entries.clear();
+                self.previous_location_index = None;
                 return Some(Err(err));
             }
+            self.current_position = 0;
 
             entry
         };
@@ -147,6 +243,8 @@ fn next(&mut self) -> Option<Self::Item> {
                     .take(target_offset - self.current_position),
                 &mut io::sink(),
             ) {
+                self.entries.clear();
+                self.previous_location_index = None;
                 return Some(Err(err.into()));
             }
 
@@ -158,6 +256,9 @@ fn next(&mut self) -> Option<Self::Item> {
 
         // Read the file data
         if let Err(err) = self.reader.read_exact(&mut self.data) {
+            // The position within the chunk is unknown, so the rest of it can't be read
+            self.entries.clear();
+            self.previous_location_index = None;
             return Some(Err(err.into()));
         }
         self.current_position += file_metadata.size();
@@ -189,3 +290,59 @@ fn size_hint(&self) -> (usize, Option<usize>) {
 }
 
 impl<R: Read + Seek> ExactSizeIterator for FilteredFilesIterator<'_, R> {}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use crate::test_support::{file_at, inno_with_files, stored_location};
+
+    #[test]
+    fn files_across_chunks() {
+        // The file in the second chunk is not at the start of it, so reading it must skip from the
+        // start of the second chunk rather than from where the first chunk's file ended
+        let mut inno = inno_with_files(
+            vec![file_at(0), file_at(1)],
+            vec![
+                stored_location(0, 4, 0, b"abcd"),
+                stored_location(8, 6, 2, b"efgh"),
+            ],
+        );
+        inno.reader = Cursor::new(b"zlb\x1aabcdzlb\x1axyefgh".to_vec());
+
+        let data = inno
+            .files()
+            .map(|result| result.map(|(_, data)| data))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(data, [b"abcd", b"efgh"]);
+    }
+
+    #[test]
+    fn files_beyond_data_stream() {
+        let mut inno = inno_with_files(
+            vec![file_at(0)],
+            vec![stored_location(u64::MAX, 4, 0, b"abcd")],
+        );
+        inno.inner.setup_loader.data_offset = 1;
+
+        assert!(inno.files().next().unwrap().is_err());
+    }
+
+    #[test]
+    fn files_from_slices() {
+        // The slices are borrowed rather than owned like the installer's reader
+        let mut slice = b"idska32\x1a\x18\0\0\0".to_vec();
+        slice.extend_from_slice(b"zlb\x1aabcdefgh");
+        let inno = inno_with_files(vec![file_at(0)], vec![stored_location(12, 8, 2, b"cdef")]);
+
+        let data = inno
+            .files_from_slices(|_| Ok(Cursor::new(slice.as_slice())))
+            .map(|result| result.map(|(_, data)| data))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(data, [b"cdef"]);
+    }
+}
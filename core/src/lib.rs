@@ -1,4 +1,5 @@
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "memmap2"), forbid(unsafe_code))]
+#![cfg_attr(feature = "memmap2", deny(unsafe_code))]
 
 /*!
 Inno is a read-only parser for [Inno Setup](https://jrsoftware.org/isinfo.php) installers (.exe).
@@ -48,6 +49,13 @@ fn main() -> InnoResult<()> {
 
 - **chrono**: Enables converting a file's created at time to a [`DateTime<UTC>`].
 - **jiff**: Enables converting a file's created at time to a [`Timestamp`].
+- **memmap2**: Enables parsing an installer from a memory-mapped file with `Inno::open_mmap`. This is
+  the only feature that uses unsafe code, and `open_mmap` is an `unsafe` function, as the file must
+  not be modified while it is mapped.
+- **tokio**: Enables parsing an installer from an asynchronous reader with `Inno::new_async`.
+- **tracing**: Emits [`tracing`] spans and events while parsing, such as the phase, entry index and
+  stream position of each entry, to help diagnose installers that fail to parse.
+- **uuid**: Enables parsing a GUID app ID into a [`Uuid`] with `Header::app_id_guid`.
 
 # What this crate provides
 
@@ -59,6 +67,7 @@ fn main() -> InnoResult<()> {
     icons, INI entries, registry entries, and run entries.
   - [`Inno::file_locations`]: low-level data/file location entries for consumers that want to
     implement extraction.
+- [`pe`]: the PE headers of the setup executable, such as its subsystem and sections.
 - Safe decoding of text according to the installer’s Unicode/ANSI mode and language codepage.
 - Version-aware parsing that accounts for structural changes between Inno Setup releases.
 
@@ -68,8 +77,10 @@ fn main() -> InnoResult<()> {
 
 # Supported versions
 
-Parsing is supported up to Inno Setup 6.7.x. Newer installers may work but can introduce format
-changes. In that case, you will get [`InnoError::UnsupportedVersion`].
+Parsing is supported from Inno Setup 1.3.3 up to Inno Setup 6.7.x. Newer installers may work but
+can introduce format changes. Installers outside of this range, including those with a nonsensical
+version such as 0.0.0, return [`InnoError::UnsupportedVersion`]. See [`MIN_SUPPORTED_VERSION`] and
+[`MAX_SUPPORTED_VERSION`].
 
 # Features
 
@@ -82,16 +93,26 @@ fn main() -> InnoResult<()> {
 All fallible operations return [`InnoError`]. Typical errors include:
 
 - Not an Inno installer file.
-- Unsupported (too-new) installer version.
+- Unsupported (too-new or too-old) installer version.
+- A packed executable, such as one compressed with UPX, whose setup loader can't be located.
 - I/O errors while reading.
-- Unexpected data at the end of a header stream (corruption or truncated file).
+- Unexpected data at the end of a header stream (corruption or truncated file). Zero padding at
+  the end of the secondary stream is tolerated and recorded in [`Inno::warnings`] unless
+  [`InnoBuilder::strict`] is enabled.
+- A secondary stream that ends before all of the declared file locations, unless
+  [`InnoBuilder::tolerate_truncation`] is enabled.
+- An invalid block header at the start of the secondary header stream, which reports the byte
+  offset of the stream.
 
 # Notes on text decoding
 
 In Unicode installers, text is always read as UTF-16LE. In ANSI installers,
 this crate picks a codepage based on the language table, preferring
 Windows-1252 when no explicit match is found, to maximize compatibility with
-older installers.
+older installers. If the heuristic picks the wrong codepage, it can be overridden with
+[`InnoBuilder::force_codepage`], or for a single kind of string with
+[`InnoBuilder::force_codepage_for`]. The license and info texts are stored in a codepage even in
+Unicode installers, and are read as Windows-1252 unless overridden.
 
 # Minimum Supported Rust Version (MSRV)
 
@@ -102,6 +123,8 @@ fn main() -> InnoResult<()> {
 - innoextract: <https://github.com/dscharrer/innoextract>
 - Inno Setup: <https://jrsoftware.org/isinfo.php>
 
+[`tracing`]: https://docs.rs/tracing
+[`Uuid`]: https://docs.rs/uuid/latest/uuid/struct.Uuid.html
 [cargo-features]: https://doc.rust-lang.org/stable/cargo/reference/manifest.html#the-features-section
 [`DateTime<Utc>`]: https://docs.rs/chrono/latest/chrono/struct.DateTime.html
 [`Timestamp`]: https://docs.rs/jiff/latest/jiff/struct.Timestamp.html
@@ -110,42 +133,77 @@ fn main() -> InnoResult<()> {
 #![doc(html_root_url = "https://docs.rs/inno")]
 #![allow(dead_code)]
 
+#[cfg(feature = "tokio")]
+mod r#async;
+mod builder;
+mod cache;
 mod compression;
+mod diff;
 mod encryption;
+mod entries;
 pub mod entry;
 pub mod error;
+pub mod extraction;
+mod file_stats;
 pub mod header;
+mod icon_issue;
+mod inno_ref;
+mod iss;
 #[cfg(feature = "extract")]
 mod iterator;
 mod loader;
 mod lzma_stream_header;
-mod pe;
+#[cfg(feature = "memmap2")]
+mod mmap;
+pub mod pe;
+mod progress;
 mod read;
+mod registry_tree;
+pub mod secret;
 pub mod string;
+mod summary;
+#[cfg(test)]
+mod test_support;
 pub mod version;
 mod wizard;
 
 use std::{
-    io,
-    io::{Read, Seek, SeekFrom},
+    collections::{BTreeMap, HashMap, HashSet},
+    io::{self, Read, Seek, SeekFrom, Write},
+    ops::Range,
 };
 
-use encoding_rs::{UTF_16LE, WINDOWS_1252};
+pub use builder::InnoBuilder;
+pub use cache::{CacheKey, InnoCache};
+pub use diff::{Change, InnoDiff};
+use encoding_rs::{Encoding, UTF_16LE, WINDOWS_1252};
 use encryption::EncryptionHeader;
+pub use entries::{Entries, Entry};
 use entry::{
     Component, DeleteEntry, Directory, File, FileLocation, ISSigKey, Icon, Ini, Language, Message,
     MessageEntry, Permission, RegistryEntry, RunEntry, Task, Type,
 };
-use error::{HeaderStream, InnoError, InnoResult};
+use error::{HeaderStream, InnoError, InnoResult, ParseWarning};
+use extraction::{ChunkMember, ChunkPlan, Encryption};
+pub use file_stats::FileStats;
+use header::Architecture;
 pub use header::Header;
+pub use icon_issue::IconIssue;
+pub use inno_ref::{HeaderStreamBuffer, InnoRef};
+use iss::Iss;
 #[cfg(feature = "extract")]
 use iterator::{ExtractEntry, FilesIterator, FilteredFilesIterator};
 use itertools::Itertools;
 use loader::SetupLoader;
 use lzma_stream_header::LzmaStreamHeader;
-use read::{ReadBytesExt, stream::InnoStreamReader};
+pub use progress::ParsePhase;
+pub use read::{BorrowBytesExt, ReadBytesExt};
+use read::{chunk::Chunk, stream::InnoStreamReader};
+pub use registry_tree::RegistryNode;
+use string::StringKind;
+pub use summary::InnoSummary;
 use version::{InnoVersion, windows_version::WindowsVersionRange};
-pub use wizard::Wizard;
+pub use wizard::{ImageFormat, Wizard, WizardImage, WizardImageKind};
 pub use zerocopy;
 
 /// The maximum supported Inno Version by this library.
@@ -154,7 +212,14 @@ fn main() -> InnoResult<()> {
 /// changes have not yet been implemented into this library.
 pub const MAX_SUPPORTED_VERSION: InnoVersion = InnoVersion::new(6, 7, u8::MAX, u8::MAX);
 
+/// The minimum supported Inno Version by this library.
+///
+/// Inno Setup 1.3.3 is the oldest version that the parser is tested against. Older formats, back
+/// to the 16-bit Inno Setup 1.2.10, are partially handled but have no test installers.
+pub const MIN_SUPPORTED_VERSION: InnoVersion = InnoVersion::new(1, 3, 3, 0);
+
 #[derive(Debug)]
+#[cfg_attr(test, derive(Default))]
 pub struct InnoInner {
     pub setup_loader: SetupLoader,
     version: InnoVersion,
@@ -178,15 +243,24 @@ pub struct InnoInner {
     uninstall_run_entries: Vec<RunEntry>,
     wizard: Wizard,
     file_locations: Vec<FileLocation>,
+    header_end: u64,
+    warnings: Vec<ParseWarning>,
 }
 
 impl InnoInner {
-    fn read_stream<R: Read + Seek>(
+    fn read_stream<R: Read + Seek, P>(
         mut reader: R,
         setup_loader: SetupLoader,
         inno_version: InnoVersion,
-    ) -> InnoResult<Self> {
+        options: InnoBuilder,
+        progress: &mut P,
+    ) -> InnoResult<Self>
+    where
+        P: FnMut(ParsePhase, u32, u32),
+    {
         let encryption_header = if inno_version >= 6.5 {
+            #[cfg(feature = "tracing")]
+            tracing::debug!("reading encryption header for Inno Setup 6.5.0+");
             Some(EncryptionHeader::read(&mut reader, inno_version)?)
         } else {
             None
@@ -194,92 +268,175 @@ fn read_stream<R: Read + Seek>(
 
         let mut reader = InnoStreamReader::new(&mut reader, inno_version)?;
 
+        progress(ParsePhase::Header, 0, 1);
         let mut header = Header::read(&mut reader, inno_version)?;
-
-        let languages = (0..header.language_count())
-            .map(|_| Language::read(&mut reader, inno_version))
-            .collect::<io::Result<Vec<_>>>()?;
-
-        let codepage = if inno_version.is_unicode() {
-            UTF_16LE
+        if let Some(encryption_header) = encryption_header {
+            header.set_encryption_header(encryption_header);
+        }
+        progress(ParsePhase::Header, 1, 1);
+
+        let languages = ParsePhase::Languages.read_entries(
+            &mut reader,
+            header.language_count(),
+            progress,
+            |reader| Language::read(reader, inno_version),
+        )?;
+
+        let forced_codepage = |kind| options.codepage_for(kind).or(options.codepage());
+        let codepage = Self::codepage(
+            inno_version,
+            &languages,
+            forced_codepage(StringKind::Entries),
+        );
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(codepage = codepage.name(), "decoding strings");
+
+        header.decode(Self::codepage(
+            inno_version,
+            &languages,
+            forced_codepage(StringKind::Metadata),
+        ));
+        header.decode_info_text(
+            options
+                .codepage_for(StringKind::InfoText)
+                .unwrap_or(WINDOWS_1252),
+        );
+
+        // A forced codepage also overrides the per-language codepage used for message values
+        let message_codepage = Self::codepage(
+            inno_version,
+            &languages,
+            forced_codepage(StringKind::Messages),
+        );
+        let message_languages = if forced_codepage(StringKind::Messages).is_some() {
+            &[]
         } else {
-            languages
-                .iter()
-                .map(Language::codepage)
-                .find_or_first(|&codepage| codepage == WINDOWS_1252)
-                .unwrap_or(WINDOWS_1252)
+            languages.as_slice()
         };
 
-        header.decode(codepage);
-
         let mut wizard = if inno_version < 4 {
-            Wizard::read(&mut reader, &header, inno_version)?
+            #[cfg(feature = "tracing")]
+            tracing::debug!("reading wizard before entries for Inno Setup < 4.0.0");
+            progress(ParsePhase::Wizard, 0, 1);
+            let wizard = Wizard::read(&mut reader, &header, inno_version)?;
+            progress(ParsePhase::Wizard, 1, 1);
+            wizard
         } else {
             Wizard::default()
         };
 
-        let messages = (0..header.custom_message_count())
-            .map(|_| MessageEntry::read(&mut reader, &languages, codepage))
-            .collect::<io::Result<Vec<_>>>()?;
-
-        let permissions = (0..header.permission_count())
-            .map(|_| Permission::read(&mut reader))
-            .collect::<io::Result<Vec<_>>>()?;
-
-        let type_entries = (0..header.type_count())
-            .map(|_| Type::read(&mut reader, codepage, inno_version))
-            .collect::<io::Result<Vec<_>>>()?;
-
-        let components = (0..header.component_count())
-            .map(|_| Component::read(&mut reader, codepage, inno_version))
-            .collect::<io::Result<Vec<_>>>()?;
-
-        let tasks = (0..header.task_count())
-            .map(|_| Task::read(&mut reader, codepage, inno_version))
-            .collect::<io::Result<Vec<_>>>()?;
-
-        let directories = (0..header.directory_count())
-            .map(|_| Directory::read(&mut reader, codepage, inno_version))
-            .collect::<io::Result<Vec<_>>>()?;
-
-        let is_sig_keys = (0..header.is_sig_keys_count())
-            .map(|_| ISSigKey::read(&mut reader, codepage))
-            .collect::<io::Result<Vec<_>>>()?;
-
-        let files = (0..header.file_count())
-            .map(|_| File::read(&mut reader, codepage, inno_version))
-            .collect::<io::Result<Vec<_>>>()?;
-
-        let icons = (0..header.icon_count())
-            .map(|_| Icon::read(&mut reader, codepage, inno_version))
-            .collect::<io::Result<Vec<_>>>()?;
-
-        let ini_entries = (0..header.ini_entry_count())
-            .map(|_| Ini::read(&mut reader, codepage, inno_version))
-            .collect::<io::Result<Vec<_>>>()?;
-
-        let registry_entries = (0..header.registry_entry_count())
-            .map(|_| RegistryEntry::read(&mut reader, codepage, inno_version))
-            .collect::<io::Result<Vec<_>>>()?;
-
-        let delete_entries = (0..header.install_delete_entry_count())
-            .map(|_| DeleteEntry::read(&mut reader, codepage, inno_version))
-            .collect::<io::Result<Vec<_>>>()?;
-
-        let uninstall_delete_entries = (0..header.uninstall_delete_entry_count())
-            .map(|_| DeleteEntry::read(&mut reader, codepage, inno_version))
-            .collect::<io::Result<Vec<_>>>()?;
-
-        let run_entries = (0..header.run_entry_count())
-            .map(|_| RunEntry::read(&mut reader, codepage, inno_version))
-            .collect::<io::Result<Vec<_>>>()?;
-
-        let uninstall_run_entries = (0..header.uninstall_run_entry_count())
-            .map(|_| RunEntry::read(&mut reader, codepage, inno_version))
-            .collect::<io::Result<Vec<_>>>()?;
+        let messages = ParsePhase::Messages.read_entries(
+            &mut reader,
+            header.custom_message_count(),
+            progress,
+            |reader| MessageEntry::read(reader, message_languages, message_codepage),
+        )?;
+
+        let permissions = ParsePhase::Permissions.read_entries(
+            &mut reader,
+            header.permission_count(),
+            progress,
+            Permission::read,
+        )?;
+
+        let type_entries = ParsePhase::Types.read_entries(
+            &mut reader,
+            header.type_count(),
+            progress,
+            |reader| Type::read(reader, codepage, inno_version),
+        )?;
+
+        let components = ParsePhase::Components.read_entries(
+            &mut reader,
+            header.component_count(),
+            progress,
+            |reader| Component::read(reader, codepage, inno_version),
+        )?;
+
+        let tasks = ParsePhase::Tasks.read_entries(
+            &mut reader,
+            header.task_count(),
+            progress,
+            |reader| Task::read(reader, codepage, inno_version),
+        )?;
+
+        let directories = ParsePhase::Directories.read_entries(
+            &mut reader,
+            header.directory_count(),
+            progress,
+            |reader| Directory::read(reader, codepage, inno_version),
+        )?;
+
+        let is_sig_keys = ParsePhase::ISSigKeys.read_entries(
+            &mut reader,
+            header.is_sig_keys_count(),
+            progress,
+            |reader| ISSigKey::read(reader, codepage),
+        )?;
+
+        let files = ParsePhase::Files.read_entries(
+            &mut reader,
+            header.file_count(),
+            progress,
+            |reader| File::read(reader, codepage, inno_version),
+        )?;
+
+        let icons = ParsePhase::Icons.read_entries(
+            &mut reader,
+            header.icon_count(),
+            progress,
+            |reader| Icon::read(reader, codepage, inno_version),
+        )?;
+
+        let ini_entries = ParsePhase::Ini.read_entries(
+            &mut reader,
+            header.ini_entry_count(),
+            progress,
+            |reader| Ini::read(reader, codepage, inno_version),
+        )?;
+
+        let registry_entries = ParsePhase::Registry.read_entries(
+            &mut reader,
+            header.registry_entry_count(),
+            progress,
+            |reader| RegistryEntry::read(reader, codepage, inno_version),
+        )?;
+
+        let delete_entries = ParsePhase::InstallDelete.read_entries(
+            &mut reader,
+            header.install_delete_entry_count(),
+            progress,
+            |reader| DeleteEntry::read(reader, codepage, inno_version),
+        )?;
+
+        let uninstall_delete_entries = ParsePhase::UninstallDelete.read_entries(
+            &mut reader,
+            header.uninstall_delete_entry_count(),
+            progress,
+            |reader| DeleteEntry::read(reader, codepage, inno_version),
+        )?;
+
+        let run_entries = ParsePhase::Run.read_entries(
+            &mut reader,
+            header.run_entry_count(),
+            progress,
+            |reader| RunEntry::read(reader, codepage, inno_version),
+        )?;
+
+        let uninstall_run_entries = ParsePhase::UninstallRun.read_entries(
+            &mut reader,
+            header.uninstall_run_entry_count(),
+            progress,
+            |reader| RunEntry::read(reader, codepage, inno_version),
+        )?;
 
         if inno_version >= 4 {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(position = reader.position(), "reading wizard after entries");
+            progress(ParsePhase::Wizard, 0, 1);
             wizard = Wizard::read(&mut reader, &header, inno_version)?;
+            progress(ParsePhase::Wizard, 1, 1);
         }
 
         // Check that the reader is at the end of the primary header stream
@@ -290,14 +447,51 @@ fn read_stream<R: Read + Seek>(
         // Reset the block reader for the secondary header stream
         reader = reader.reset()?;
 
-        let file_locations = (0..header.file_location_entry_count())
-            .map(|_| FileLocation::read(&mut reader, &header, inno_version))
-            .collect::<io::Result<Vec<_>>>()?;
+        let mut warnings = Vec::new();
+        let mut warn = |warning: ParseWarning| {
+            if options.is_strict() {
+                return Err(InnoError::from(warning));
+            }
+
+            #[cfg(feature = "tracing")]
+            tracing::warn!(%warning, "tolerating malformed installer");
 
+            warnings.push(warning);
+            Ok(())
+        };
+
+        let file_locations = ParsePhase::FileLocations.read_entries_until_end(
+            &mut reader,
+            header.file_location_entry_count(),
+            progress,
+            |reader| FileLocation::read(reader, &header, inno_version),
+        )?;
+
+        if usize::try_from(header.file_location_entry_count()).ok() != Some(file_locations.len()) {
+            let mismatch = ParseWarning::EntryCountMismatch {
+                phase: ParsePhase::FileLocations,
+                declared: header.file_location_entry_count(),
+                parsed: file_locations.len(),
+            };
+
+            // A truncated stream is only accepted if the caller has opted in
+            if !options.tolerates_truncation() {
+                return Err(mismatch.into());
+            }
+            warn(mismatch)?;
+        }
+
+        // Some installers pad the secondary stream with zeros, which is only rejected if strict
         if !reader.is_end_of_stream() {
-            return Err(InnoError::UnexpectedExtraData(HeaderStream::Secondary));
+            if !reader.skip_zero_padding().unwrap_or_default() {
+                return Err(InnoError::UnexpectedExtraData(HeaderStream::Secondary));
+            }
+            warn(ParseWarning::ZeroPadding(HeaderStream::Secondary))?;
         }
 
+        // The whole secondary stream has been read, so this is where the header streams end
+        let header_end = reader.skip()?.stream_position()?;
+
         Ok(Self {
             setup_loader,
             version: inno_version,
@@ -321,8 +515,98 @@ fn read_stream<R: Read + Seek>(
             uninstall_run_entries,
             wizard,
             file_locations,
+            header_end,
+            warnings,
         })
     }
+
+    /// Reads the header and then the file locations, skipping the rest of the primary header
+    /// stream without decompressing it.
+    fn read_file_locations<R: Read + Seek>(
+        mut reader: R,
+        inno_version: InnoVersion,
+    ) -> InnoResult<Vec<FileLocation>> {
+        if inno_version >= 6.5 {
+            EncryptionHeader::read(&mut reader, inno_version)?;
+        }
+
+        let mut reader = InnoStreamReader::new(&mut reader, inno_version)?;
+
+        let header = Header::read(&mut reader, inno_version)?;
+
+        let mut reader = InnoStreamReader::new(reader.skip()?, inno_version)?;
+
+        let file_locations = ParsePhase::FileLocations.read_entries_until_end(
+            &mut reader,
+            header.file_location_entry_count(),
+            &mut |_, _, _| {},
+            |reader| FileLocation::read(reader, &header, inno_version),
+        )?;
+
+        if usize::try_from(header.file_location_entry_count()).ok() != Some(file_locations.len()) {
+            return Err(InnoError::EntryCountMismatch {
+                phase: ParsePhase::FileLocations,
+                declared: header.file_location_entry_count(),
+                parsed: file_locations.len(),
+            });
+        }
+
+        if !reader.is_end_of_stream() && !reader.skip_zero_padding().unwrap_or_default() {
+            return Err(InnoError::UnexpectedExtraData(HeaderStream::Secondary));
+        }
+
+        Ok(file_locations)
+    }
+
+    fn read_summary<R: Read + Seek>(
+        mut reader: R,
+        inno_version: InnoVersion,
+        options: InnoBuilder,
+    ) -> InnoResult<InnoSummary> {
+        if inno_version >= 6.5 {
+            EncryptionHeader::read(&mut reader, inno_version)?;
+        }
+
+        let mut reader = InnoStreamReader::new(&mut reader, inno_version)?;
+
+        let mut header = Header::read(&mut reader, inno_version)?;
+
+        let languages = ParsePhase::Languages.read_entries(
+            &mut reader,
+            header.language_count(),
+            &mut |_, _, _| {},
+            |reader| Language::read(reader, inno_version),
+        )?;
+
+        header.decode(Self::codepage(
+            inno_version,
+            &languages,
+            options
+                .codepage_for(StringKind::Metadata)
+                .or(options.codepage()),
+        ));
+
+        Ok(InnoSummary::new(inno_version, &header, &languages))
+    }
+
+    /// Returns the codepage used to decode strings that aren't tied to a specific language.
+    fn codepage(
+        version: InnoVersion,
+        languages: &[Language],
+        forced: Option<&'static Encoding>,
+    ) -> &'static Encoding {
+        if version.is_unicode() {
+            UTF_16LE
+        } else if let Some(codepage) = forced {
+            codepage
+        } else {
+            languages
+                .iter()
+                .map(Language::codepage)
+                .find_or_first(|&codepage| codepage == WINDOWS_1252)
+                .unwrap_or(WINDOWS_1252)
+        }
+    }
 }
 
 pub struct Inno<R: Read + Seek> {
@@ -331,32 +615,150 @@ pub struct Inno<R: Read + Seek> {
 }
 
 impl<R: Read + Seek> Inno<R> {
-    pub fn new(mut reader: R) -> InnoResult<Self> {
-        let setup_loader =
-            SetupLoader::read_from(&mut reader).map_err(|_| InnoError::NotInnoFile)?;
+    /// The minimum supported Inno Version. See [`MIN_SUPPORTED_VERSION`].
+    pub const MIN_SUPPORTED_VERSION: InnoVersion = MIN_SUPPORTED_VERSION;
+
+    /// The maximum supported Inno Version. See [`MAX_SUPPORTED_VERSION`].
+    pub const MAX_SUPPORTED_VERSION: InnoVersion = MAX_SUPPORTED_VERSION;
+
+    /// Parses an installer from the given reader using the default parse options.
+    ///
+    /// Use [`InnoBuilder`] to configure how the installer is parsed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InnoError::UninstallerExecutable`] if the reader is an Inno Setup uninstaller,
+    /// such as `unins000.exe`, and [`InnoError::NotInnoFile`] if it is not an Inno Setup installer
+    /// at all.
+    pub fn new(reader: R) -> InnoResult<Self> {
+        Self::read_with(reader, InnoBuilder::new(), &mut |_, _, _| {})
+    }
+
+    /// Parses an installer from the given reader using the default parse options, reporting
+    /// progress to the given callback.
+    ///
+    /// The callback is invoked with the current [`ParsePhase`], the number of entries of that
+    /// phase that have been parsed, and the total number of entries in that phase. It is invoked
+    /// before each entry is parsed and once all entries of the phase have been parsed, so every
+    /// phase that is parsed reports a final `done == total`. If the installer's version is
+    /// ambiguous, phases may be reported again while parsing is retried.
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use inno::{Inno, error::InnoResult};
+    ///
+    /// fn main() -> InnoResult<()> {
+    ///     let file = File::open("path/to/setup.exe")?;
+    ///     let inno = Inno::open_with_progress(file, |phase, done, total| {
+    ///         println!("{phase}: {done}/{total}");
+    ///     })?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn open_with_progress<P>(reader: R, mut progress: P) -> InnoResult<Self>
+    where
+        P: FnMut(ParsePhase, u32, u32),
+    {
+        Self::read_with(reader, InnoBuilder::new(), &mut progress)
+    }
+
+    /// Parses only the file locations of an installer, for consumers that only need to know where
+    /// the data of each file is, such as for extraction.
+    ///
+    /// The header is parsed for the number of file locations, and the rest of the primary header
+    /// stream is skipped without being decompressed or verified. This is much faster than
+    /// [`Inno::new`] for installers with many entries.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Inno::new`], except for errors in the entries that are skipped.
+    pub fn parse_file_locations_only(mut reader: R) -> InnoResult<Vec<FileLocation>> {
+        Self::read_versioned(&mut reader, |reader, _, inno_version| {
+            InnoInner::read_file_locations(reader, inno_version)
+        })
+    }
+
+    /// Parses only the header and languages of an installer into an [`InnoSummary`], for
+    /// consumers that only need its top-level metadata.
+    ///
+    /// The rest of the header stream is not read, so this is faster than [`Inno::new`] and does
+    /// not hold any entries in memory. The summary is the same as [`Inno::summary`]. Use
+    /// [`InnoBuilder::build_summary`] to decode the summary with a forced codepage.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Inno::new`], except for errors in the entries that are not
+    /// read.
+    pub fn parse_summary_only(reader: R) -> InnoResult<InnoSummary> {
+        Self::read_summary_with(reader, InnoBuilder::new())
+    }
+
+    fn read_summary_with(mut reader: R, options: InnoBuilder) -> InnoResult<InnoSummary> {
+        Self::read_versioned(&mut reader, |reader, _, inno_version| {
+            InnoInner::read_summary(reader, inno_version, options)
+        })
+    }
+
+    fn read_with<P>(mut reader: R, options: InnoBuilder, progress: &mut P) -> InnoResult<Self>
+    where
+        P: FnMut(ParsePhase, u32, u32),
+    {
+        let inner = Self::read_versioned(&mut reader, |reader, setup_loader, inno_version| {
+            InnoInner::read_stream(reader, setup_loader, inno_version, options, progress)
+        })?;
+
+        Ok(Self { reader, inner })
+    }
+
+    /// Reads the setup loader and version, then reads the header streams with the given function.
+    ///
+    /// Inno Setup sometimes didn't increment the version number between versions with breaking
+    /// changes. If the version is ambiguous, the header streams are read using successive candidate
+    /// versions until one succeeds.
+    fn read_versioned<T, F>(reader: &mut R, mut read: F) -> InnoResult<T>
+    where
+        F: FnMut(&mut R, SetupLoader, InnoVersion) -> InnoResult<T>,
+    {
+        let setup_loader = SetupLoader::read_from(&mut *reader).map_err(|err| {
+            if matches!(err, InnoError::PackedExecutable { .. }) {
+                err
+            } else if SetupLoader::is_uninstaller(&mut *reader).unwrap_or_default() {
+                InnoError::UninstallerExecutable
+            } else {
+                InnoError::NotInnoFile
+            }
+        })?;
+
+        setup_loader.validate_against_len(reader.seek(SeekFrom::End(0))?)?;
 
         // Seek to Inno header
         reader.seek(SeekFrom::Start(setup_loader.header_offset().unsigned_abs()))?;
 
-        let mut inno_version = InnoVersion::read(&mut reader)?;
+        let mut inno_version = InnoVersion::read(&mut *reader)?;
 
-        if inno_version > MAX_SUPPORTED_VERSION {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(version = %inno_version, "read Inno Setup version");
+
+        if !inno_version.supported() {
             return Err(InnoError::UnsupportedVersion(inno_version));
         }
 
-        // Inno Setup sometimes didn't increment the version number between versions with breaking
-        // changes. If the version is ambiguous, try reading using successive candidate versions
-        // until one succeeds.
-        let inner = if let Some(mut versions_to_try) =
-            inno_version.ambiguous_candidates().map(Vec::into_iter)
-        {
+        if let Some(mut versions_to_try) = inno_version.ambiguous_candidates().map(Vec::into_iter) {
             let position = reader.stream_position()?;
 
             loop {
-                match InnoInner::read_stream(&mut reader, setup_loader, inno_version) {
-                    Ok(inno) => break Ok(inno),
+                match read(reader, setup_loader, inno_version) {
+                    Ok(value) => break Ok(value),
                     Err(err) => {
                         if let Some(next) = versions_to_try.next() {
+                            #[cfg(feature = "tracing")]
+                            tracing::debug!(
+                                failed = %inno_version,
+                                %next,
+                                error = %err,
+                                "retrying ambiguous version"
+                            );
+
                             inno_version = next;
                             reader.seek(SeekFrom::Start(position))?;
                         } else {
@@ -366,10 +768,8 @@ pub fn new(mut reader: R) -> InnoResult<Self> {
                 }
             }
         } else {
-            InnoInner::read_stream(&mut reader, setup_loader, inno_version)
-        }?;
-
-        Ok(Self { reader, inner })
+            read(reader, setup_loader, inno_version)
+        }
     }
 
     #[inline]
@@ -388,6 +788,36 @@ pub const fn version(&self) -> InnoVersion {
         self.inner.version
     }
 
+    /// Returns the Inno Setup version in the format that innoextract prints it, such as
+    /// `Inno Setup 6.4.0 (unicode)`.
+    ///
+    /// See [`InnoVersion::setup_version_string`].
+    #[must_use]
+    #[inline]
+    pub fn setup_version_string(&self) -> String {
+        self.inner.version.setup_version_string()
+    }
+
+    /// Returns the oldest innoextract release that is known to extract this installer, for tools
+    /// that fall back to innoextract.
+    ///
+    /// See [`InnoVersion::recommended_innoextract`].
+    #[must_use]
+    #[inline]
+    pub fn recommended_innoextract(&self) -> Option<&'static str> {
+        self.inner.version.recommended_innoextract()
+    }
+
+    /// Returns `true` if the installer was created by the newest Inno Setup release line that this
+    /// library supports, i.e. the same major and minor version as [`MAX_SUPPORTED_VERSION`].
+    #[must_use]
+    #[inline]
+    pub const fn is_latest_supported(&self) -> bool {
+        let version = self.version();
+        version.major() == MAX_SUPPORTED_VERSION.major()
+            && version.minor() == MAX_SUPPORTED_VERSION.minor()
+    }
+
     /// Returns the encryption header, if any.
     #[must_use]
     pub fn encryption_header(&self) -> Option<&EncryptionHeader> {
@@ -397,6 +827,34 @@ pub fn encryption_header(&self) -> Option<&EncryptionHeader> {
             .or_else(|| self.inner.header.encryption_header())
     }
 
+    /// Returns `true` if the installer is allowed to install on a system running the given Windows
+    /// architecture.
+    ///
+    /// See [`Header::runs_on`].
+    #[must_use]
+    #[inline]
+    pub fn runs_on(&self, system: Architecture) -> bool {
+        self.header().runs_on(system)
+    }
+
+    /// Returns `true` if Setup enables [64-bit install mode] on a system running the given Windows
+    /// architecture.
+    ///
+    /// In 64-bit install mode, `{sys}` is the 64-bit System32 directory and `{commonpf}` is the
+    /// 64-bit Program Files directory. Otherwise, they are SysWOW64 and Program Files (x86) on
+    /// 64-bit systems. Files with the [`BITS_32`](entry::file::FileFlags::BITS_32) or
+    /// [`BITS_64`](entry::file::FileFlags::BITS_64) flag override the install mode for that file
+    /// alone, changing where `{sys}` maps to in its `Source` and `DestDir` and how it is
+    /// registered. Always returns `false` before Inno Setup 5.1, which had no 64-bit install mode.
+    ///
+    /// See [`Header::installs_in_64_bit_mode_on`].
+    ///
+    /// [64-bit install mode]: https://jrsoftware.org/ishelp/topic_32vs64bitinstalls.htm
+    #[must_use]
+    pub fn installs_64bit_on(&self, system: Architecture) -> bool {
+        self.header().installs_in_64_bit_mode_on(system)
+    }
+
     /// Returns the primary language of the installer, if available.
     #[must_use]
     #[inline]
@@ -411,6 +869,39 @@ pub const fn languages(&self) -> &[Language] {
         self.inner.languages.as_slice()
     }
 
+    /// Returns the internal name of each language paired with its codepage, such as
+    /// `("english", WINDOWS_1252)`.
+    ///
+    /// The codepages of an ANSI installer may differ between languages, in which case strings that
+    /// aren't tied to a language are decoded with Windows-1252 if any language uses it, and
+    /// otherwise with the codepage of the first language. Every language of a Unicode installer
+    /// uses UTF-16LE.
+    #[must_use]
+    pub fn language_codepages(&self) -> Vec<(&str, &'static Encoding)> {
+        self.languages()
+            .iter()
+            .map(|language| (language.name(), language.codepage()))
+            .collect()
+    }
+
+    /// Returns the language with the given internal name, such as `english` or `german`, if any.
+    ///
+    /// The name is matched case-insensitively, as messages and conditions reference languages by
+    /// their internal name.
+    #[must_use]
+    pub fn language(&self, internal_name: &str) -> Option<&Language> {
+        self.language_position(internal_name)
+            .map(|index| &self.languages()[index])
+    }
+
+    /// Returns the index of the language with the given internal name, matched as in
+    /// [`language`](Self::language).
+    fn language_position(&self, internal_name: &str) -> Option<usize> {
+        self.languages()
+            .iter()
+            .position(|language| language.name().eq_ignore_ascii_case(internal_name))
+    }
+
     /// Returns the message entries as a slice.
     #[must_use]
     #[inline]
@@ -425,6 +916,58 @@ pub fn messages(&self) -> impl Iterator<Item = Message<'_, '_>> {
             .map(|message| Message::new(message, self.languages()))
     }
 
+    /// Returns a map of each custom message name to the names of the languages that have no value
+    /// for it.
+    ///
+    /// Messages that have a value for every language map to an empty list, and messages that apply
+    /// to all languages are never missing. This is useful to find untranslated messages in
+    /// multilingual installers.
+    #[must_use]
+    pub fn message_coverage(&self) -> HashMap<&str, Vec<&str>> {
+        MessageEntry::missing_languages(self.message_entries(), self.languages().len())
+            .into_iter()
+            .map(|(name, missing)| {
+                let missing = missing
+                    .into_iter()
+                    .map(|index| self.languages()[index].name())
+                    .collect();
+                (name, missing)
+            })
+            .collect()
+    }
+
+    /// Returns an iterator over the custom messages for the language with the given internal name,
+    /// as `(name, value)` pairs.
+    ///
+    /// Each message name is yielded once. Messages that apply to all languages are used when a
+    /// message has no value specific to the language. If `fallback` is true, messages that have
+    /// neither use the value of the [primary language](Self::primary_language) instead of being
+    /// skipped, which also applies when no language has the given name.
+    pub fn messages_by_language(
+        &self,
+        language: &str,
+        fallback: bool,
+    ) -> impl Iterator<Item = (&str, &str)> {
+        let language_index = self
+            .language_position(language)
+            .and_then(|index| i32::try_from(index).ok());
+
+        MessageEntry::for_language(self.message_entries(), language_index, fallback)
+    }
+
+    /// Returns the value of the custom message with the given name in the
+    /// [primary language](Self::primary_language), such as the `{cm:LaunchProgram}` message.
+    ///
+    /// The name is matched case-insensitively, as in the `{cm:...}` constant. If the primary
+    /// language has no value of its own, the value that applies to all languages is returned.
+    /// Returns `None` if there is neither, even if another language has a value.
+    #[must_use]
+    pub fn primary_message(&self, name: &str) -> Option<&str> {
+        MessageEntry::for_language(self.message_entries(), Some(0), false)
+            .find(|(message, _)| message.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    }
+
     /// Returns the permission entries as a slice.
     #[must_use]
     #[inline]
@@ -432,6 +975,17 @@ pub const fn permissions(&self) -> &[Permission] {
         self.inner.permissions.as_slice()
     }
 
+    /// Returns the permission entry at the given index, as stored by files, directories and
+    /// registry entries.
+    ///
+    /// Returns `None` for the `-1` index, which means that the entry has no permissions, and for
+    /// indices that are otherwise out of range.
+    #[must_use]
+    #[inline]
+    pub fn permission_at(&self, index: i16) -> Option<&Permission> {
+        self.permissions().get(usize::try_from(index).ok()?)
+    }
+
     /// Returns the type entries as a slice.
     #[must_use]
     #[inline]
@@ -446,6 +1000,25 @@ pub const fn components(&self) -> &[Component] {
         self.inner.components.as_slice()
     }
 
+    /// Returns the components that are checked by default, when the first setup type is selected.
+    ///
+    /// This is the selection that the wizard starts with for a new installation. A parent
+    /// component is also checked if any of its subcomponents are, as the wizard shows it as
+    /// partially checked and installs it. See [`Component::default_checked`].
+    #[must_use]
+    pub fn default_components(&self) -> Vec<&Component> {
+        let default_type = self.type_entries().first().and_then(Type::name);
+
+        self.components()
+            .iter()
+            .zip(Component::default_selection(
+                self.components(),
+                default_type,
+            ))
+            .filter_map(|(component, checked)| checked.then_some(component))
+            .collect()
+    }
+
     /// Returns the task entries as a slice.
     #[must_use]
     #[inline]
@@ -474,6 +1047,233 @@ pub const fn file_entries(&self) -> &[File] {
         self.inner.files.as_slice()
     }
 
+    /// Returns an iterator over the file entries that are not embedded in the installer.
+    ///
+    /// A file is embedded when its [`location`](File::location) is an index into
+    /// [`file_locations`](Self::file_locations), which describes where its data is stored.
+    /// Files declared with the `external` or `download` flags have no data in the installer, so
+    /// their location is a sentinel value instead. Such files are skipped by
+    /// [`files`](Self::files) and [`filtered_files`](Self::filtered_files).
+    ///
+    /// See [`File::is_external`].
+    pub fn external_files(&self) -> impl Iterator<Item = &File> {
+        self.file_entries().iter().filter(|file| file.is_external())
+    }
+
+    /// Returns an iterator over the file entries, each paired with the [`FileLocation`] that
+    /// describes where its data is stored.
+    ///
+    /// The location is `None` for [external](File::is_external) files, and for files whose
+    /// location index is otherwise out of range.
+    pub fn file_with_location(&self) -> impl Iterator<Item = (&File, Option<&FileLocation>)> {
+        self.file_entries()
+            .iter()
+            .map(|file| (file, self.location_of(file)))
+    }
+
+    /// Returns an iterator over the file entries that are downloaded at install time, which is
+    /// supported by Inno Setup 6.5 and later.
+    ///
+    /// These are the files with the [`DOWNLOAD`](entry::file::FileFlags::DOWNLOAD) flag, whose
+    /// [source](File::source) is the URL to download from. The credentials used for the download
+    /// are given by [`File::download_user_name`] and [`File::download_password`], and are often
+    /// empty. If the downloaded file is an archive to extract, it also has the
+    /// [`EXTRACT_ARCHIVE`](entry::file::FileFlags::EXTRACT_ARCHIVE) flag and may have an
+    /// [`extract_archive_password`](File::extract_archive_password).
+    ///
+    /// Downloaded files are [external](File::is_external), so they are also included in
+    /// [`external_files`](Self::external_files).
+    pub fn download_files(&self) -> impl Iterator<Item = &File> {
+        self.file_entries()
+            .iter()
+            .filter(|file| file.flags().contains(entry::file::FileFlags::DOWNLOAD))
+    }
+
+    /// Returns an iterator over the destination of each file, such as `{app}\MyProg.exe`.
+    ///
+    /// Files without a destination are skipped.
+    pub fn install_paths(&self) -> impl Iterator<Item = &str> {
+        self.file_entries().iter().filter_map(File::destination)
+    }
+
+    /// Returns an iterator over the destination of each file, after expanding the given
+    /// constants.
+    ///
+    /// See [`File::expanded_destination`] and [`install_paths`](Self::install_paths).
+    pub fn install_paths_expanded<'a>(
+        &'a self,
+        constants: &'a [(&str, &str)],
+    ) -> impl Iterator<Item = String> + 'a {
+        self.file_entries()
+            .iter()
+            .filter_map(|file| file.expanded_destination(constants))
+    }
+
+    /// Returns an iterator over the files that install fonts, each paired with the name of the font
+    /// that it registers, such as `Lucida Console`.
+    ///
+    /// This is the `FontInstall` parameter of the `[Files]` section. Fonts that are not TrueType
+    /// have the [`FONT_IS_NOT_TRUE_TYPE`](entry::file::FileFlags::FONT_IS_NOT_TRUE_TYPE) flag.
+    pub fn installed_fonts(&self) -> impl Iterator<Item = (&File, &str)> {
+        self.file_entries().iter().filter_map(|file| {
+            file.install_font_name()
+                .filter(|name| !name.is_empty())
+                .map(|name| (file, name))
+        })
+    }
+
+    /// Returns an iterator over the .NET assemblies that are installed into the Global Assembly
+    /// Cache, each paired with its strong assembly name.
+    ///
+    /// These are the files with the [`GAC_INSTALL`](entry::file::FileFlags::GAC_INSTALL) flag. The
+    /// strong assembly name is the `StrongAssemblyName` parameter of the `[Files]` section, such
+    /// as `MyAssembly, Version=1.0.0.0, Culture=neutral, PublicKeyToken=abcdef0123456789`. The
+    /// compiler requires one for the flag, so files without one are malformed and are skipped.
+    pub fn gac_assemblies(&self) -> impl Iterator<Item = (&File, &str)> {
+        self.file_entries()
+            .iter()
+            .filter(|file| file.flags().contains(entry::file::FileFlags::GAC_INSTALL))
+            .filter_map(|file| {
+                let name = file.strong_assembly_name().filter(|name| !name.is_empty());
+
+                #[cfg(feature = "tracing")]
+                if name.is_none() {
+                    tracing::warn!(
+                        destination = file.destination(),
+                        "skipping GAC assembly without a strong assembly name"
+                    );
+                }
+
+                name.map(|name| (file, name))
+            })
+    }
+
+    /// Returns the first file that installs to the given path, as described by
+    /// [`File::destination_matches`].
+    ///
+    /// Files are searched in the order that they appear in the script. Several files can install
+    /// to the same path, such as when they have mutually exclusive conditions, in which case only
+    /// the first of them is returned.
+    #[must_use]
+    #[inline]
+    pub fn find_file(&self, destination: &str) -> Option<&File> {
+        self.find_file_with_constants(destination, &[])
+    }
+
+    /// Returns the first file that installs to the given path, after expanding the given
+    /// constants in each file's destination.
+    ///
+    /// See [`File::destination_matches`] and [`find_file`](Self::find_file).
+    #[must_use]
+    pub fn find_file_with_constants(
+        &self,
+        destination: &str,
+        constants: &[(&str, &str)],
+    ) -> Option<&File> {
+        self.file_entries()
+            .iter()
+            .find(|file| file.destination_matches(destination, constants))
+    }
+
+    /// Writes the pretty-printed debug representation of the parsed installer to the given writer,
+    /// followed by a newline.
+    ///
+    /// This is the same output as the CLI's `--debug` flag, and can be used to capture it to a
+    /// file or buffer, such as for snapshot tests.
+    ///
+    /// Sensitive strings, such as passwords, are redacted. See
+    /// [`write_debug_with`](Self::write_debug_with) to reveal them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the writer could not be written to.
+    #[inline]
+    pub fn write_debug<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.write_debug_with(writer, false)
+    }
+
+    /// Writes the pretty-printed debug representation of the parsed installer to the given writer,
+    /// followed by a newline, revealing sensitive strings such as passwords if `reveal` is `true`.
+    ///
+    /// This is the same output as the CLI's `--debug` flag, with `--reveal-secrets` if `reveal`
+    /// is `true`. See the [`secret`] module for the strings that are redacted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the writer could not be written to.
+    pub fn write_debug_with<W: Write>(&self, writer: &mut W, reveal: bool) -> io::Result<()> {
+        if reveal {
+            writeln!(writer, "{:#?}", secret::Revealed(&self.inner))
+        } else {
+            writeln!(writer, "{:#?}", self.inner)
+        }
+    }
+
+    /// Returns the top-level metadata of the installer.
+    ///
+    /// See [`parse_summary_only`](Self::parse_summary_only) to read only the metadata.
+    #[must_use]
+    pub fn summary(&self) -> InnoSummary {
+        InnoSummary::new(self.version(), self.header(), self.languages())
+    }
+
+    /// Returns aggregate statistics about the file entries, such as their counts by
+    /// [`FileType`](entry::file::FileType) and by destination root, and their total sizes.
+    #[must_use]
+    pub fn file_stats(&self) -> FileStats {
+        FileStats::new(self.file_with_location())
+    }
+
+    /// Returns the [`FileLocation`] of the given file, or `None` if the file is
+    /// [external](File::is_external) or its location index is out of range.
+    #[must_use]
+    #[inline]
+    pub fn location_of(&self, file: &File) -> Option<&FileLocation> {
+        self.file_locations()
+            .get(usize::try_from(file.location()).ok()?)
+    }
+
+    /// Returns the disk space, in bytes, required to install the given selected components.
+    ///
+    /// This is the sum of the header's
+    /// [`extra_disk_space_required`](Header::extra_disk_space_required), the size of every file
+    /// whose components condition is satisfied by the selection, and the
+    /// [`extra_disk_space_required`](Component::extra_disk_space_required) of each selected
+    /// component. Component names are compared case-insensitively.
+    ///
+    /// This is an estimate. Setup rounds each file up to the cluster size of the destination
+    /// drive, which can't be known ahead of time, so the real requirement is usually slightly
+    /// higher. Files with tasks, languages or `Check` conditions are counted regardless of those
+    /// conditions.
+    #[must_use]
+    pub fn disk_space_for(&self, components: &[&str]) -> u64 {
+        let files = self
+            .file_with_location()
+            .filter(|(file, _)| file.condition().matches_components(components))
+            .map(|(file, location)| {
+                location.map_or(file.external_size(), FileLocation::uncompressed_size)
+            })
+            .sum::<u64>();
+
+        let selected_components = self
+            .components()
+            .iter()
+            .filter(|component| {
+                component.name().is_some_and(|name| {
+                    components
+                        .iter()
+                        .any(|selected| selected.eq_ignore_ascii_case(name))
+                })
+            })
+            .map(Component::extra_disk_space_required)
+            .sum::<u64>();
+
+        self.header()
+            .extra_disk_space_required()
+            .saturating_add(files)
+            .saturating_add(selected_components)
+    }
+
     /// Returns the icon entries as a slice.
     #[must_use]
     #[inline]
@@ -481,6 +1281,21 @@ pub const fn icon_entries(&self) -> &[Icon] {
         self.inner.icons.as_slice()
     }
 
+    /// Checks that the target of each icon is installed by the installer.
+    ///
+    /// An icon's target resolves if it is one of the installed files or directories, if it is a
+    /// parent directory of one, or if it is inside a system location such as `{sys}` or `{win}`.
+    /// Paths are compared case-insensitively after normalizing separators. Targets containing
+    /// constants whose values are only known at install time, such as `{code:...}` or
+    /// `{reg:...}`, are assumed to resolve. Icons with the [`CREATE_ONLY_IF_FILE_EXISTS`] flag are
+    /// skipped.
+    ///
+    /// [`CREATE_ONLY_IF_FILE_EXISTS`]: entry::IconFlags::CREATE_ONLY_IF_FILE_EXISTS
+    #[must_use]
+    pub fn validate_icons(&self) -> Vec<IconIssue<'_>> {
+        IconIssue::find(self.icon_entries(), self.file_entries(), self.directories())
+    }
+
     /// Returns the ini entries as a slice.
     #[must_use]
     #[inline]
@@ -495,6 +1310,16 @@ pub const fn registry_entries(&self) -> &[RegistryEntry] {
         self.inner.registry_entries.as_slice()
     }
 
+    /// Returns a hierarchical view of the registry entries, grouped by registry root and then by
+    /// each segment of their key.
+    ///
+    /// Keys that contain constants, such as `{app}` or `{reg:...}`, are not expanded and are kept
+    /// as literal segments.
+    #[must_use]
+    pub fn registry_tree(&self) -> RegistryNode<'_> {
+        RegistryNode::new(self.registry_entries())
+    }
+
     /// Returns the delete entries as a slice.
     #[must_use]
     #[inline]
@@ -523,6 +1348,16 @@ pub const fn uninstall_run_entries(&self) -> &[RunEntry] {
         self.inner.uninstall_run_entries.as_slice()
     }
 
+    /// Returns the indices of the uninstall run entries grouped by their
+    /// [`RunOnceId`](RunEntry::run_once_id), in order of each group's first entry.
+    ///
+    /// Only one entry of each group is run during uninstallation, even if the application was
+    /// installed several times. Entries without a `RunOnceId` each form a group of one.
+    #[must_use]
+    pub fn uninstall_run_entries_by_once_id(&self) -> Vec<Vec<usize>> {
+        RunEntry::group_by_once_id(self.uninstall_run_entries())
+    }
+
     /// Returns a reference to the [`Wizard`].
     #[must_use]
     #[inline]
@@ -530,6 +1365,35 @@ pub const fn wizard(&self) -> &Wizard {
         &self.inner.wizard
     }
 
+    /// Returns an iterator over the images embedded in the [`Wizard`], such as those set by the
+    /// `WizardImageFile` and `WizardSmallImageFile` directives.
+    ///
+    /// The images are stored in the primary header stream rather than among the file locations.
+    /// Before Inno Setup 4.0.0 they are stored directly after the header and languages, and in
+    /// newer versions they are stored after the last entry section. Images are typically BMPs,
+    /// which may be 32-bit with an alpha channel interpreted according to
+    /// [`WizardImage::alpha_format`]. See [`WizardImageKind`] for the versions that store each kind
+    /// of image.
+    pub fn wizard_images(&self) -> impl Iterator<Item = WizardImage<'_>> {
+        let header = self.header();
+
+        WizardImageKind::ALL.into_iter().flat_map(move |kind| {
+            let back_color = match kind {
+                WizardImageKind::Image => Some(header.image_background_color()),
+                WizardImageKind::SmallImage => Some(header.small_image_background_color()),
+                WizardImageKind::ImageDynamicDark => Some(header.image_dynamic_background_color()),
+                WizardImageKind::SmallImageDynamicDark => {
+                    Some(header.small_image_dynamic_background_color())
+                }
+                WizardImageKind::BackImage | WizardImageKind::BackImageDynamicDark => None,
+            };
+
+            self.wizard().images_of(kind).iter().map(move |data| {
+                WizardImage::new(kind, data, back_color, header.wizard_image_alpha_format())
+            })
+        })
+    }
+
     /// Returns the file locations entries as a slice.
     #[must_use]
     #[inline]
@@ -537,6 +1401,457 @@ pub const fn file_locations(&self) -> &[FileLocation] {
         self.inner.file_locations.as_slice()
     }
 
+    /// Returns the indices of the file locations grouped by the chunk that stores them, in the
+    /// order that the chunks are stored in the data stream.
+    ///
+    /// Files that share a chunk form a solid block, which only needs to be decompressed once to
+    /// extract every file in it. Each group is sorted by the file's offset within the block. Chunks
+    /// that store a single file form a group of one.
+    #[must_use]
+    pub fn solid_blocks(&self) -> Vec<Vec<usize>> {
+        let mut blocks = BTreeMap::<_, Vec<_>>::new();
+
+        for (index, location) in self.file_locations().iter().enumerate() {
+            let chunk = location.chunk();
+            blocks
+                .entry((chunk.first_slice(), chunk.start_offset()))
+                .or_default()
+                .push(index);
+        }
+
+        blocks
+            .into_values()
+            .map(|mut block| {
+                block.sort_by_key(|&index| self.file_locations()[index].file().offset());
+                block
+            })
+            .collect()
+    }
+
+    /// Returns the files grouped by the chunk that stores their data, in the order that the chunks
+    /// are stored in the data stream.
+    ///
+    /// Unlike [`solid_blocks`](Self::solid_blocks), this lists file entries rather than file
+    /// locations, so a location that several files install from is listed once for each of them.
+    /// [External](File::is_external) files are not stored in any chunk and are skipped.
+    #[must_use]
+    pub fn extraction_plan(&self) -> Vec<ChunkPlan<'_>> {
+        let mut plans = BTreeMap::new();
+
+        for file in self.file_entries() {
+            let Some(location) = self.location_of(file) else {
+                continue;
+            };
+
+            let chunk = location.chunk();
+            plans
+                .entry((chunk.first_slice(), chunk.start_offset()))
+                .or_insert_with(|| ChunkPlan::new(*chunk))
+                .push(ChunkMember::new(
+                    file,
+                    location.file().offset(),
+                    location.file().size(),
+                ));
+        }
+
+        plans
+            .into_values()
+            .map(|mut plan| {
+                plan.sort();
+                plan
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the data of any file is stored in an encrypted chunk, so a password is
+    /// needed to extract it.
+    ///
+    /// Chunks can be encrypted in installers of any version from Inno Setup 4.2.2, independently
+    /// of the [encryption header](Self::encryption_header) that was added in 6.5.
+    #[must_use]
+    pub fn has_encrypted_chunks(&self) -> bool {
+        self.file_locations()
+            .iter()
+            .any(|location| location.chunk().is_encrypted())
+    }
+
+    /// Returns the set of encryption methods used by the chunks of file data, including
+    /// [`Plaintext`](Encryption::Plaintext) if any chunk is not encrypted.
+    #[must_use]
+    pub fn chunk_encryption_kinds(&self) -> HashSet<Encryption> {
+        self.file_locations()
+            .iter()
+            .map(|location| location.chunk().encryption())
+            .collect()
+    }
+
+    /// Returns the overall compression ratio of the installer's file data, which is the
+    /// [uncompressed size](FileStats::uncompressed_size) of the embedded files divided by the
+    /// [compressed size](FileStats::compressed_size) of their chunks.
+    ///
+    /// Chunks that hold multiple files, as in solid compression, are only counted once. External
+    /// files are not stored in the installer, so they are not counted. Returns `1.0` if the
+    /// installer has no file data.
+    #[must_use]
+    pub fn compression_ratio(&self) -> f64 {
+        let stats = FileStats::new(
+            self.file_with_location()
+                .filter(|(_, location)| location.is_some()),
+        );
+
+        if stats.compressed_size() == 0 {
+            1.0
+        } else {
+            stats.uncompressed_size() as f64 / stats.compressed_size() as f64
+        }
+    }
+
+    /// Returns the total number of disk slices that hold the installer's file data.
+    ///
+    /// When the installer is disk spanned, each slice is stored in a separate file alongside the
+    /// installer, such as `setup-1.bin`, `setup-2.bin`, and so on, with
+    /// [`slices_per_disk`](Header::slices_per_disk) slices per disk. Returns 0 if the installer has
+    /// no file locations.
+    #[must_use]
+    pub fn slice_count(&self) -> u32 {
+        self.file_locations()
+            .iter()
+            .map(|location| location.chunk().last_slice().saturating_add(1))
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns the absolute offset in the setup executable at which the data of each file's chunk
+    /// is stored, or 0 if the installer is [disk spanned](Self::is_disk_spanned).
+    ///
+    /// This is the `Offset1` value of the [setup loader](Self::setup_loader). See the
+    /// [`extraction`] module for how it combines with the offsets of each file location.
+    #[must_use]
+    #[inline]
+    pub const fn data_base_offset(&self) -> u64 {
+        self.inner.setup_loader.data_offset().unsigned_abs()
+    }
+
+    /// Returns the range of bytes in the setup executable that hold the header streams, starting
+    /// with the version string at the header offset of the [setup loader](Self::setup_loader).
+    ///
+    /// This is the region that [`verify_header`](Self::verify_header) reads, and can be used to
+    /// hash or verify the header independently.
+    #[must_use]
+    #[inline]
+    pub const fn header_stream_range(&self) -> Range<u64> {
+        self.inner.setup_loader.header_offset().unsigned_abs()..self.inner.header_end
+    }
+
+    /// Returns the range of bytes in the setup executable that hold the chunks of file data,
+    /// starting at the [data base offset](Self::data_base_offset).
+    ///
+    /// The range ends after the last chunk, so it doesn't include anything appended to the setup
+    /// executable such as an Authenticode signature. The range is empty if the installer has no
+    /// file data or is [disk spanned](Self::is_disk_spanned). The end saturates at [`u64::MAX`]
+    /// for malformed installers whose chunks would extend beyond it.
+    #[must_use]
+    pub fn data_stream_range(&self) -> Range<u64> {
+        let start = self.data_base_offset();
+
+        if self.is_disk_spanned() {
+            return start..start;
+        }
+
+        let end = self
+            .file_locations()
+            .iter()
+            .map(|location| {
+                let chunk = location.chunk();
+                chunk
+                    .start_offset()
+                    .saturating_add(Chunk::MAGIC_LEN)
+                    .saturating_add(chunk.original_size())
+            })
+            .max()
+            .map_or(start, |len| start.saturating_add(len));
+
+        start..end
+    }
+
+    /// Returns true if the installer's file data is stored in external disk slice files rather than
+    /// in the setup executable.
+    ///
+    /// Files of such installers are extracted with [`files_from_slices`](Self::files_from_slices).
+    #[must_use]
+    #[inline]
+    pub const fn is_disk_spanned(&self) -> bool {
+        self.inner.setup_loader.data_offset() == 0
+    }
+
+    /// Returns the file name of the given zero-based disk slice, as written by the Inno Setup
+    /// compiler.
+    ///
+    /// `base_name` is the file name of the setup executable without its extension. When there is
+    /// one slice per disk, slices are named `setup-1.bin`, `setup-2.bin`, and so on. Otherwise, the
+    /// slices of each disk are distinguished by a letter, such as `setup-1a.bin` and
+    /// `setup-1b.bin`.
+    ///
+    /// Returns `None` if the slice can't be named, such as because the header declares more slices
+    /// per disk than there are letters.
+    #[must_use]
+    pub fn slice_file_name(&self, base_name: &str, slice: u32) -> Option<String> {
+        match self.header().slices_per_disk() {
+            0 | 1 => Some(format!("{base_name}-{}.bin", slice.checked_add(1)?)),
+            slices_per_disk => {
+                let disk = (slice / slices_per_disk).checked_add(1)?;
+                let letter = u8::try_from(slice % slices_per_disk)
+                    .ok()
+                    .and_then(|index| b'a'.checked_add(index))
+                    .filter(u8::is_ascii_lowercase)?;
+                Some(format!("{base_name}-{disk}{}.bin", char::from(letter)))
+            }
+        }
+    }
+
+    /// Returns an iterator over every entry in the installer, across all sections.
+    ///
+    /// Entries are yielded section by section, in the order that the sections are stored in the
+    /// installer, ending with the file locations.
+    #[must_use]
+    #[inline]
+    pub const fn entries(&self) -> Entries<'_> {
+        Entries::new(&self.inner)
+    }
+
+    /// Returns an approximate Inno Setup script (.iss) reconstructed from the parsed installer.
+    ///
+    /// The script contains the `[Setup]` directives and a section for each kind of entry present
+    /// in the installer, with the `Components`, `Tasks`, `Languages`, `Check`, `BeforeInstall`
+    /// and `AfterInstall` parameters of each entry. It is not byte-for-byte identical to the
+    /// original script, as some directives are not stored in the compiled installer, but it is
+    /// intended to be valid enough to compile for common cases.
+    #[doc(alias = "as_iss_fragment")]
+    #[must_use]
+    pub fn to_iss(&self) -> String {
+        Iss::new(&self.inner).to_string()
+    }
+
+    /// Compares this installer against another, such as a newer release of the same application,
+    /// and returns the differences between them.
+    ///
+    /// This installer is treated as the old installer and `other` as the new one. The report
+    /// covers the Inno Setup version, the recoverable `[Setup]` directives, files and registry
+    /// entries. See [`InnoDiff`] for how entries are matched.
+    #[must_use]
+    pub fn diff<'inno, O>(&'inno self, other: &'inno Inno<O>) -> InnoDiff<'inno>
+    where
+        O: Read + Seek,
+    {
+        InnoDiff::new(&self.inner, &other.inner)
+    }
+
+    /// Returns the problems that were tolerated while parsing the installer, because
+    /// [strict mode](InnoBuilder::strict) was disabled.
+    ///
+    /// This is empty for installers parsed in strict mode, as each of these is an error instead.
+    #[must_use]
+    #[inline]
+    pub const fn warnings(&self) -> &[ParseWarning] {
+        self.inner.warnings.as_slice()
+    }
+
+    /// Verifies that every section of the installer contains as many entries as its
+    /// [declared count](Header::entry_counts).
+    ///
+    /// A section that is missing entries in the middle of a header stream can't be parsed at all,
+    /// but the header stream may end before all of its declared file locations. This is an error
+    /// unless [truncation is tolerated](InnoBuilder::tolerate_truncation), in which case it is
+    /// recorded as a [warning](Self::warnings) and reported here.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InnoError::EntryCountMismatch`] for the first section whose number of entries
+    /// differs from its declared count.
+    pub fn verify_counts(&self) -> InnoResult<()> {
+        let counts = self.inner.header.entry_counts();
+        let inner = &self.inner;
+
+        [
+            (
+                ParsePhase::Languages,
+                counts.language(),
+                inner.languages.len(),
+            ),
+            (
+                ParsePhase::Messages,
+                counts.custom_message(),
+                inner.messages.len(),
+            ),
+            (
+                ParsePhase::Permissions,
+                counts.permission(),
+                inner.permissions.len(),
+            ),
+            (ParsePhase::Types, counts.r#type(), inner.type_entries.len()),
+            (
+                ParsePhase::Components,
+                counts.component(),
+                inner.components.len(),
+            ),
+            (ParsePhase::Tasks, counts.task(), inner.tasks.len()),
+            (
+                ParsePhase::Directories,
+                counts.directory(),
+                inner.directories.len(),
+            ),
+            (
+                ParsePhase::ISSigKeys,
+                counts.is_sig_key(),
+                inner.is_sig_keys.len(),
+            ),
+            (ParsePhase::Files, counts.file(), inner.files.len()),
+            (ParsePhase::Icons, counts.icon(), inner.icons.len()),
+            (ParsePhase::Ini, counts.ini(), inner.ini_entries.len()),
+            (
+                ParsePhase::Registry,
+                counts.registry(),
+                inner.registry_entries.len(),
+            ),
+            (
+                ParsePhase::InstallDelete,
+                counts.install_delete(),
+                inner.delete_entries.len(),
+            ),
+            (
+                ParsePhase::UninstallDelete,
+                counts.uninstall_delete(),
+                inner.uninstall_delete_entries.len(),
+            ),
+            (ParsePhase::Run, counts.run(), inner.run_entries.len()),
+            (
+                ParsePhase::UninstallRun,
+                counts.uninstall_run(),
+                inner.uninstall_run_entries.len(),
+            ),
+            (
+                ParsePhase::FileLocations,
+                counts.file_location(),
+                inner.file_locations.len(),
+            ),
+        ]
+        .into_iter()
+        .find(|&(_, declared, parsed)| usize::try_from(declared).ok() != Some(parsed))
+        .map_or(Ok(()), |(phase, declared, parsed)| {
+            Err(InnoError::EntryCountMismatch {
+                phase,
+                declared,
+                parsed,
+            })
+        })
+    }
+
+    /// Verifies the integrity of the installer's header by re-reading it and checking its stored
+    /// checksums.
+    ///
+    /// Inno Setup does not store a single digest over the header. Instead, the header streams are
+    /// protected by a CRC32 checksum over each stream's header and over each 4 KiB block of the
+    /// compressed data, and, from Inno Setup 6.5.0, a CRC32 checksum over the encryption header.
+    /// These checksums are validated during parsing, so this is only useful to check that the
+    /// installer has not changed on disk since it was parsed.
+    ///
+    /// Returns `false` if any of the checksums do not match.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header could not be read for a reason other than a checksum
+    /// mismatch.
+    pub fn verify_header(&mut self) -> InnoResult<bool> {
+        let version = self.version();
+
+        let result = (|| {
+            self.reader.seek(SeekFrom::Start(
+                self.inner.setup_loader.header_offset().unsigned_abs(),
+            ))?;
+
+            InnoVersion::read(&mut self.reader)?;
+
+            if version >= 6.5 {
+                EncryptionHeader::read(&mut self.reader, version)?;
+            }
+
+            let reader = InnoStreamReader::verify(&mut self.reader, version)?;
+            InnoStreamReader::verify(reader, version)?;
+
+            Ok(())
+        })();
+
+        match result {
+            Ok(()) => Ok(true),
+            Err(InnoError::ChecksumMismatch { .. }) => Ok(false),
+            Err(InnoError::Io(err))
+                if err
+                    .get_ref()
+                    .and_then(|inner| inner.downcast_ref::<InnoError>())
+                    .is_some_and(|inner| matches!(inner, InnoError::ChecksumMismatch { .. })) =>
+            {
+                Ok(false)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Reads the PE headers of the installer executable, such as to inspect its subsystem, DLL
+    /// characteristics or sections.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the headers could not be read.
+    pub fn pe_headers(&mut self) -> InnoResult<pe::PeHeaders> {
+        pe::PeHeaders::read_from(&mut self.reader).map_err(InnoError::from)
+    }
+
+    /// Returns `true` if the installer executable has an Authenticode signature.
+    ///
+    /// This only checks that a signature is present. The signature is not verified.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the PE headers could not be read.
+    pub fn is_authenticode_signed(&mut self) -> InnoResult<bool> {
+        Ok(self.pe_headers()?.is_authenticode_signed())
+    }
+
+    /// Reads the Authenticode signature of the installer executable, as a DER-encoded PKCS#7
+    /// `SignedData` structure.
+    ///
+    /// Returns `None` if the executable is not signed, or if its first certificate is not a PKCS#7
+    /// signature. The signature is not verified.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the PE headers or the signature could not be read.
+    pub fn authenticode_signature(&mut self) -> InnoResult<Option<Vec<u8>>> {
+        let Some(certificate_table) = self.pe_headers()?.certificate_table() else {
+            return Ok(None);
+        };
+
+        pe::read_authenticode_signature(&mut self.reader, certificate_table)
+            .map_err(InnoError::from)
+    }
+
+    /// Reads the icon of the installer executable, as shown in Explorer, and returns it as the
+    /// bytes of an `.ico` file.
+    ///
+    /// The icon is read from the executable's `RT_GROUP_ICON` and `RT_ICON` resources, and every
+    /// image in the icon group is included. This is the icon set by the `SetupIconFile` directive,
+    /// or Inno Setup's default icon if it was not set.
+    ///
+    /// Returns `None` if the executable has no icon.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the executable's resources could not be read.
+    #[doc(alias = "SetupIconFile")]
+    pub fn setup_icon(&mut self) -> InnoResult<Option<Vec<u8>>> {
+        pe::resource::read_icon_file(&mut self.reader).map_err(InnoError::from)
+    }
+
     /// Returns an iterator of files.
     ///
     /// If you do not need every file, use [`filtered_files`].
@@ -561,4 +1876,502 @@ pub fn filtered_files<P>(&mut self, predicate: P) -> FilteredFilesIterator<'_, R
     {
         FilteredFilesIterator::new(self, predicate)
     }
+
+    /// Returns an iterator of files whose data is stored in external disk slice files.
+    ///
+    /// Disk-spanned installers, which are compiled with `DiskSpanning=yes`, store their compressed
+    /// data in separate `.bin` files rather than in the setup executable. The given closure is
+    /// called with a zero-based slice number and must return a reader for that slice's file, which
+    /// need not be the same type as the installer's reader. See
+    /// [`slice_file_name`](Self::slice_file_name) for how slices map to file names.
+    ///
+    /// Chunks that straddle a slice boundary are read by continuing into the next slice.
+    #[cfg(feature = "extract")]
+    pub fn files_from_slices<'inno, F, S>(&'inno self, slice_reader: F) -> FilesIterator<'inno, R>
+    where
+        F: FnMut(u32) -> io::Result<S> + 'inno,
+        S: Read + Seek + 'inno,
+    {
+        FilesIterator::with_slices(self, slice_reader)
+    }
+
+    /// Returns an iterator of files whose data is stored in external disk slice files that match
+    /// the given predicate.
+    ///
+    /// See [`files_from_slices`](Self::files_from_slices) and
+    /// [`filtered_files`](Self::filtered_files).
+    #[cfg(feature = "extract")]
+    pub fn filtered_files_from_slices<'inno, F, S, P>(
+        &'inno self,
+        slice_reader: F,
+        predicate: P,
+    ) -> FilteredFilesIterator<'inno, R>
+    where
+        F: FnMut(u32) -> io::Result<S> + 'inno,
+        S: Read + Seek + 'inno,
+        P: FnMut(&ExtractEntry) -> bool,
+    {
+        FilteredFilesIterator::with_slices(self, slice_reader, predicate)
+    }
+
+    /// Extracts every file and verifies its checksum, returning the result for each file by its
+    /// index in [`file_entries`](Self::file_entries).
+    ///
+    /// Files with the [`DONT_VERIFY_CHECKSUM`] flag and [external](File::is_external) files are
+    /// skipped, so they have no result. Data that is shared by several files is only extracted and
+    /// verified once, and its result is reported for the first of those files. The results are in
+    /// order of file index.
+    ///
+    /// If a chunk can't be read, such as because it is [encrypted](Self::has_encrypted_chunks),
+    /// the error is reported for the first file in it and the rest of its files are skipped.
+    ///
+    /// This reads and decompresses all of the installer's data, so it is slow for large
+    /// installers. The data is read from the setup executable, so this does not support
+    /// [disk-spanned](Self::is_disk_spanned) installers.
+    ///
+    /// [`DONT_VERIFY_CHECKSUM`]: entry::file::FileFlags::DONT_VERIFY_CHECKSUM
+    #[cfg(feature = "extract")]
+    pub fn verify_all(&mut self) -> Vec<(usize, InnoResult<()>)> {
+        self.verify_all_with_progress(|_, _| {})
+    }
+
+    /// Extracts every file and verifies its checksum like [`verify_all`](Self::verify_all),
+    /// reporting progress through the given callback.
+    ///
+    /// The callback is called with the number of files verified so far and the total number of
+    /// files to verify, before each file is verified and once all of them have been verified.
+    /// Files that are skipped because their chunk can't be read are counted as verified.
+    #[cfg(feature = "extract")]
+    pub fn verify_all_with_progress<P>(&mut self, mut progress: P) -> Vec<(usize, InnoResult<()>)>
+    where
+        P: FnMut(usize, usize),
+    {
+        let mut file_indices = BTreeMap::new();
+        for (index, file) in self.file_entries().iter().enumerate() {
+            if !file
+                .flags()
+                .contains(entry::file::FileFlags::DONT_VERIFY_CHECKSUM)
+                && self.location_of(file).is_some()
+            {
+                file_indices.entry(file.location()).or_insert(index);
+            }
+        }
+
+        let mut results = Vec::with_capacity(file_indices.len());
+        let mut files =
+            self.filtered_files(|entry| file_indices.contains_key(&entry.location_index()));
+        let total = files.len();
+        while let Some(location) = files.peek_entry().map(ExtractEntry::location_index) {
+            progress(total - files.len(), total);
+            let Some(result) = files.next() else {
+                break;
+            };
+            results.push((file_indices[&location], result.map(|_| ())));
+        }
+        progress(total, total);
+
+        results.sort_by_key(|&(index, _)| index);
+        results
+    }
+}
+
+impl<'inno, R: Read + Seek> IntoIterator for &'inno Inno<R> {
+    type Item = Entry<'inno>;
+
+    type IntoIter = Entries<'inno>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use encoding_rs::{Encoding, SHIFT_JIS, UTF_16LE, WINDOWS_1252};
+    use rstest::rstest;
+
+    use super::{Inno, InnoBuilder, InnoError, InnoInner};
+    use crate::{
+        ParsePhase,
+        entry::{self, File, FileLocation, Language},
+        error::ParseWarning,
+        header::HeaderString,
+        loader::SetupLoader,
+        read::chunk::Chunk,
+        test_support::{file_at, inno_with_files, sized_location, stored_stream},
+        version::{InnoVersion, VersionVariant},
+    };
+    #[cfg(feature = "extract")]
+    use crate::{read::chunk::Encryption, test_support::location_in_chunk};
+
+    const VERSION_5_5: InnoVersion =
+        InnoVersion::new_with_variant(5, 5, 0, 0, VersionVariant::UNICODE);
+
+    /// Builds the header streams of an Inno Setup 5.5.0 installer that declares two file
+    /// locations, of which only `stored` are in the secondary stream.
+    fn header_streams(stored: usize) -> Vec<u8> {
+        // Every string is empty, and every entry count is zero except for the file locations
+        let mut header = vec![0; HeaderString::layout(VERSION_5_5).count() * size_of::<u32>()];
+        let mut counts = [0u32; 16];
+        counts[8] = 2;
+        header.extend(counts.iter().flat_map(|count| count.to_le_bytes()));
+        header.resize(header.len() + 1024, 0);
+
+        // Each file location is 74 bytes, and is valid when all zero
+        let secondary = vec![0; stored * 74];
+
+        let mut streams = stored_stream(&header);
+        streams.extend(stored_stream(&secondary));
+        streams
+    }
+
+    const TRUNCATED: ParseWarning = ParseWarning::EntryCountMismatch {
+        phase: ParsePhase::FileLocations,
+        declared: 2,
+        parsed: 1,
+    };
+
+    #[rstest]
+    #[case::complete(2, InnoBuilder::new(), Ok((2, vec![])))]
+    #[case::truncated(1, InnoBuilder::new(), Err(TRUNCATED))]
+    #[case::tolerated_truncation(
+        1,
+        InnoBuilder::new().tolerate_truncation(true),
+        Ok((1, vec![TRUNCATED]))
+    )]
+    #[case::strict_truncation(
+        1,
+        InnoBuilder::new().tolerate_truncation(true).strict(true),
+        Err(TRUNCATED)
+    )]
+    fn secondary_stream_end(
+        #[case] stored: usize,
+        #[case] options: InnoBuilder,
+        #[case] expected: Result<(usize, Vec<ParseWarning>), ParseWarning>,
+    ) {
+        let inner = InnoInner::read_stream(
+            Cursor::new(header_streams(stored)),
+            SetupLoader::default(),
+            VERSION_5_5,
+            options,
+            &mut |_, _, _| {},
+        );
+
+        assert_eq!(
+            inner
+                .map(|inner| (inner.file_locations.len(), inner.warnings))
+                .map_err(|err| err.to_string()),
+            expected.map_err(|warning| InnoError::from(warning).to_string())
+        );
+    }
+
+    #[rstest]
+    #[case::complete(2, true)]
+    #[case::truncated(1, false)]
+    fn read_file_locations(#[case] stored: usize, #[case] expected: bool) {
+        let file_locations =
+            InnoInner::read_file_locations(Cursor::new(header_streams(stored)), VERSION_5_5);
+
+        assert_eq!(file_locations.is_ok(), expected);
+    }
+
+    #[rstest]
+    #[case::matching(2, 2, None)]
+    #[case::missing_files(3, 2, Some((ParsePhase::Files, 3)))]
+    #[case::missing_file_locations(2, 3, Some((ParsePhase::FileLocations, 3)))]
+    #[case::extra_file_locations(2, 1, Some((ParsePhase::FileLocations, 1)))]
+    fn verify_counts(
+        #[case] file_count: u32,
+        #[case] file_location_count: u32,
+        #[case] expected: Option<(ParsePhase, u32)>,
+    ) {
+        let mut inner = InnoInner {
+            directories: vec![entry::Directory::default()],
+            files: vec![File::default(), File::default()],
+            file_locations: vec![FileLocation::default(), FileLocation::default()],
+            ..InnoInner::default()
+        };
+        inner.header.entry_counts.directory = 1;
+        inner.header.entry_counts.file = file_count;
+        inner.header.entry_counts.file_location = file_location_count;
+
+        let inno = Inno {
+            reader: Cursor::new(Vec::new()),
+            inner,
+        };
+
+        match (inno.verify_counts(), expected) {
+            (Ok(()), None) => {}
+            (
+                Err(InnoError::EntryCountMismatch {
+                    phase,
+                    declared,
+                    parsed,
+                }),
+                Some((expected_phase, expected_declared)),
+            ) => {
+                assert_eq!(phase, expected_phase);
+                assert_eq!(declared, expected_declared);
+                assert_eq!(parsed, 2);
+            }
+            (result, expected) => panic!("expected {expected:?}, got {result:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case::empty(vec![], vec![], 1.0)]
+    #[case::shared_chunk(
+        vec![file_at(0), file_at(1), file_at(2)],
+        vec![sized_location(0, 40, 60), sized_location(0, 40, 20), sized_location(100, 10, 20)],
+        2.0
+    )]
+    #[case::external_file(
+        vec![file_at(0), File { external_size: 1000, ..file_at(u32::MAX) }],
+        vec![sized_location(0, 40, 80)],
+        2.0
+    )]
+    fn compression_ratio(
+        #[case] files: Vec<File>,
+        #[case] file_locations: Vec<FileLocation>,
+        #[case] expected: f64,
+    ) {
+        let inno = inno_with_files(files, file_locations);
+
+        assert_eq!(inno.compression_ratio(), expected);
+    }
+
+    #[rstest]
+    #[case(InnoVersion::new(5, 5, 0, 0), None, WINDOWS_1252)]
+    #[case(InnoVersion::new(5, 5, 0, 0), Some(SHIFT_JIS), SHIFT_JIS)]
+    #[case(
+        InnoVersion::new_with_variant(5, 5, 0, 0, VersionVariant::UNICODE),
+        Some(SHIFT_JIS),
+        UTF_16LE
+    )]
+    fn codepage(
+        #[case] version: InnoVersion,
+        #[case] forced: Option<&'static Encoding>,
+        #[case] expected: &'static Encoding,
+    ) {
+        let languages = [Language::default()];
+        assert_eq!(InnoInner::codepage(version, &languages, forced), expected);
+    }
+
+    #[rstest]
+    #[case::uninstaller(*b"InUn", |err: &InnoError| matches!(err, InnoError::UninstallerExecutable))]
+    #[case::other(*b"\0\0\0\0", |err: &InnoError| matches!(err, InnoError::NotInnoFile))]
+    fn not_an_installer(#[case] mode: [u8; 4], #[case] expected: fn(&InnoError) -> bool) {
+        let mut executable = vec![0; 0x40];
+        executable[0x30..0x34].copy_from_slice(&mode);
+
+        let err = Inno::new(Cursor::new(executable)).err().unwrap();
+        assert!(expected(&err), "{err:?}");
+    }
+
+    #[rstest]
+    #[case::one_per_disk(1, 0, Some("setup-1.bin"))]
+    #[case::one_per_disk_later(1, 4, Some("setup-5.bin"))]
+    #[case::one_per_disk_last(1, u32::MAX, None)]
+    #[case::several_per_disk(3, 0, Some("setup-1a.bin"))]
+    #[case::several_per_disk_later(3, 5, Some("setup-2c.bin"))]
+    #[case::every_letter(26, 51, Some("setup-2z.bin"))]
+    #[case::beyond_letters(27, 26, None)]
+    #[case::beyond_byte(300, 299, None)]
+    fn slice_file_name(
+        #[case] slices_per_disk: u32,
+        #[case] slice: u32,
+        #[case] expected: Option<&str>,
+    ) {
+        let mut inno = inno_with_files(vec![], vec![]);
+        inno.inner.header.slices_per_disk = slices_per_disk;
+
+        assert_eq!(inno.slice_file_name("setup", slice).as_deref(), expected);
+    }
+
+    #[cfg(feature = "extract")]
+    #[test]
+    fn verify_all_encrypted_chunks() {
+        // The first two files share an encrypted chunk, and the third is in another one
+        let mut inno = inno_with_files(
+            vec![file_at(0), file_at(1), file_at(2)],
+            vec![
+                location_in_chunk(0, Encryption::XChaCha20),
+                location_in_chunk(0, Encryption::XChaCha20),
+                location_in_chunk(100, Encryption::Arc4Sha1),
+            ],
+        );
+
+        let mut progress = Vec::new();
+        let results = inno.verify_all_with_progress(|done, total| progress.push((done, total)));
+
+        // The rest of a chunk that can't be read is skipped, and counted as done
+        assert_eq!(progress, [(0, 3), (2, 3), (3, 3)]);
+        assert_eq!(
+            results.iter().map(|&(index, _)| index).collect::<Vec<_>>(),
+            [0, 2]
+        );
+        assert!(
+            results
+                .iter()
+                .all(|(_, result)| matches!(result, Err(InnoError::Encrypted)))
+        );
+    }
+
+    #[rstest]
+    #[case::font(Some("Lucida Console"), Some("Lucida Console"))]
+    #[case::empty(Some(""), None)]
+    #[case::none(None, None)]
+    fn installed_fonts(#[case] install_font_name: Option<&str>, #[case] expected: Option<&str>) {
+        let inno = inno_with_files(
+            vec![File {
+                install_font_name: install_font_name.map(str::to_owned),
+                ..File::default()
+            }],
+            vec![],
+        );
+
+        assert_eq!(
+            inno.installed_fonts().map(|(_, name)| name).next(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case::gac(true, Some("MyAssembly"), Some("MyAssembly"))]
+    #[case::not_gac(false, Some("MyAssembly"), None)]
+    #[case::empty_name(true, Some(""), None)]
+    #[case::no_name(true, None, None)]
+    fn gac_assemblies(
+        #[case] gac_install: bool,
+        #[case] strong_assembly_name: Option<&str>,
+        #[case] expected: Option<&str>,
+    ) {
+        let inno = inno_with_files(
+            vec![File {
+                strong_assembly_name: strong_assembly_name.map(str::to_owned),
+                flags: if gac_install {
+                    entry::file::FileFlags::GAC_INSTALL
+                } else {
+                    entry::file::FileFlags::empty()
+                },
+                ..File::default()
+            }],
+            vec![],
+        );
+
+        assert_eq!(inno.gac_assemblies().map(|(_, name)| name).next(), expected);
+    }
+
+    #[rstest]
+    #[case::download(entry::file::FileFlags::DOWNLOAD, 1)]
+    #[case::download_archive(
+        entry::file::FileFlags::DOWNLOAD | entry::file::FileFlags::EXTRACT_ARCHIVE,
+        1
+    )]
+    #[case::extract_archive(entry::file::FileFlags::EXTRACT_ARCHIVE, 0)]
+    #[case::embedded(entry::file::FileFlags::empty(), 0)]
+    fn download_files(#[case] flags: entry::file::FileFlags, #[case] expected: usize) {
+        let inno = inno_with_files(
+            vec![File {
+                source: Some("https://example.com/setup.zip".to_owned()),
+                flags,
+                ..File::default()
+            }],
+            vec![],
+        );
+
+        assert_eq!(inno.download_files().count(), expected);
+    }
+
+    #[test]
+    fn extraction_plan() {
+        let location = |first_slice, start_offset, offset| FileLocation {
+            chunk: Chunk {
+                first_slice,
+                start_offset,
+                ..Chunk::default()
+            },
+            file: entry::location::File {
+                offset,
+                ..entry::location::File::default()
+            },
+            ..FileLocation::default()
+        };
+        let file = |destination: &str, location| File {
+            destination: Some(destination.to_owned()),
+            location,
+            ..File::default()
+        };
+
+        // The second location is shared by two files, and the last chunk starts at the same offset
+        // as the first but in the next slice
+        let inno = inno_with_files(
+            vec![
+                file("a", 1),
+                file("b", 0),
+                file("c", 1),
+                file("d", 2),
+                file("external", u32::MAX),
+                file("e", 3),
+            ],
+            vec![
+                location(0, 100, 0),
+                location(0, 0, 8),
+                location(0, 0, 0),
+                location(1, 0, 0),
+            ],
+        );
+
+        let plans = inno
+            .extraction_plan()
+            .iter()
+            .map(|plan| {
+                let chunk = plan.chunk();
+                let members = plan
+                    .members()
+                    .iter()
+                    .map(|member| (member.file().destination().unwrap(), member.offset()))
+                    .collect::<Vec<_>>();
+                ((chunk.first_slice(), chunk.start_offset()), members)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            plans,
+            [
+                ((0, 0), vec![("d", 0), ("a", 8), ("c", 8)]),
+                ((0, 100), vec![("b", 0)]),
+                ((1, 0), vec![("e", 0)]),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case::empty(vec![], 0x1000)]
+    #[case::chunks(vec![(100, 20), (0, 10)], 0x1000 + 124)]
+    #[case::start_overflow(vec![(u64::MAX, 0)], u64::MAX)]
+    #[case::size_overflow(vec![(0, u64::MAX)], u64::MAX)]
+    fn data_stream_range(#[case] chunks: Vec<(u64, u64)>, #[case] expected_end: u64) {
+        let mut inno = inno_with_files(
+            vec![],
+            chunks
+                .into_iter()
+                .map(|(start_offset, original_size)| FileLocation {
+                    chunk: Chunk {
+                        start_offset,
+                        original_size,
+                        ..Chunk::default()
+                    },
+                    ..FileLocation::default()
+                })
+                .collect(),
+        );
+
+        inno.inner.setup_loader.data_offset = 0x1000;
+
+        assert_eq!(inno.data_stream_range(), 0x1000..expected_end);
+    }
 }
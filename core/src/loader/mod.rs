@@ -17,8 +17,8 @@
     entry::checksum::ChecksumMismatchError,
     error::InnoResult,
     pe::{
-        CoffHeader, DosHeader, OptionalHeader, SectionTable, Signature,
-        resource::{ImageResourceDataEntry, ResourceDirectory, SectionReader},
+        AddressNotInSectionError, PeHeaders,
+        resource::{ResourceDirectory, ResourceType},
     },
     read::crc32::Crc32Reader,
     version::InnoVersion,
@@ -85,16 +85,40 @@ pub struct SetupLoader {
     /// * 1: u32
     /// * 2: i64
     #[doc(alias = "Offset1")]
-    data_offset: i64,
+    pub(crate) data_offset: i64,
 
     /// Reserved padding for future use, present in revision 2 and later.
     #[doc(alias = "ReservedPadding")]
     reserved_padding: u32,
 }
 
+#[cfg(test)]
+impl Default for SetupLoader {
+    fn default() -> Self {
+        Self {
+            signature: SetupLoaderSignature::new([0; SetupLoaderSignature::LEN]),
+            version: InnoVersion::default(),
+            revision: 0,
+            minimum_setup_exe_size: 0,
+            exe_offset: 0,
+            exe_compressed_size: 0,
+            exe_uncompressed_size: 0,
+            exe_checksum: Checksum::Crc32(0),
+            message_offset: 0,
+            header_offset: 0,
+            data_offset: 0,
+            reserved_padding: 0,
+        }
+    }
+}
+
 impl SetupLoader {
     const EXE_MODE_OFFSET: u32 = 0x30;
 
+    /// The marker at [`EXE_MODE_OFFSET`](Self::EXE_MODE_OFFSET) of an uninstaller executable.
+    #[doc(alias = "SetupExeModeUninstaller")]
+    const EXE_MODE_UNINSTALLER: [u8; 4] = *b"InUn";
+
     const TABLE_RESOURCE_ID: u32 = 11111;
 
     /// Attempts to find the setup loader via the legacy method, falling back to checking for a PE
@@ -106,6 +130,24 @@ pub fn read_from<R>(mut src: R) -> InnoResult<Self>
         Self::read_legacy(&mut src).or_else(|_| Self::read_from_resource(&mut src))
     }
 
+    /// Returns `true` if the executable is an Inno Setup uninstaller, such as `unins000.exe`.
+    ///
+    /// Uninstallers are a copy of the setup program itself, rather than the setup loader that
+    /// wraps it, so they have no offset table and no embedded setup data. Instead, they are marked
+    /// as uninstallers at the same offset that the setup loader of old versions stores a pointer to
+    /// its offset table, and what to uninstall is read from the `unins000.dat` log alongside them.
+    pub fn is_uninstaller<R>(mut reader: R) -> io::Result<bool>
+    where
+        R: Read + Seek,
+    {
+        reader.seek(SeekFrom::Start(Self::EXE_MODE_OFFSET.into()))?;
+
+        let mut mode = [0; 4];
+        reader.read_exact(&mut mode)?;
+
+        Ok(mode == Self::EXE_MODE_UNINSTALLER)
+    }
+
     /// Prior to Inno 5.1.5, the offset table is found by following a pointer at a constant offset.
     fn read_legacy<R>(mut reader: R) -> InnoResult<Self>
     where
@@ -128,73 +170,51 @@ fn read_from_resource<R>(mut reader: R) -> InnoResult<Self>
     where
         R: Read + Seek,
     {
-        // Seek to the start of the file
-        reader.seek(SeekFrom::Start(0))?;
-
-        // Read DOS Header
-        let dos_header = DosHeader::try_read_from_io(&mut reader)?;
-
-        // Seek to PE header
-        reader.seek(SeekFrom::Start(dos_header.pe_pointer().into()))?;
-
-        // Read PE Signature
-        let _signature = Signature::try_read_from_io(&mut reader)?; // PE/0/0
-
-        // Read COFF header
-        let coff_header = reader.read_t::<CoffHeader>()?;
-
-        // Read optional header
-        let optional_header = OptionalHeader::read_from(&mut reader)?;
-
-        // Read the section table
-        let section_table = SectionTable::read_from(&mut reader, coff_header)?;
-
-        // Get the resource table data directory header
-        let resource_table = optional_header
-            .data_directories
-            .resource_table()
+        let (mut resource_directory, section_table) =
+            match ResourceDirectory::from_executable(&mut reader) {
+                Ok(resource_directory) => resource_directory,
+                Err(err) => return Err(Self::packed_executable_error(err, &mut reader)),
+            };
+
+        let loader_data_entry = resource_directory
+            .find_data(ResourceType::RCData, Some(Self::TABLE_RESOURCE_ID))?
             .ok_or_else(|| {
                 InnoError::Io(io::Error::new(
                     io::ErrorKind::InvalidData,
-                    "No resource table found in executable",
+                    "No loader data entry found in loader directory",
                 ))
             })?;
 
-        // Get the actual file offset of the resource directory section
-        let resource_directory_offset = resource_table.file_offset(&section_table)?;
-
-        let section_reader = SectionReader::new(
-            &mut reader,
-            resource_directory_offset.into(),
-            resource_table.size().into(),
-        )?;
-
-        let mut resource_directory = ResourceDirectory::new(section_reader)?;
-
-        let _rc_data = resource_directory.find_rc_data()?;
-
-        let loader_directory_table =
-            resource_directory.find_directory_table_by_id(Self::TABLE_RESOURCE_ID)?;
-
-        let loader_directory = loader_directory_table.entries().next().ok_or_else(|| {
-            InnoError::Io(io::Error::new(
-                io::ErrorKind::InvalidData,
-                "No loader data entry found in loader directory",
-            ))
-        })?;
-
-        let loader_data_entry_offset = loader_directory.file_offset(resource_directory_offset);
-        reader.seek(SeekFrom::Start(loader_data_entry_offset.into()))?;
-
-        let loader_data_entry = reader.read_t::<ImageResourceDataEntry>()?;
-        let setup_loader_offset =
-            section_table.to_file_offset(loader_data_entry.offset_to_data())?;
+        let setup_loader_offset = section_table
+            .to_file_offset(loader_data_entry.offset_to_data())
+            .map_err(|err| Self::packed_executable_error(err, &mut reader))?;
 
         reader.seek(SeekFrom::Start(setup_loader_offset.into()))?;
 
         Self::new(reader.take(loader_data_entry.size().into()))
     }
 
+    /// Returns [`InnoError::PackedExecutable`] if an address could not be translated to a file
+    /// offset, as packers such as UPX move the sections that the resource addresses are relative
+    /// to.
+    fn packed_executable_error<R>(err: io::Error, reader: R) -> InnoError
+    where
+        R: Read + Seek,
+    {
+        if err
+            .get_ref()
+            .is_some_and(|inner| inner.is::<AddressNotInSectionError>())
+        {
+            InnoError::PackedExecutable {
+                packer: PeHeaders::read_from(reader)
+                    .ok()
+                    .and_then(|headers| headers.section_table().packer()),
+            }
+        } else {
+            err.into()
+        }
+    }
+
     fn new<R>(reader: R) -> InnoResult<Self>
     where
         R: Read,
@@ -305,6 +325,24 @@ pub const fn revision(&self) -> u32 {
         self.revision
     }
 
+    /// Returns `true` if the offset table stores its sizes and offsets as 64-bit integers.
+    ///
+    /// Offset tables from revision 2 onwards store the [header offset], [data offset], [EXE offset]
+    /// and [minimum setup.exe size] as `i64`, which allows installers larger than 4 GiB. Earlier
+    /// revisions store them as `u32`, so they never exceed [`u32::MAX`].
+    ///
+    /// When this returns `true`, the offsets must not be truncated to `u32`.
+    ///
+    /// [header offset]: Self::header_offset
+    /// [data offset]: Self::data_offset
+    /// [EXE offset]: Self::exe_offset
+    /// [minimum setup.exe size]: Self::minimum_setup_exe_size
+    #[must_use]
+    #[inline]
+    pub const fn is_64bit_offsets(&self) -> bool {
+        self.revision >= 2
+    }
+
     /// Returns the signature of the setup loader.
     #[doc(alias = "ID")]
     #[must_use]
@@ -361,6 +399,10 @@ pub const fn message_offset(&self) -> u32 {
     }
 
     /// Returns the offset of the embedded setup-0.bin data.
+    ///
+    /// This may exceed [`u32::MAX`] if the offset table [uses 64-bit offsets].
+    ///
+    /// [uses 64-bit offsets]: Self::is_64bit_offsets
     #[doc(alias = "Offset0")]
     #[must_use]
     #[inline]
@@ -369,20 +411,158 @@ pub const fn header_offset(&self) -> i64 {
     }
 
     /// Returns the offset of the embedded setup-1.bin data.
+    ///
+    /// This may exceed [`u32::MAX`] if the offset table [uses 64-bit offsets].
+    ///
+    /// [uses 64-bit offsets]: Self::is_64bit_offsets
     #[doc(alias = "Offset1")]
     #[must_use]
     #[inline]
     pub const fn data_offset(&self) -> i64 {
         self.data_offset
     }
+
+    /// Checks that an installer of the given length is long enough for the offsets and sizes in
+    /// the offset table.
+    ///
+    /// The installer must be at least [`minimum_setup_exe_size`](Self::minimum_setup_exe_size)
+    /// bytes, the [header offset](Self::header_offset) must be within it, and the
+    /// [data offset](Self::data_offset) and the end of the compressed setup.e32 must not be past
+    /// its end.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InnoError::Truncated`] with the length that the offset table requires if the
+    /// installer is shorter than that, such as when it was not completely downloaded.
+    pub fn validate_against_len(&self, file_len: u64) -> InnoResult<()> {
+        let expected = [
+            self.minimum_setup_exe_size.unsigned_abs(),
+            self.header_offset.unsigned_abs() + 1,
+            self.data_offset.unsigned_abs(),
+            self.exe_offset.unsigned_abs() + u64::from(self.exe_compressed_size),
+        ]
+        .into_iter()
+        .max()
+        .unwrap_or_default();
+
+        if file_len < expected {
+            return Err(InnoError::Truncated {
+                expected,
+                actual: file_len,
+            });
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::io::Cursor;
+
+    use rstest::rstest;
+
     use super::SetupLoader;
+    use crate::error::InnoError;
+
+    /// Builds an Inno Setup 5.1.5+ offset table of the given revision, with the given header and
+    /// data offsets.
+    fn offset_table(revision: u32, header_offset: i64, data_offset: i64) -> Vec<u8> {
+        let offset = |value: i64| {
+            if revision >= 2 {
+                value.to_le_bytes().to_vec()
+            } else {
+                u32::try_from(value).unwrap().to_le_bytes().to_vec()
+            }
+        };
+
+        let mut table = [
+            b"rDlPtS\xCD\xE6\xD7{\x0B*".as_slice(),
+            &revision.to_le_bytes(),
+            &offset(0x1000),
+            &offset(0x200),
+            &0x800u32.to_le_bytes(),
+            &0u32.to_le_bytes(),
+            &offset(header_offset),
+            &offset(data_offset),
+        ]
+        .concat();
+
+        if revision >= 2 {
+            table.extend(0u32.to_le_bytes());
+        }
+
+        table.extend(crc32fast::hash(&table).to_le_bytes());
+        table
+    }
 
     #[test]
     fn size() {
         assert_eq!(size_of::<SetupLoader>(), 80);
     }
+
+    #[rstest]
+    #[case::revision_1(1, false)]
+    #[case::revision_2(2, true)]
+    fn is_64bit_offsets(#[case] revision: u32, #[case] expected: bool) {
+        let loader = SetupLoader::new(offset_table(revision, 0x400, 0x600).as_slice()).unwrap();
+
+        assert_eq!(loader.revision(), revision);
+        assert_eq!(loader.is_64bit_offsets(), expected);
+        assert_eq!(loader.header_offset(), 0x400);
+        assert_eq!(loader.data_offset(), 0x600);
+    }
+
+    #[test]
+    fn offsets_beyond_u32() {
+        let header_offset = i64::from(u32::MAX) + 0x400;
+        let data_offset = 6 * 1024 * 1024 * 1024;
+
+        let loader =
+            SetupLoader::new(offset_table(2, header_offset, data_offset).as_slice()).unwrap();
+
+        assert_eq!(loader.header_offset(), header_offset);
+        assert_eq!(loader.data_offset(), data_offset);
+    }
+
+    #[rstest]
+    #[case::uninstaller(*b"InUn", true)]
+    #[case::setup(*b"Inno", false)]
+    fn is_uninstaller(#[case] mode: [u8; 4], #[case] expected: bool) {
+        let mut executable = vec![0; 0x40];
+        executable[0x30..0x34].copy_from_slice(&mode);
+
+        assert_eq!(
+            SetupLoader::is_uninstaller(Cursor::new(executable)).unwrap(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case::complete(0x2000, 0x3000, 0x3000, None)]
+    #[case::truncated_data(0x2000, 0x3000, 0x2FFF, Some(0x3000))]
+    #[case::disk_spanned(0x1800, 0, 0x1801, None)]
+    #[case::truncated_header(0x1800, 0, 0x1800, Some(0x1801))]
+    #[case::minimum_size(0x100, 0, 0xFFF, Some(0x1000))]
+    fn validate_against_len(
+        #[case] header_offset: i64,
+        #[case] data_offset: i64,
+        #[case] file_len: u64,
+        #[case] expected: Option<u64>,
+    ) {
+        let loader =
+            SetupLoader::new(offset_table(2, header_offset, data_offset).as_slice()).unwrap();
+
+        match loader.validate_against_len(file_len) {
+            Ok(()) => assert_eq!(expected, None),
+            Err(InnoError::Truncated {
+                expected: required,
+                actual,
+            }) => {
+                assert_eq!(Some(required), expected);
+                assert_eq!(actual, file_len);
+            }
+            Err(err) => panic!("unexpected error: {err}"),
+        }
+    }
 }
@@ -12,28 +12,55 @@ impl SetupLoaderSignature {
     /// The length of the signature in bytes.
     pub const LEN: usize = SIGNATURE_LEN;
 
-    const KNOWN: [(Self, InnoVersion); 7] = [
-        (Self(*b"rDlPtS02\x87eVx"), InnoVersion::new(1, 2, 10, 0)),
-        (Self(*b"rDlPtS04\x87eVx"), InnoVersion::new(4, 0, 0, 0)),
-        (Self(*b"rDlPtS05\x87eVx"), InnoVersion::new(4, 0, 3, 0)),
-        (Self(*b"rDlPtS06\x87eVx"), InnoVersion::new(4, 0, 10, 0)),
-        (Self(*b"rDlPtS07\x87eVx"), InnoVersion::new(4, 1, 6, 0)),
+    const KNOWN: [(Self, InnoVersion, &'static str); 7] = [
+        (
+            Self(*b"rDlPtS02\x87eVx"),
+            InnoVersion::new(1, 2, 10, 0),
+            "Inno Setup 1.2.10",
+        ),
+        (
+            Self(*b"rDlPtS04\x87eVx"),
+            InnoVersion::new(4, 0, 0, 0),
+            "Inno Setup 4.0.0",
+        ),
+        (
+            Self(*b"rDlPtS05\x87eVx"),
+            InnoVersion::new(4, 0, 3, 0),
+            "Inno Setup 4.0.3",
+        ),
+        (
+            Self(*b"rDlPtS06\x87eVx"),
+            InnoVersion::new(4, 0, 10, 0),
+            "Inno Setup 4.0.10",
+        ),
+        (
+            Self(*b"rDlPtS07\x87eVx"),
+            InnoVersion::new(4, 1, 6, 0),
+            "Inno Setup 4.1.6",
+        ),
         (
             Self(*b"rDlPtS\xCD\xE6\xD7{\x0B*"),
             InnoVersion::new(5, 1, 5, 0),
+            "Inno Setup 5.1.5",
         ),
         (
             Self(*b"nS5W7dT\x83\xAA\x1B\x0Fj"),
             InnoVersion::new(5, 1, 5, 0),
+            "Inno Setup 5.1.5 (alternate)",
         ),
     ];
 
-    const KNOWN_LEGACY: [(Self, InnoVersion); 2] = [
+    const KNOWN_LEGACY: [(Self, InnoVersion, &'static str); 2] = [
         (
             Self(*b"i1.2.10--16\x1A"),
             InnoVersion::new_with_variant(1, 2, 10, 0, VersionVariant::BITS_16),
+            "Inno Setup 1.2.10 (16-bit legacy)",
+        ),
+        (
+            Self(*b"i1.2.10--32\x1A"),
+            InnoVersion::new(1, 2, 10, 0),
+            "Inno Setup 1.2.10 (32-bit legacy)",
         ),
-        (Self(*b"i1.2.10--32\x1A"), InnoVersion::new(1, 2, 10, 0)),
     ];
 
     /// Reads a `SetupLoaderSignature` from a reader.
@@ -56,7 +83,7 @@ pub fn read_from<R>(mut src: R) -> io::Result<Self>
     /// Returns `true` if the signature is known.
     #[must_use]
     pub fn is_known(self) -> bool {
-        Self::KNOWN.into_iter().any(|(sig, _)| sig == self)
+        Self::KNOWN.into_iter().any(|(sig, _, _)| sig == self)
     }
 
     /// Returns the number of bits of the Inno Setup version the signature represents.
@@ -85,12 +112,40 @@ pub fn is_16_bit(self) -> bool {
         self.0
     }
 
+    /// Returns the signature as a lossy ASCII string, such as `rDlPtS02�eVx`.
+    ///
+    /// Bytes that are not printable ASCII are replaced with [`char::REPLACEMENT_CHARACTER`]. Use
+    /// the [`Display`](fmt::Display) implementation instead to escape them.
+    #[must_use]
+    pub fn as_str(&self) -> String {
+        self.0
+            .iter()
+            .map(|&byte| {
+                if byte.is_ascii_graphic() || byte == b' ' {
+                    char::from(byte)
+                } else {
+                    char::REPLACEMENT_CHARACTER
+                }
+            })
+            .collect()
+    }
+
+    /// Returns a label for the signature if it is recognized, naming the first Inno Setup
+    /// version whose loader stub used it.
+    #[must_use]
+    pub fn known_name(self) -> Option<&'static str> {
+        Self::KNOWN
+            .into_iter()
+            .chain(Self::KNOWN_LEGACY)
+            .find_map(|(sig, _, name)| (sig == self).then_some(name))
+    }
+
     /// Returns the version associated with the signature, if known.
     #[must_use]
     pub fn version(self) -> Option<InnoVersion> {
         Self::KNOWN
             .into_iter()
-            .find_map(|(sig, version)| (sig == self).then_some(version))
+            .find_map(|(sig, version, _)| (sig == self).then_some(version))
     }
 }
 
@@ -121,3 +176,25 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             .try_for_each(|escaped| f.write_char(escaped))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::SetupLoaderSignature;
+
+    #[rstest]
+    #[case::known(*b"rDlPtS02\x87eVx", "rDlPtS02\u{FFFD}eVx", Some("Inno Setup 1.2.10"))]
+    #[case::legacy(*b"i1.2.10--16\x1A", "i1.2.10--16\u{FFFD}", Some("Inno Setup 1.2.10 (16-bit legacy)"))]
+    #[case::unknown(*b"Inno Setup 9", "Inno Setup 9", None)]
+    fn as_str_and_known_name(
+        #[case] signature: [u8; SetupLoaderSignature::LEN],
+        #[case] expected_str: &str,
+        #[case] expected_name: Option<&str>,
+    ) {
+        let signature = SetupLoaderSignature::new(signature);
+
+        assert_eq!(signature.as_str(), expected_str);
+        assert_eq!(signature.known_name(), expected_name);
+    }
+}
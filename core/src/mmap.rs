@@ -0,0 +1,49 @@
+use std::{fs::File, io::Cursor, path::Path};
+
+use memmap2::Mmap;
+
+use crate::{Inno, error::InnoResult};
+
+impl Inno<Cursor<Mmap>> {
+    /// Parses the installer at the given path by memory-mapping it, using the default parse
+    /// options.
+    ///
+    /// Parsing and extraction seek back and forth throughout the installer, which is cheaper with a
+    /// memory map than with buffered reads of a large file.
+    ///
+    /// Use [`Inno::new`] with a [`File`] if the installer could be modified while it is parsed.
+    ///
+    /// ```no_run
+    /// use inno::{Inno, error::InnoResult};
+    ///
+    /// fn main() -> InnoResult<()> {
+    ///     // SAFETY: Nothing else modifies the installer while it is mapped.
+    ///     let inno = unsafe { Inno::open_mmap("path/to/setup.exe")? };
+    ///     println!("{}", inno.version());
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// The installer must not be modified or truncated while the returned [`Inno`] is in use, by
+    /// this or any other process. The mapped memory would otherwise change underneath the parsed
+    /// installer, which is undefined behaviour. See [`Mmap::map`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Inno::new`], and an I/O error if the file could not be opened
+    /// or mapped.
+    #[allow(unsafe_code)]
+    pub unsafe fn open_mmap<P>(path: P) -> InnoResult<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::open(path)?;
+
+        // SAFETY: The caller guarantees that the installer is not modified while it is mapped.
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        Self::new(Cursor::new(mmap))
+    }
+}
@@ -0,0 +1,72 @@
+use std::{
+    io,
+    io::{Read, Seek, SeekFrom},
+};
+
+use zerocopy::LE;
+
+use super::optional_header::DataDirectory;
+use crate::ReadBytesExt;
+
+/// The `wCertificateType` of a `WIN_CERTIFICATE` that contains a PKCS#7 `SignedData` structure.
+const WIN_CERT_TYPE_PKCS_SIGNED_DATA: u16 = 2;
+
+/// Reads the PKCS#7 `SignedData` blob of the first Authenticode signature in the attribute
+/// certificate table.
+///
+/// Returns `None` if the first certificate is not a PKCS#7 signature.
+pub fn read_authenticode_signature<R>(
+    mut reader: R,
+    certificate_table: DataDirectory,
+) -> io::Result<Option<Vec<u8>>>
+where
+    R: Read + Seek,
+{
+    // The address of the certificate table is a file offset rather than a virtual address
+    reader.seek(SeekFrom::Start(certificate_table.virtual_address().into()))?;
+
+    let length = reader.read_u32::<LE>()?;
+    let _revision = reader.read_u16::<LE>()?;
+    let certificate_type = reader.read_u16::<LE>()?;
+
+    if certificate_type != WIN_CERT_TYPE_PKCS_SIGNED_DATA {
+        return Ok(None);
+    }
+
+    // The length includes the 8-byte header, and can't exceed the table
+    let size = length.min(certificate_table.size()).saturating_sub(8);
+
+    let mut signature = Vec::new();
+    reader.take(size.into()).read_to_end(&mut signature)?;
+    Ok(Some(signature))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use rstest::rstest;
+    use zerocopy::FromBytes;
+
+    use super::{DataDirectory, read_authenticode_signature};
+
+    #[rstest]
+    #[case::pkcs7(2, Some(&[0x30, 0x82, 0x01][..]))]
+    #[case::x509(1, None)]
+    fn authenticode_signature(#[case] certificate_type: u16, #[case] expected: Option<&[u8]>) {
+        let mut executable = vec![0; 0x10];
+        executable.extend(11u32.to_le_bytes());
+        executable.extend(0x0200u16.to_le_bytes());
+        executable.extend(certificate_type.to_le_bytes());
+        executable.extend([0x30, 0x82, 0x01, 0x00, 0x00]);
+
+        let table = DataDirectory::read_from_bytes(&[0x10, 0, 0, 0, 16, 0, 0, 0]).unwrap();
+
+        assert_eq!(
+            read_authenticode_signature(Cursor::new(executable), table)
+                .unwrap()
+                .as_deref(),
+            expected
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use bitflags::bitflags;
 use zerocopy::{FromBytes, Immutable, KnownLayout};
 
+/// The characteristics of an image, from its [`CoffHeader`](crate::pe::CoffHeader).
 #[derive(Clone, Copy, Debug, Eq, PartialEq, FromBytes, Immutable, KnownLayout)]
 #[repr(transparent)]
 pub struct CoffCharacteristics(u16);
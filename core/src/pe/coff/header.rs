@@ -1,4 +1,4 @@
-use zerocopy::{FromBytes, Immutable, KnownLayout, LittleEndian, U16, U32};
+use zerocopy::{FromBytes, Immutable, KnownLayout, LittleEndian, U16, U32, Unaligned};
 
 use super::CoffCharacteristics;
 
@@ -19,7 +19,7 @@
 /// [`IMAGE_NT_HEADERS`]: https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-image_nt_headers32
 /// [`Rich header`]: https://0xrick.github.io/win-internals/pe3/#rich-header
 #[doc(alias("IMAGE_FILE_HEADER"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq, FromBytes, KnownLayout, Immutable)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, FromBytes, KnownLayout, Immutable, Unaligned)]
 #[repr(C)]
 pub struct CoffHeader {
     #[doc(alias("Machine"))]
@@ -41,7 +41,7 @@ pub struct CoffHeader {
     size_of_optional_header: U16<LittleEndian>,
 
     #[doc(alias("Characteristics"))]
-    characteristics: CoffCharacteristics,
+    characteristics: U16<LittleEndian>,
 }
 
 impl CoffHeader {
@@ -50,35 +50,35 @@ impl CoffHeader {
     ///
     /// Can be one of the following values:
     ///
-    /// * [`COFF_MACHINE_UNKNOWN`],
-    /// * [`COFF_MACHINE_ALPHA`],
-    /// * [`COFF_MACHINE_ALPHA64`],
-    /// * [`COFF_MACHINE_AM33`],
-    /// * [`COFF_MACHINE_X86_64`],
-    /// * [`COFF_MACHINE_ARM`],
-    /// * [`COFF_MACHINE_ARM64`],
-    /// * [`COFF_MACHINE_ARMNT`],
-    /// * [`COFF_MACHINE_EBC`],
-    /// * [`COFF_MACHINE_X86`],
-    /// * [`COFF_MACHINE_IA64`],
-    /// * [`COFF_MACHINE_LOONGARCH32`],
-    /// * [`COFF_MACHINE_LOONGARCH64`],
-    /// * [`COFF_MACHINE_M32R`],
-    /// * [`COFF_MACHINE_MIPS16`],
-    /// * [`COFF_MACHINE_MIPSFPU`],
-    /// * [`COFF_MACHINE_MIPSFPU16`],
-    /// * [`COFF_MACHINE_POWERPC`],
-    /// * [`COFF_MACHINE_POWERPCFP`],
-    /// * [`COFF_MACHINE_R4000`],
-    /// * [`COFF_MACHINE_RISCV32`],
-    /// * [`COFF_MACHINE_RISCV64`],
-    /// * [`COFF_MACHINE_RISCV128`],
-    /// * [`COFF_MACHINE_SH3`],
-    /// * [`COFF_MACHINE_SH3DSP`],
-    /// * [`COFF_MACHINE_SH4`],
-    /// * [`COFF_MACHINE_SH5`],
-    /// * [`COFF_MACHINE_THUMB`],
-    /// * [`COFF_MACHINE_WCEMIPSV2`],
+    /// * `COFF_MACHINE_UNKNOWN`,
+    /// * `COFF_MACHINE_ALPHA`,
+    /// * `COFF_MACHINE_ALPHA64`,
+    /// * `COFF_MACHINE_AM33`,
+    /// * `COFF_MACHINE_X86_64`,
+    /// * `COFF_MACHINE_ARM`,
+    /// * `COFF_MACHINE_ARM64`,
+    /// * `COFF_MACHINE_ARMNT`,
+    /// * `COFF_MACHINE_EBC`,
+    /// * `COFF_MACHINE_X86`,
+    /// * `COFF_MACHINE_IA64`,
+    /// * `COFF_MACHINE_LOONGARCH32`,
+    /// * `COFF_MACHINE_LOONGARCH64`,
+    /// * `COFF_MACHINE_M32R`,
+    /// * `COFF_MACHINE_MIPS16`,
+    /// * `COFF_MACHINE_MIPSFPU`,
+    /// * `COFF_MACHINE_MIPSFPU16`,
+    /// * `COFF_MACHINE_POWERPC`,
+    /// * `COFF_MACHINE_POWERPCFP`,
+    /// * `COFF_MACHINE_R4000`,
+    /// * `COFF_MACHINE_RISCV32`,
+    /// * `COFF_MACHINE_RISCV64`,
+    /// * `COFF_MACHINE_RISCV128`,
+    /// * `COFF_MACHINE_SH3`,
+    /// * `COFF_MACHINE_SH3DSP`,
+    /// * `COFF_MACHINE_SH4`,
+    /// * `COFF_MACHINE_SH5`,
+    /// * `COFF_MACHINE_THUMB`,
+    /// * `COFF_MACHINE_WCEMIPSV2`,
     ///
     /// or any other value that is not listed here.
     ///
@@ -138,6 +138,6 @@ pub const fn size_of_optional_header(&self) -> u16 {
     /// [characteristics]: https://learn.microsoft.com/windows/win32/debug/pe-format#characteristics
     #[inline]
     pub const fn characteristics(&self) -> CoffCharacteristics {
-        self.characteristics
+        CoffCharacteristics::from_bits_retain(self.characteristics.get())
     }
 }
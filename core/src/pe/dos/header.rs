@@ -10,7 +10,7 @@
 /// if the binary is run in MS-DOS by utilizing the DOS stub.
 ///
 /// Nowadays, only two fields from the DOS header are used on Windows:
-/// [`signature` (aka `e_magic`)](DosHeader::signature) and
+/// `signature` (aka `e_magic`) and
 /// [`pe_pointer` (aka `e_lfanew`)](DosHeader::pe_pointer).
 ///
 /// ## Position in a modern PE file
@@ -121,6 +121,11 @@ pub struct DosHeader {
 }
 
 impl DosHeader {
+    /// Reads a DOS header, checking its `MZ` signature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header could not be read or has an invalid signature.
     pub fn try_read_from_io<R>(mut src: R) -> io::Result<Self>
     where
         Self: Sized,
@@ -0,0 +1,114 @@
+use std::{
+    io,
+    io::{Read, Seek, SeekFrom},
+};
+
+use super::{
+    CoffHeader, DosHeader, OptionalHeader, SectionTable, Signature, optional_header::DataDirectory,
+};
+use crate::ReadBytesExt;
+
+/// The headers of a PE executable, from the DOS header through to the section table.
+///
+/// This is read by [`Inno::pe_headers`](crate::Inno::pe_headers), or by
+/// [`PeHeaders::read_from`] for any executable.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PeHeaders {
+    dos_header: DosHeader,
+    coff_header: CoffHeader,
+    optional_header: OptionalHeader,
+    section_table: SectionTable,
+}
+
+impl PeHeaders {
+    /// Reads the headers from the start of an executable.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reader could not be read or if the executable is not a valid PE
+    /// file.
+    pub fn read_from<R>(mut reader: R) -> io::Result<Self>
+    where
+        R: Read + Seek,
+    {
+        // Seek to the start of the file
+        reader.seek(SeekFrom::Start(0))?;
+
+        // Read DOS Header
+        let dos_header = DosHeader::try_read_from_io(&mut reader)?;
+
+        // Seek to PE header
+        reader.seek(SeekFrom::Start(dos_header.pe_pointer().into()))?;
+
+        // Read PE Signature
+        let _signature = Signature::try_read_from_io(&mut reader)?; // PE/0/0
+
+        // Read COFF header
+        let coff_header = reader.read_t::<CoffHeader>()?;
+
+        // Read optional header
+        let optional_header = OptionalHeader::read_from(&mut reader)?;
+
+        // Read the section table
+        let section_table = SectionTable::read_from(&mut reader, coff_header)?;
+
+        Ok(Self {
+            dos_header,
+            coff_header,
+            optional_header,
+            section_table,
+        })
+    }
+
+    /// Returns the DOS header, which points to the rest of the headers.
+    #[must_use]
+    #[inline]
+    pub const fn dos_header(&self) -> &DosHeader {
+        &self.dos_header
+    }
+
+    /// Returns the COFF header, which has the machine type and characteristics of the executable.
+    #[must_use]
+    #[inline]
+    pub const fn coff_header(&self) -> &CoffHeader {
+        &self.coff_header
+    }
+
+    /// Returns the optional header, which has the subsystem, DLL characteristics and data
+    /// directories of the executable.
+    #[must_use]
+    #[inline]
+    pub const fn optional_header(&self) -> &OptionalHeader {
+        &self.optional_header
+    }
+
+    /// Returns the section table.
+    #[must_use]
+    #[inline]
+    pub const fn section_table(&self) -> &SectionTable {
+        &self.section_table
+    }
+
+    /// Returns the attribute certificate table, which holds the executable's Authenticode
+    /// signatures, or `None` if the executable is not signed.
+    ///
+    /// Unlike the other data directories, the [address](DataDirectory::virtual_address) of the
+    /// certificate table is a file offset. The table is usually at the end of the file.
+    #[must_use]
+    pub fn certificate_table(&self) -> Option<DataDirectory> {
+        self.optional_header
+            .data_directories
+            .certificate_table()
+            .filter(|table| table.virtual_address() != 0 && table.size() != 0)
+            .copied()
+    }
+
+    /// Returns `true` if the executable has an Authenticode signature.
+    ///
+    /// This only checks that a signature is present. The signature is not verified.
+    #[must_use]
+    #[inline]
+    pub fn is_authenticode_signed(&self) -> bool {
+        self.certificate_table().is_some()
+    }
+}
@@ -1,12 +1,23 @@
+//! A parser for the headers of PE executables, such as the setup executable of an installer.
+//!
+//! Use [`Inno::pe_headers`](crate::Inno::pe_headers) to read the headers of an installer, or
+//! [`PeHeaders::read_from`] to read them from any executable. The headers include the machine type
+//! and [characteristics](CoffCharacteristics) in the [`CoffHeader`], the subsystem and DLL
+//! characteristics in the [`OptionalHeader`], and the sections in the [`SectionTable`].
+
+mod certificate;
 mod coff;
-pub mod dos;
+mod dos;
+mod headers;
 pub mod optional_header;
-pub mod resource;
+pub(crate) mod resource;
 mod section_table;
 mod signature;
 
-pub use coff::CoffHeader;
+pub(crate) use certificate::read_authenticode_signature;
+pub use coff::{CoffCharacteristics, CoffHeader};
 pub use dos::DosHeader;
+pub use headers::PeHeaders;
 pub use optional_header::OptionalHeader;
-pub use section_table::SectionTable;
+pub use section_table::{AddressNotInSectionError, SectionHeader, SectionTable};
 pub use signature::Signature;
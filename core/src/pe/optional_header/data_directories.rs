@@ -4,6 +4,7 @@
 
 use super::DataDirectory;
 
+/// The data directories of the optional header, in their standard order.
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct DataDirectories(Vec<DataDirectory>);
@@ -22,6 +23,11 @@ fn new_zeroed(len: usize) -> Self
         Self(vec![DataDirectory::new_zeroed(); len])
     }
 
+    /// Reads the given number of data directories.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data directories could not be read.
     pub fn read_from<R>(mut src: R, len: usize) -> io::Result<Self>
     where
         R: io::Read,
@@ -47,81 +53,100 @@ const fn inner_mut(&mut self) -> &mut [DataDirectory] {
         self.0.as_mut_slice()
     }
 
+    /// Returns the export table, or `None` if there are too few data directories.
     #[inline]
     pub const fn export_table(&self) -> Option<&DataDirectory> {
         self.inner().first()
     }
 
+    /// Returns the import table.
     #[inline]
     pub fn import_table(&self) -> Option<&DataDirectory> {
         self.inner().get(1)
     }
 
+    /// Returns the resource table.
     #[inline]
     pub fn resource_table(&self) -> Option<&DataDirectory> {
         self.inner().get(2)
     }
 
+    /// Returns the exception table.
     #[inline]
     pub fn exception_table(&self) -> Option<&DataDirectory> {
         self.inner().get(3)
     }
 
+    /// Returns the attribute certificate table, which holds the Authenticode signature.
+    ///
+    /// Unlike the other data directories, its address is a file offset rather than a virtual
+    /// address.
     #[inline]
     pub fn certificate_table(&self) -> Option<&DataDirectory> {
         self.inner().get(4)
     }
 
+    /// Returns the base relocation table.
     #[inline]
     pub fn base_relocation_table(&self) -> Option<&DataDirectory> {
         self.inner().get(5)
     }
 
+    /// Returns the debug data.
     #[inline]
     pub fn debug_table(&self) -> Option<&DataDirectory> {
         self.inner().get(6)
     }
 
+    /// Returns the architecture data, which is reserved.
     #[inline]
     pub fn architecture(&self) -> Option<&DataDirectory> {
         self.inner().get(7)
     }
 
+    /// Returns the global pointer register value.
     #[inline]
     pub fn global_ptr(&self) -> Option<&DataDirectory> {
         self.inner().get(8)
     }
 
+    /// Returns the thread local storage table.
     #[inline]
     pub fn tls_table(&self) -> Option<&DataDirectory> {
         self.inner().get(9)
     }
 
+    /// Returns the load configuration table.
     #[inline]
     pub fn load_config_table(&self) -> Option<&DataDirectory> {
         self.inner().get(10)
     }
 
+    /// Returns the bound import table.
     #[inline]
     pub fn bound_import_table(&self) -> Option<&DataDirectory> {
         self.inner().get(11)
     }
 
+    /// Returns the import address table.
     #[inline]
     pub fn import_address_table(&self) -> Option<&DataDirectory> {
         self.inner().get(12)
     }
 
+    /// Returns the delay import descriptor.
     #[inline]
     pub fn delay_import_descriptor(&self) -> Option<&DataDirectory> {
         self.inner().get(13)
     }
 
+    /// Returns the CLR runtime header, which is present in .NET executables.
     #[inline]
     pub fn clr_runtime_header(&self) -> Option<&DataDirectory> {
         self.inner().get(14)
     }
 
+    /// Returns an iterator over the data directories.
     #[inline]
     pub fn iter(&self) -> impl Iterator<Item = &DataDirectory> {
         self.into_iter()
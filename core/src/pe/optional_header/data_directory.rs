@@ -1,10 +1,14 @@
 use std::io;
 
-use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, LittleEndian, U32};
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, LittleEndian, U32, Unaligned};
 
 use crate::pe::SectionTable;
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, FromBytes, IntoBytes, Immutable, KnownLayout)]
+/// The address and size of a table, such as the resource table.
+#[doc(alias = "IMAGE_DATA_DIRECTORY")]
+#[derive(
+    Copy, Clone, Debug, Eq, PartialEq, FromBytes, IntoBytes, Immutable, KnownLayout, Unaligned,
+)]
 #[repr(C)]
 pub struct DataDirectory {
     virtual_address: U32<LittleEndian>,
@@ -12,16 +16,24 @@ pub struct DataDirectory {
 }
 
 impl DataDirectory {
+    /// Returns the relative virtual address of the table.
     #[inline]
     pub const fn virtual_address(self) -> u32 {
         self.virtual_address.get()
     }
 
+    /// Returns the size of the table, in bytes.
     #[inline]
     pub const fn size(self) -> u32 {
         self.size.get()
     }
 
+    /// Returns the file offset of the table, using the section table to convert its virtual
+    /// address.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the address is not in any section.
     #[inline]
     pub fn file_offset(self, section_table: &SectionTable) -> io::Result<u32> {
         section_table.to_file_offset(self.virtual_address())
@@ -1,3 +1,5 @@
+//! The optional header of a PE executable, which is required for images.
+
 mod data_directories;
 mod data_directory;
 mod standard_fields;
@@ -11,13 +13,27 @@
 pub use windows_fields::{WindowsFields, WindowsFields32, WindowsFields64};
 use zerocopy::FromBytes;
 
+/// The optional header of a PE executable, which is either `IMAGE_OPTIONAL_HEADER32` or
+/// `IMAGE_OPTIONAL_HEADER64`.
+#[doc(alias = "IMAGE_OPTIONAL_HEADER")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct OptionalHeader {
+    /// The standard COFF fields, which are present for all images.
     pub standard_fields: StandardFields,
+
+    /// The Windows-specific fields, such as the subsystem and DLL characteristics.
     pub windows_fields: WindowsFields,
+
+    /// The data directories, which locate tables such as the resource table.
     pub data_directories: DataDirectories,
 }
 
 impl OptionalHeader {
+    /// Reads a 32-bit or 64-bit optional header, depending on its magic.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the optional header could not be read or has an unknown magic.
     #[expect(clippy::cast_possible_wrap)]
     pub fn read_from<R>(mut src: R) -> io::Result<Self>
     where
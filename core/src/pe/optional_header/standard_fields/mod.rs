@@ -14,7 +14,7 @@
 /// * [`IMAGE_OPTIONAL_HEADER32`](https://learn.microsoft.com/windows/win32/api/winnt/ns-winnt-image_optional_header32); or
 /// * [`IMAGE_OPTIONAL_HEADER64`](https://learn.microsoft.com/windows/win32/api/winnt/ns-winnt-image_optional_header64)
 ///
-/// from `winnt.h`, depending on the value of [`StandardFields::magic`].
+/// from `winnt.h`, depending on its [`Magic`].
 ///
 /// ## Position in PE binary
 ///
@@ -27,6 +27,7 @@
 /// * For 64-bit version, see [`StandardFields64`].
 ///
 /// [`OptionalHeader`]: crate::pe::OptionalHeader
+/// [`WindowsFields`]: crate::pe::optional_header::WindowsFields
 ///
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum StandardFields {
@@ -2,6 +2,9 @@
 
 use zerocopy::{Immutable, KnownLayout, LittleEndian, TryFromBytes, U32};
 
+#[cfg(doc)]
+use super::{StandardFields, StandardFields32};
+
 /// Standard 64-bit COFF fields (for `PE32+`).
 ///
 /// In `winnt.h`, this is a subset of [`IMAGE_OPTIONAL_HEADER64`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-image_optional_header64).
@@ -4,4 +4,5 @@
 pub use windows_fields_32::WindowsFields32;
 pub use windows_fields_64::WindowsFields64;
 
+/// The Windows-specific fields of either a 32-bit or 64-bit image, with 32-bit fields widened.
 pub type WindowsFields = WindowsFields64;
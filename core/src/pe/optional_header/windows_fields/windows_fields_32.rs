@@ -1,7 +1,7 @@
-use zerocopy::{FromBytes, Immutable, KnownLayout, LittleEndian, U16, U32};
+use zerocopy::{FromBytes, Immutable, KnownLayout, LittleEndian, U16, U32, Unaligned};
 
 #[cfg(doc)]
-use super::WindowsFields;
+use super::{WindowsFields, WindowsFields64};
 
 /// Windows specific fields for 32-bit binary (`PE32`). They're also known as "NT additional fields".
 ///
@@ -9,7 +9,7 @@
 ///
 /// * For 64-bit version, see [`WindowsFields64`].
 /// * For unified version, see [`WindowsFields`].
-#[derive(Copy, Clone, Debug, Eq, PartialEq, FromBytes, Immutable, KnownLayout)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, FromBytes, Immutable, KnownLayout, Unaligned)]
 #[repr(C)]
 pub struct WindowsFields32 {
     /// See docs for [`WindowsFields::image_base`].
@@ -1,4 +1,4 @@
-use zerocopy::{FromBytes, Immutable, KnownLayout, LittleEndian, U16, U32, U64};
+use zerocopy::{FromBytes, Immutable, KnownLayout, LittleEndian, U16, U32, U64, Unaligned};
 
 #[cfg(doc)]
 use super::WindowsFields;
@@ -13,7 +13,7 @@
 ///
 /// * For 32-bit version, see [`WindowsFields32`].
 /// * For unified version, see [`WindowsFields`].
-#[derive(Copy, Clone, Debug, Eq, PartialEq, FromBytes, Immutable, KnownLayout)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, FromBytes, Immutable, KnownLayout, Unaligned)]
 #[repr(C)]
 pub struct WindowsFields64 {
     /// See docs for [`WindowsFields::image_base`].
@@ -134,8 +134,9 @@ impl WindowsFields64 {
     ///
     /// ## Position in PE binary
     ///
-    /// Windows fields are located inside [`OptionalHeader`] after [`StandardFields`] and before the
-    /// [`DataDirectories`](data_directories::DataDirectories).
+    /// Windows fields are located inside [`OptionalHeader`](crate::pe::OptionalHeader) after
+    /// [`StandardFields`](crate::pe::optional_header::StandardFields) and before the
+    /// [`DataDirectories`](crate::pe::optional_header::DataDirectories).
     ///
     /// ## Related structures
     ///
@@ -163,96 +164,122 @@ pub const fn section_alignment(&self) -> u32 {
         self.section_alignment.get()
     }
 
+    /// The alignment, in bytes, of the raw data of sections in the image file.
     #[inline]
     pub const fn file_alignment(&self) -> u32 {
         self.file_alignment.get()
     }
 
+    /// The major version number of the required operating system.
     #[inline]
     pub const fn major_operating_system_version(&self) -> u16 {
         self.major_operating_system_version.get()
     }
 
+    /// The minor version number of the required operating system.
     #[inline]
     pub const fn minor_operating_system_version(&self) -> u16 {
         self.minor_operating_system_version.get()
     }
 
+    /// The major version number of the image.
     #[inline]
     pub const fn major_image_version(&self) -> u16 {
         self.major_image_version.get()
     }
 
+    /// The minor version number of the image.
     #[inline]
     pub const fn minor_image_version(&self) -> u16 {
         self.minor_image_version.get()
     }
 
+    /// The major version number of the subsystem.
     #[inline]
     pub const fn major_subsystem_version(&self) -> u16 {
         self.major_subsystem_version.get()
     }
 
+    /// The minor version number of the subsystem.
     #[inline]
     pub const fn minor_subsystem_version(&self) -> u16 {
         self.minor_subsystem_version.get()
     }
 
+    /// Reserved, must be zero.
     #[inline]
     pub const fn win32_version_value(&self) -> u32 {
         self.win32_version_value.get()
     }
 
+    /// The size, in bytes, of the image as it is loaded in memory, including all headers.
     #[inline]
     pub const fn size_of_image(&self) -> u32 {
         self.size_of_image.get()
     }
 
+    /// The combined size, in bytes, of the DOS stub, PE header and section headers, rounded up
+    /// to a multiple of the [file alignment](Self::file_alignment).
     #[inline]
     pub const fn size_of_headers(&self) -> u32 {
         self.size_of_headers.get()
     }
 
+    /// The image file checksum, which is only validated for drivers and DLLs loaded at boot.
     #[inline]
     pub const fn check_sum(&self) -> u32 {
         self.check_sum.get()
     }
 
+    /// The subsystem that is required to run the image, such as `IMAGE_SUBSYSTEM_WINDOWS_GUI`
+    /// (2) or `IMAGE_SUBSYSTEM_WINDOWS_CUI` (3).
+    ///
+    /// [Source](https://learn.microsoft.com/windows/win32/debug/pe-format#windows-subsystem).
     #[inline]
     pub const fn subsystem(&self) -> u16 {
         self.subsystem.get()
     }
 
+    /// The DLL characteristics of the image, such as `IMAGE_DLLCHARACTERISTICS_DYNAMIC_BASE`
+    /// (0x40) or `IMAGE_DLLCHARACTERISTICS_NX_COMPAT` (0x100).
+    ///
+    /// [Source](https://learn.microsoft.com/windows/win32/debug/pe-format#dll-characteristics).
     #[inline]
     pub const fn dll_characteristics(&self) -> u16 {
         self.dll_characteristics.get()
     }
 
+    /// The size of the stack to reserve, in bytes.
     #[inline]
     pub const fn size_of_stack_reserve(&self) -> u64 {
         self.size_of_stack_reserve.get()
     }
 
+    /// The size of the stack to commit, in bytes.
     #[inline]
     pub const fn size_of_stack_commit(&self) -> u64 {
         self.size_of_stack_commit.get()
     }
 
+    /// The size of the local heap space to reserve, in bytes.
     #[inline]
     pub const fn size_of_heap_reserve(&self) -> u64 {
         self.size_of_heap_reserve.get()
     }
 
+    /// The size of the local heap space to commit, in bytes.
     #[inline]
     pub const fn size_of_heap_commit(&self) -> u64 {
         self.size_of_heap_commit.get()
     }
 
+    /// Reserved, must be zero.
     #[inline]
     pub const fn loader_flags(&self) -> u32 {
         self.loader_flags.get()
     }
 
+    /// The number of data directories in the remainder of the optional header.
     #[inline]
     pub const fn number_of_data_directories(&self) -> u32 {
         self.number_of_data_directories.get()
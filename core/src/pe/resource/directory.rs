@@ -1,9 +1,10 @@
 use std::{
     io,
-    io::{Read, Seek},
+    io::{Read, Seek, SeekFrom},
 };
 
-use super::{ResourceDirectoryTable, ResourceType, SectionReader};
+use super::{ImageResourceDataEntry, ResourceDirectoryTable, ResourceType, SectionReader};
+use crate::pe::{PeHeaders, SectionTable};
 
 pub struct ResourceDirectory<R: Read + Seek> {
     reader: SectionReader<R>,
@@ -18,6 +19,35 @@ pub fn new(mut reader: SectionReader<R>) -> io::Result<Self> {
         })
     }
 
+    /// Reads the PE headers from the start of an executable and opens its resource directory,
+    /// returning it along with the executable's section table.
+    pub fn from_executable(mut reader: R) -> io::Result<(Self, SectionTable)> {
+        let headers = PeHeaders::read_from(&mut reader)?;
+
+        // Get the resource table data directory header
+        let resource_table = headers
+            .optional_header()
+            .data_directories
+            .resource_table()
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "No resource table found in executable",
+                )
+            })?;
+
+        // Get the actual file offset of the resource directory section
+        let resource_directory_offset = resource_table.file_offset(headers.section_table())?;
+
+        let section_reader = SectionReader::new(
+            reader,
+            resource_directory_offset.into(),
+            resource_table.size().into(),
+        )?;
+
+        Ok((Self::new(section_reader)?, headers.section_table().clone()))
+    }
+
     #[inline]
     pub const fn reader_mut(&mut self) -> &mut SectionReader<R> {
         &mut self.reader
@@ -41,4 +71,42 @@ pub fn find_directory_table_by_id(&mut self, id: u32) -> io::Result<&ResourceDir
         self.current_directory_table = directory_entry.data(self)?.table().unwrap();
         Ok(&self.current_directory_table)
     }
+
+    /// Finds the data entry of a resource by its type and ID, starting from the root of the
+    /// directory. If `id` is `None`, the first resource of the type is used. The first language of
+    /// the resource is always used.
+    pub fn find_data(
+        &mut self,
+        resource_type: ResourceType,
+        id: Option<u32>,
+    ) -> io::Result<Option<ImageResourceDataEntry>> {
+        self.reader.seek(SeekFrom::Start(0))?;
+        let root = ResourceDirectoryTable::read_from(&mut self.reader)?;
+
+        let Some(type_entry) = root.find_id_entry(resource_type.id()) else {
+            return Ok(None);
+        };
+
+        let Some(types) = type_entry.data(self)?.table() else {
+            return Ok(None);
+        };
+
+        let resource_entry = match id {
+            Some(id) => types.find_id_entry(id),
+            None => types.entries().next(),
+        };
+
+        let Some(resource_entry) = resource_entry else {
+            return Ok(None);
+        };
+
+        let Some(languages) = resource_entry.data(self)?.table() else {
+            return Ok(None);
+        };
+
+        match languages.entries().next() {
+            Some(language_entry) => Ok(language_entry.data(self)?.data()),
+            None => Ok(None),
+        }
+    }
 }
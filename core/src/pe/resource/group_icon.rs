@@ -0,0 +1,309 @@
+use std::{
+    io,
+    io::{Read, Seek, SeekFrom},
+};
+
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, LittleEndian, U16, U32, Unaligned};
+
+use super::{ImageResourceDataEntry, ResourceDirectory, ResourceType};
+use crate::{ReadBytesExt, pe::SectionTable};
+
+/// The header of an icon group resource and of an `.ico` file.
+///
+/// <https://learn.microsoft.com/en-us/previous-versions/ms997538(v=msdn.10)>
+#[derive(
+    Copy, Clone, Debug, Eq, PartialEq, FromBytes, IntoBytes, Immutable, KnownLayout, Unaligned,
+)]
+#[repr(C)]
+struct IconDirectory {
+    reserved: U16<LittleEndian>,
+    r#type: U16<LittleEndian>,
+    count: U16<LittleEndian>,
+}
+
+/// An image in an icon group resource, which refers to an `RT_ICON` resource by its ID.
+#[doc(alias = "GRPICONDIRENTRY")]
+#[derive(
+    Copy, Clone, Debug, Eq, PartialEq, FromBytes, IntoBytes, Immutable, KnownLayout, Unaligned,
+)]
+#[repr(C)]
+struct GroupIconEntry {
+    width: u8,
+    height: u8,
+    color_count: u8,
+    reserved: u8,
+    planes: U16<LittleEndian>,
+    bit_count: U16<LittleEndian>,
+    bytes_in_resource: U32<LittleEndian>,
+    id: U16<LittleEndian>,
+}
+
+/// An image in an `.ico` file, which refers to the image data by its offset in the file.
+#[doc(alias = "ICONDIRENTRY")]
+#[derive(
+    Copy, Clone, Debug, Eq, PartialEq, FromBytes, IntoBytes, Immutable, KnownLayout, Unaligned,
+)]
+#[repr(C)]
+struct IconFileEntry {
+    width: u8,
+    height: u8,
+    color_count: u8,
+    reserved: u8,
+    planes: U16<LittleEndian>,
+    bit_count: U16<LittleEndian>,
+    bytes_in_resource: U32<LittleEndian>,
+    image_offset: U32<LittleEndian>,
+}
+
+/// Reads the first icon group resource from an executable and assembles it into the bytes of an
+/// `.ico` file containing each of its images.
+///
+/// Returns `None` if the executable has no icon group resource.
+pub fn read_icon_file<R>(reader: R) -> io::Result<Option<Vec<u8>>>
+where
+    R: Read + Seek,
+{
+    let (mut resource_directory, section_table) = ResourceDirectory::from_executable(reader)?;
+
+    let Some(group) = resource_directory.find_data(ResourceType::GroupIcon, None)? else {
+        return Ok(None);
+    };
+
+    let group_data = read_data(
+        resource_directory.reader_mut().get_mut(),
+        &section_table,
+        group,
+    )?;
+    let mut group_data = group_data.as_slice();
+
+    let mut images = Vec::new();
+
+    let directory = group_data.read_t::<IconDirectory>()?;
+    for _ in 0..directory.count.get() {
+        let entry = group_data.read_t::<GroupIconEntry>()?;
+        if let Some(image) =
+            resource_directory.find_data(ResourceType::Icon, Some(entry.id.get().into()))?
+        {
+            let data = read_data(
+                resource_directory.reader_mut().get_mut(),
+                &section_table,
+                image,
+            )?;
+            images.push((entry, data));
+        }
+    }
+
+    let mut offset = size_of::<IconDirectory>() + images.len() * size_of::<IconFileEntry>();
+
+    let mut icon_file = IconDirectory {
+        count: U16::new(images.len().try_into().unwrap_or(u16::MAX)),
+        ..directory
+    }
+    .as_bytes()
+    .to_vec();
+
+    let mut image_data = Vec::new();
+    for (entry, data) in images {
+        let file_entry = IconFileEntry {
+            width: entry.width,
+            height: entry.height,
+            color_count: entry.color_count,
+            reserved: entry.reserved,
+            planes: entry.planes,
+            bit_count: entry.bit_count,
+            bytes_in_resource: U32::new(data.len().try_into().unwrap_or(u32::MAX)),
+            image_offset: U32::new(offset.try_into().unwrap_or(u32::MAX)),
+        };
+
+        offset += data.len();
+        icon_file.extend_from_slice(file_entry.as_bytes());
+        image_data.extend(data);
+    }
+
+    icon_file.extend(image_data);
+
+    Ok(Some(icon_file))
+}
+
+/// Reads the data of a resource, whose address is relative to the image base.
+fn read_data<R>(
+    mut reader: R,
+    section_table: &SectionTable,
+    entry: ImageResourceDataEntry,
+) -> io::Result<Vec<u8>>
+where
+    R: Read + Seek,
+{
+    let offset = section_table.to_file_offset(entry.offset_to_data())?;
+    reader.seek(SeekFrom::Start(offset.into()))?;
+
+    let mut data = Vec::new();
+    reader.take(entry.size().into()).read_to_end(&mut data)?;
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use zerocopy::FromBytes;
+
+    use super::{IconDirectory, IconFileEntry, read_icon_file};
+
+    const SUBDIRECTORY: u32 = 1 << 31;
+    const SECTION_OFFSET: usize = 0x200;
+    const SECTION_ADDRESS: u32 = 0x1000;
+
+    /// Writes the bytes at the given offset, growing the buffer if needed.
+    fn put(buffer: &mut Vec<u8>, offset: usize, bytes: &[u8]) {
+        if buffer.len() < offset + bytes.len() {
+            buffer.resize(offset + bytes.len(), 0);
+        }
+        buffer[offset..offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    /// Creates a resource directory table whose entries are each an ID and an offset within the
+    /// resource section.
+    fn table(entries: &[(u32, u32)]) -> Vec<u8> {
+        let mut table = vec![0; 14];
+        table.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+        for (id, offset) in entries {
+            table.extend_from_slice(&id.to_le_bytes());
+            table.extend_from_slice(&offset.to_le_bytes());
+        }
+        table
+    }
+
+    /// Creates a resource data entry for data at the given offset within the resource section.
+    fn data_entry(offset: u32, size: usize) -> Vec<u8> {
+        let mut entry = (SECTION_ADDRESS + offset).to_le_bytes().to_vec();
+        entry.extend_from_slice(&(size as u32).to_le_bytes());
+        entry.extend_from_slice(&[0; 8]);
+        entry
+    }
+
+    /// Creates a 32-bit executable with a single `.rsrc` section holding the given resource
+    /// section.
+    fn executable(resources: &[u8]) -> Vec<u8> {
+        let mut executable = Vec::new();
+        put(&mut executable, 0, b"MZ");
+        put(&mut executable, 0x3C, &0x40u32.to_le_bytes());
+        put(&mut executable, 0x40, b"PE\0\0");
+
+        // The COFF header, with one section and a 224-byte optional header
+        put(&mut executable, 0x44, &0x14Cu16.to_le_bytes());
+        put(&mut executable, 0x46, &1u16.to_le_bytes());
+        put(&mut executable, 0x54, &224u16.to_le_bytes());
+
+        // The optional header, with 16 data directories of which the third is the resource table
+        put(&mut executable, 0x58, &0x10Bu16.to_le_bytes());
+        put(&mut executable, 0x58 + 92, &16u32.to_le_bytes());
+        put(&mut executable, 0x58 + 112, &SECTION_ADDRESS.to_le_bytes());
+        put(
+            &mut executable,
+            0x58 + 116,
+            &(resources.len() as u32).to_le_bytes(),
+        );
+
+        // The section header
+        let section = 0x58 + 224;
+        put(&mut executable, section, b".rsrc");
+        put(
+            &mut executable,
+            section + 8,
+            &(resources.len() as u32).to_le_bytes(),
+        );
+        put(
+            &mut executable,
+            section + 12,
+            &SECTION_ADDRESS.to_le_bytes(),
+        );
+        put(
+            &mut executable,
+            section + 16,
+            &(resources.len() as u32).to_le_bytes(),
+        );
+        put(
+            &mut executable,
+            section + 20,
+            &(SECTION_OFFSET as u32).to_le_bytes(),
+        );
+
+        put(&mut executable, SECTION_OFFSET, resources);
+        executable
+    }
+
+    /// Creates an icon group entry for the `RT_ICON` resource with the given ID.
+    fn group_entry(size: u8, id: u16) -> Vec<u8> {
+        let mut entry = vec![size, size, 0, 0];
+        entry.extend_from_slice(&1u16.to_le_bytes());
+        entry.extend_from_slice(&32u16.to_le_bytes());
+        // The size in the group is ignored in favour of the size of the image resource
+        entry.extend_from_slice(&0u32.to_le_bytes());
+        entry.extend_from_slice(&id.to_le_bytes());
+        entry
+    }
+
+    #[test]
+    fn icon_group_with_two_images() {
+        const FIRST_IMAGE: &[u8] = b"first";
+        const SECOND_IMAGE: &[u8] = b"2nd";
+
+        let mut group = vec![0, 0, 1, 0, 2, 0];
+        group.extend(group_entry(16, 1));
+        group.extend(group_entry(32, 2));
+
+        // The root has the icons and then the icon groups, each of which has one language
+        let mut resources = Vec::new();
+        put(
+            &mut resources,
+            0x00,
+            &table(&[(3, 0x20 | SUBDIRECTORY), (14, 0x40 | SUBDIRECTORY)]),
+        );
+        put(
+            &mut resources,
+            0x20,
+            &table(&[(1, 0x60 | SUBDIRECTORY), (2, 0x78 | SUBDIRECTORY)]),
+        );
+        put(&mut resources, 0x40, &table(&[(1, 0x90 | SUBDIRECTORY)]));
+        put(&mut resources, 0x60, &table(&[(1033, 0xA8)]));
+        put(&mut resources, 0x78, &table(&[(1033, 0xB8)]));
+        put(&mut resources, 0x90, &table(&[(1033, 0xC8)]));
+        put(&mut resources, 0xA8, &data_entry(0xE0, FIRST_IMAGE.len()));
+        put(&mut resources, 0xB8, &data_entry(0xF0, SECOND_IMAGE.len()));
+        put(&mut resources, 0xC8, &data_entry(0x100, group.len()));
+        put(&mut resources, 0xE0, FIRST_IMAGE);
+        put(&mut resources, 0xF0, SECOND_IMAGE);
+        put(&mut resources, 0x100, &group);
+
+        let icon_file = read_icon_file(Cursor::new(executable(&resources)))
+            .unwrap()
+            .unwrap();
+
+        let (directory, rest) = IconDirectory::read_from_prefix(&icon_file).unwrap();
+        assert_eq!(directory.r#type.get(), 1);
+        assert_eq!(directory.count.get(), 2);
+
+        let (entries, _) = <[IconFileEntry; 2]>::read_from_prefix(rest).unwrap();
+
+        // The images follow the directory and its two entries, in the order of the group
+        let header_len = size_of::<IconDirectory>() + 2 * size_of::<IconFileEntry>();
+        assert_eq!(entries[0].width, 16);
+        assert_eq!(entries[0].image_offset.get() as usize, header_len);
+        assert_eq!(
+            entries[0].bytes_in_resource.get() as usize,
+            FIRST_IMAGE.len()
+        );
+        assert_eq!(entries[1].width, 32);
+        assert_eq!(
+            entries[1].image_offset.get() as usize,
+            header_len + FIRST_IMAGE.len()
+        );
+        assert_eq!(
+            entries[1].bytes_in_resource.get() as usize,
+            SECOND_IMAGE.len()
+        );
+
+        assert_eq!(&icon_file[header_len..], b"first2nd");
+    }
+}
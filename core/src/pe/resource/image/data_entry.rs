@@ -1,4 +1,4 @@
-use zerocopy::{FromBytes, Immutable, KnownLayout, LittleEndian, U32};
+use zerocopy::{FromBytes, Immutable, KnownLayout, LittleEndian, U32, Unaligned};
 
 // Each resource data entry describes a leaf node in the resource directory tree. It contains an
 // offset, relative to the beginning of the resource directory of the data for the resource, a size
@@ -6,7 +6,7 @@
 // decoding code point values within the resource data. Typically, for new applications the code
 // page would be the Unicode code page.
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, FromBytes, Immutable, KnownLayout)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, FromBytes, Immutable, KnownLayout, Unaligned)]
 #[repr(C)]
 pub struct ImageResourceDataEntry {
     /// RVA of the data.
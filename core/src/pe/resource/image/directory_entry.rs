@@ -3,7 +3,7 @@
     io::{Read, Seek},
 };
 
-use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, LittleEndian, U32};
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, LittleEndian, U32, Unaligned};
 
 use crate::{
     pe::resource::{
@@ -13,7 +13,9 @@
     read::ReadBytesExt,
 };
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, FromBytes, IntoBytes, Immutable, KnownLayout)]
+#[derive(
+    Copy, Clone, Debug, Eq, PartialEq, FromBytes, IntoBytes, Immutable, KnownLayout, Unaligned,
+)]
 #[repr(C)]
 pub struct ImageResourceDirectoryEntry {
     name_or_id: U32<LittleEndian>,
@@ -1,6 +1,6 @@
-use zerocopy::{FromBytes, Immutable, KnownLayout, LittleEndian, U16, U32};
+use zerocopy::{FromBytes, Immutable, KnownLayout, LittleEndian, U16, U32, Unaligned};
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, FromBytes, Immutable, KnownLayout)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, FromBytes, Immutable, KnownLayout, Unaligned)]
 #[repr(C)]
 pub struct ImageResourceDirectory {
     characteristics: U32<LittleEndian>,
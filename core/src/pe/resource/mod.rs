@@ -1,5 +1,6 @@
 mod directory;
 mod directory_entry_data;
+mod group_icon;
 mod image;
 mod resource_types;
 mod section_reader;
@@ -7,6 +8,7 @@
 use std::io;
 
 pub use directory::ResourceDirectory;
+pub use group_icon::read_icon_file;
 pub use image::{ImageResourceDataEntry, ImageResourceDirectory, ImageResourceDirectoryEntry};
 pub use resource_types::ResourceType;
 pub use section_reader::SectionReader;
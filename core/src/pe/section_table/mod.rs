@@ -3,10 +3,30 @@
 use std::{io, slice, vec};
 
 pub use section_header::SectionHeader;
+use thiserror::Error;
 use zerocopy::{FromZeros, IntoBytes};
 
 use super::CoffHeader;
 
+/// The error returned when a virtual address is not in any section of a [`SectionTable`].
+///
+/// This is the source of the [`io::Error`] returned by [`SectionTable::to_file_offset`].
+#[derive(Clone, Copy, Debug, Error, Eq, PartialEq)]
+#[error("Address {address:#X} not found in any section")]
+pub struct AddressNotInSectionError {
+    address: u32,
+}
+
+impl AddressNotInSectionError {
+    /// Returns the virtual address that was not found.
+    #[must_use]
+    #[inline]
+    pub const fn address(self) -> u32 {
+        self.address
+    }
+}
+
+/// The section headers of a PE executable, which follow the optional header.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[repr(transparent)]
 pub struct SectionTable(Vec<SectionHeader>);
@@ -17,6 +37,11 @@ fn inner(&self) -> &[SectionHeader] {
         &self.0
     }
 
+    /// Reads the section table with the number of sections given by the COFF header.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the section table could not be read.
     pub fn read_from<R>(mut src: R, coff_header: CoffHeader) -> io::Result<Self>
     where
         R: io::Read,
@@ -48,9 +73,22 @@ pub fn to_file_offset(&self, address: u32) -> io::Result<u32> {
 
         Err(io::Error::new(
             io::ErrorKind::InvalidInput,
-            format!("Address {address:#X} not found in any section"),
+            AddressNotInSectionError { address },
         ))
     }
+
+    /// Returns the name of the packer that compressed the executable, if it is recognized by the
+    /// names of its sections, such as `UPX0` and `UPX1` for UPX.
+    ///
+    /// Packers move the sections around, so addresses may not be found in any section.
+    #[must_use]
+    pub fn packer(&self) -> Option<&'static str> {
+        self.into_iter()
+            .find_map(|section| match section.real_name() {
+                "UPX0" | "UPX1" | "UPX2" => Some("UPX"),
+                _ => None,
+            })
+    }
 }
 
 impl<'table> IntoIterator for &'table SectionTable {
@@ -63,3 +101,40 @@ fn into_iter(self) -> Self::IntoIter {
         self.0.iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use zerocopy::{FromZeros, IntoBytes};
+
+    use super::{AddressNotInSectionError, SectionHeader, SectionTable};
+
+    fn section(name: &str) -> SectionHeader {
+        let mut section = SectionHeader::new_zeroed();
+        section.as_mut_bytes()[..name.len()].copy_from_slice(name.as_bytes());
+        section
+    }
+
+    #[rstest]
+    #[case::upx(&["UPX0", "UPX1", ".rsrc"], Some("UPX"))]
+    #[case::unpacked(&[".text", ".data", ".rsrc"], None)]
+    fn packer(#[case] names: &[&str], #[case] expected: Option<&str>) {
+        let section_table = SectionTable(names.iter().map(|name| section(name)).collect());
+
+        assert_eq!(section_table.packer(), expected);
+    }
+
+    #[test]
+    fn address_not_in_section() {
+        let err = SectionTable(vec![section(".text")])
+            .to_file_offset(0x1000)
+            .unwrap_err();
+
+        assert_eq!(
+            err.get_ref()
+                .and_then(|inner| inner.downcast_ref::<AddressNotInSectionError>())
+                .map(|inner| inner.address()),
+            Some(0x1000)
+        );
+    }
+}
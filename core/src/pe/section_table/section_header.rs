@@ -1,8 +1,10 @@
 use std::fmt;
 
-use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, LittleEndian, U16, U32};
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, LittleEndian, U16, U32, Unaligned};
 
-#[derive(Copy, Clone, Eq, PartialEq, FromBytes, IntoBytes, Immutable, KnownLayout)]
+/// A section header, which describes where a section is in the file and in memory.
+#[doc(alias = "IMAGE_SECTION_HEADER")]
+#[derive(Copy, Clone, Eq, PartialEq, FromBytes, IntoBytes, Immutable, KnownLayout, Unaligned)]
 #[repr(C)]
 pub struct SectionHeader {
     name: [u8; 8],
@@ -18,57 +20,69 @@ pub struct SectionHeader {
 }
 
 impl SectionHeader {
+    /// Returns the name of the section, such as `.text`, without its trailing null bytes.
     pub fn real_name(&self) -> &str {
         std::str::from_utf8(&self.name)
             .map(|name| name.trim_end_matches('\0'))
             .unwrap_or_default()
     }
 
+    /// Returns the 8-byte name of the section, padded with null bytes.
     #[inline]
     pub const fn raw_name(&self) -> [u8; 8] {
         self.name
     }
 
+    /// Returns the size of the section when it is loaded into memory.
     #[inline]
     pub const fn virtual_size(&self) -> u32 {
         self.virtual_size.get()
     }
 
+    /// Returns the address of the section relative to the image base when it is loaded into
+    /// memory.
     #[inline]
     pub const fn virtual_address(&self) -> u32 {
         self.virtual_address.get()
     }
 
+    /// Returns the size of the section in the file.
     #[inline]
     pub const fn size_of_raw_data(&self) -> u32 {
         self.size_of_raw_data.get()
     }
 
+    /// Returns the file offset of the section's data.
     #[inline]
     pub const fn pointer_to_raw_data(&self) -> u32 {
         self.pointer_to_raw_data.get()
     }
 
+    /// Returns the file offset of the section's relocation entries, which is zero for images.
     #[inline]
     pub const fn pointer_to_relocations(&self) -> u32 {
         self.pointer_to_relocations.get()
     }
 
+    /// Returns the file offset of the section's COFF line numbers, which are deprecated.
     #[inline]
     pub const fn pointer_to_line_numbers(&self) -> u32 {
         self.pointer_to_line_numbers.get()
     }
 
+    /// Returns the number of relocation entries of the section.
     #[inline]
     pub const fn number_of_relocations(&self) -> u16 {
         self.number_of_relocations.get()
     }
 
+    /// Returns the number of COFF line numbers of the section.
     #[inline]
     pub const fn number_of_line_numbers(&self) -> u16 {
         self.number_of_line_numbers.get()
     }
 
+    /// Returns the characteristics of the section, such as whether it is executable.
     #[inline]
     pub const fn characteristics(&self) -> u32 {
         self.characteristics.get()
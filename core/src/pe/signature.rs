@@ -11,6 +11,11 @@ pub enum Signature {
 }
 
 impl Signature {
+    /// Reads the `PE\0\0` signature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signature could not be read or is invalid.
     pub fn try_read_from_io<R>(mut src: R) -> io::Result<Self>
     where
         Self: Sized,
@@ -22,6 +27,7 @@ pub fn try_read_from_io<R>(mut src: R) -> io::Result<Self>
             .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))
     }
 
+    /// Returns the signature as a string slice.
     pub fn as_str(&self) -> &str {
         std::str::from_utf8(self.as_bytes()).unwrap_or_default()
     }
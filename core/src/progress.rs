@@ -0,0 +1,221 @@
+use std::{
+    fmt,
+    io::{self, Read},
+};
+
+use crate::read::stream::InnoStreamReader;
+
+/// A phase of parsing an installer, reported to the progress callback of
+/// [`Inno::open_with_progress`](crate::Inno::open_with_progress).
+///
+/// Phases are reported in the order that they are parsed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ParsePhase {
+    Header,
+    Languages,
+    Messages,
+    Permissions,
+    Types,
+    Components,
+    Tasks,
+    Directories,
+    ISSigKeys,
+    Files,
+    Icons,
+    Ini,
+    Registry,
+    InstallDelete,
+    UninstallDelete,
+    Run,
+    UninstallRun,
+    Wizard,
+    FileLocations,
+}
+
+impl ParsePhase {
+    /// Returns the parse phase as a static string.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Header => "Header",
+            Self::Languages => "Languages",
+            Self::Messages => "Messages",
+            Self::Permissions => "Permissions",
+            Self::Types => "Types",
+            Self::Components => "Components",
+            Self::Tasks => "Tasks",
+            Self::Directories => "Directories",
+            Self::ISSigKeys => "ISSigKeys",
+            Self::Files => "Files",
+            Self::Icons => "Icons",
+            Self::Ini => "INI",
+            Self::Registry => "Registry",
+            Self::InstallDelete => "InstallDelete",
+            Self::UninstallDelete => "UninstallDelete",
+            Self::Run => "Run",
+            Self::UninstallRun => "UninstallRun",
+            Self::Wizard => "Wizard",
+            Self::FileLocations => "FileLocations",
+        }
+    }
+
+    /// Reads `count` entries from the reader with the given function, reporting progress before
+    /// each entry and once all entries have been read.
+    pub(crate) fn read_entries<R, T, P, F>(
+        self,
+        reader: &mut InnoStreamReader<R>,
+        count: u32,
+        progress: &mut P,
+        mut read: F,
+    ) -> io::Result<Vec<T>>
+    where
+        R: Read,
+        P: FnMut(Self, u32, u32),
+        F: FnMut(&mut InnoStreamReader<R>) -> io::Result<T>,
+    {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("read_entries", phase = self.as_str(), count).entered();
+
+        let entries = (0..count)
+            .map(|index| {
+                progress(self, index, count);
+
+                #[cfg(feature = "tracing")]
+                tracing::trace!(index, position = reader.position(), "reading entry");
+
+                let entry = read(reader);
+
+                #[cfg(feature = "tracing")]
+                if let Err(error) = &entry {
+                    tracing::debug!(index, position = reader.position(), %error, "failed to read entry");
+                }
+
+                entry
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        progress(self, count, count);
+
+        Ok(entries)
+    }
+
+    /// Reads up to `count` entries like [`read_entries`](Self::read_entries), but stops early if
+    /// the stream ends exactly where an entry would start.
+    ///
+    /// This is for the last section of a stream, where a header that declares more entries than
+    /// are stored can be detected, rather than failing with an unexpected end of file. A stream
+    /// that ends partway through an entry is still an error.
+    pub(crate) fn read_entries_until_end<R, T, P, F>(
+        self,
+        reader: &mut InnoStreamReader<R>,
+        count: u32,
+        progress: &mut P,
+        mut read: F,
+    ) -> io::Result<Vec<T>>
+    where
+        R: Read,
+        P: FnMut(Self, u32, u32),
+        F: FnMut(&mut InnoStreamReader<R>) -> io::Result<T>,
+    {
+        let mut entries = Vec::new();
+
+        for index in 0..count {
+            progress(self, index, count);
+
+            let start = reader.position();
+            match read(reader) {
+                Ok(entry) => entries.push(entry),
+                Err(err)
+                    if err.kind() == io::ErrorKind::UnexpectedEof && reader.position() == start =>
+                {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(index, count, "stream ended before all entries were read");
+                    break;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        progress(self, count, count);
+
+        Ok(entries)
+    }
+}
+
+impl fmt::Display for ParsePhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Cursor};
+
+    use rstest::rstest;
+
+    use super::ParsePhase;
+    use crate::{
+        read::stream::InnoStreamReader, test_support::stored_stream, version::InnoVersion,
+    };
+
+    #[test]
+    fn read_entries_reports_progress() {
+        let mut reports = Vec::new();
+        let mut reader = InnoStreamReader::new(
+            Cursor::new(stored_stream(&[1, 2, 3])),
+            InnoVersion::new(5, 5, 0, 0),
+        )
+        .unwrap();
+
+        let entries = ParsePhase::Files
+            .read_entries(
+                &mut reader,
+                3,
+                &mut |phase, done, total| reports.push((phase, done, total)),
+                |reader| io::Read::bytes(reader).next().unwrap(),
+            )
+            .unwrap();
+
+        assert_eq!(entries, [1, 2, 3]);
+        assert_eq!(reader.position(), 3);
+        assert_eq!(
+            reports,
+            [
+                (ParsePhase::Files, 0, 3),
+                (ParsePhase::Files, 1, 3),
+                (ParsePhase::Files, 2, 3),
+                (ParsePhase::Files, 3, 3),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case::all_entries(&[1, 2, 3, 4], 2, Some(vec![[1, 2], [3, 4]]))]
+    #[case::ends_between_entries(&[1, 2, 3, 4], 3, Some(vec![[1, 2], [3, 4]]))]
+    #[case::ends_within_entry(&[1, 2, 3], 2, None)]
+    fn read_entries_until_end(
+        #[case] data: &[u8],
+        #[case] count: u32,
+        #[case] expected: Option<Vec<[u8; 2]>>,
+    ) {
+        let mut reader = InnoStreamReader::new(
+            Cursor::new(stored_stream(data)),
+            InnoVersion::new(5, 5, 0, 0),
+        )
+        .unwrap();
+
+        let entries = ParsePhase::FileLocations.read_entries_until_end(
+            &mut reader,
+            count,
+            &mut |_, _, _| {},
+            |reader| {
+                let mut entry = [0; 2];
+                io::Read::read_exact(reader, &mut entry)?;
+                Ok(entry)
+            },
+        );
+
+        assert_eq!(entries.ok(), expected);
+    }
+}
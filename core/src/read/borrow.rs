@@ -0,0 +1,155 @@
+use std::io::{Error, ErrorKind, Result};
+
+use zerocopy::{FromBytes, Immutable, KnownLayout, Ref, Unaligned};
+
+/// Extends byte slices with methods for borrowing fixed-layout structures directly from the
+/// underlying buffer.
+///
+/// This is the zero-copy counterpart to [`ReadBytesExt::read_t`](super::ReadBytesExt::read_t).
+/// Rather than copying each structure out of a reader, the returned references point into the
+/// original buffer, which avoids copying when analysing installers that are already in memory.
+///
+/// Only types without alignment requirements can be borrowed, as the structures are rarely at
+/// aligned offsets within an installer. This includes the [checksums], [`Color`] and the
+/// encryption salt and nonce, along with the PE structures that have no enum fields, such as the
+/// [`CoffHeader`] and the [`SectionHeader`].
+///
+/// # Examples
+///
+/// ```
+/// use inno::{BorrowBytesExt, entry::checksum::Sha1};
+///
+/// let mut data: &[u8] = &[0xAB; 24];
+///
+/// let sha1 = data.borrow_t::<Sha1>().unwrap();
+///
+/// assert_eq!(sha1, &[0xAB; 20]);
+/// assert_eq!(data.len(), 4);
+/// ```
+///
+/// [checksums]: crate::entry::checksum
+/// [`Color`]: crate::header::Color
+/// [`CoffHeader`]: crate::pe::CoffHeader
+/// [`SectionHeader`]: crate::pe::SectionHeader
+pub trait BorrowBytesExt<'data> {
+    /// Borrows a type that implements [`FromBytes`] from the start of the slice, advancing the
+    /// slice past it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`ErrorKind::UnexpectedEof`] if the slice is shorter than the
+    /// type, in which case the slice is left unchanged.
+    fn borrow_t<T>(&mut self) -> Result<&'data T>
+    where
+        T: FromBytes + KnownLayout + Immutable + Unaligned;
+
+    /// Borrows `count` consecutive values of a type that implements [`FromBytes`] from the start of
+    /// the slice, advancing the slice past them.
+    ///
+    /// This is for tables of fixed-layout structures, such as a PE section table.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`ErrorKind::UnexpectedEof`] if the slice is shorter than `count`
+    /// values, in which case the slice is left unchanged.
+    fn borrow_slice<T>(&mut self, count: usize) -> Result<&'data [T]>
+    where
+        T: FromBytes + Immutable + Unaligned;
+}
+
+impl<'data> BorrowBytesExt<'data> for &'data [u8] {
+    fn borrow_t<T>(&mut self) -> Result<&'data T>
+    where
+        T: FromBytes + KnownLayout + Immutable + Unaligned,
+    {
+        let (value, rest) =
+            Ref::<_, T>::from_prefix(*self).map_err(|_| Error::from(ErrorKind::UnexpectedEof))?;
+        *self = rest;
+        Ok(Ref::into_ref(value))
+    }
+
+    fn borrow_slice<T>(&mut self, count: usize) -> Result<&'data [T]>
+    where
+        T: FromBytes + Immutable + Unaligned,
+    {
+        let (values, rest) = Ref::<_, [T]>::from_prefix_with_elems(*self, count)
+            .map_err(|_| Error::from(ErrorKind::UnexpectedEof))?;
+        *self = rest;
+        Ok(Ref::into_ref(values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::ErrorKind;
+
+    use super::BorrowBytesExt;
+    use crate::{
+        ReadBytesExt,
+        encryption::{EncryptionNonce, KDFSalt},
+        entry::checksum::Sha1,
+        header::Color,
+        pe::{CoffHeader, SectionHeader},
+    };
+
+    #[test]
+    fn borrow_matches_read() {
+        let bytes = (0..=u8::MAX).collect::<Vec<_>>();
+
+        let mut borrowed = &bytes[1..];
+        let mut read = &bytes[1..];
+
+        assert_eq!(
+            borrowed.borrow_t::<EncryptionNonce>().unwrap(),
+            &read.read_t::<EncryptionNonce>().unwrap()
+        );
+        assert_eq!(
+            borrowed.borrow_t::<KDFSalt>().unwrap(),
+            &read.read_t::<KDFSalt>().unwrap()
+        );
+        assert_eq!(
+            borrowed.borrow_t::<Color>().unwrap(),
+            &read.read_t::<Color>().unwrap()
+        );
+        assert_eq!(borrowed, read);
+    }
+
+    #[test]
+    fn borrow_pe_headers() {
+        let bytes = (0..=u8::MAX).cycle().take(256).collect::<Vec<_>>();
+
+        let mut borrowed = &bytes[3..];
+        let mut read = &bytes[3..];
+
+        assert_eq!(
+            borrowed.borrow_t::<CoffHeader>().unwrap(),
+            &read.read_t::<CoffHeader>().unwrap()
+        );
+        let sections = borrowed.borrow_slice::<SectionHeader>(2).unwrap();
+        assert_eq!(sections[0], read.read_t::<SectionHeader>().unwrap());
+        assert_eq!(sections[1], read.read_t::<SectionHeader>().unwrap());
+        assert_eq!(borrowed, read);
+    }
+
+    #[test]
+    fn borrow_slice_too_short() {
+        let mut data: &[u8] = &[0; 39];
+
+        assert_eq!(
+            data.borrow_slice::<Sha1>(2).unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+        assert_eq!(data.len(), 39);
+    }
+
+    #[test]
+    fn too_short() {
+        let mut data: &[u8] = &[0; 15];
+
+        assert_eq!(
+            data.borrow_t::<KDFSalt>().unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+        assert_eq!(data.len(), 15);
+    }
+}
@@ -1,9 +1,14 @@
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// The encryption of a chunk of file data.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 pub enum Encryption {
+    /// The chunk is not encrypted.
     #[default]
     Plaintext,
+    /// ARC4 with an MD5-derived key, used before Inno Setup 5.3.9.
     Arc4Md5,
+    /// ARC4 with a SHA-1-derived key, used from Inno Setup 5.3.9 until 6.4.
     Arc4Sha1,
+    /// XChaCha20, used from Inno Setup 6.4.
     XChaCha20,
 }
 
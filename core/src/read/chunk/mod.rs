@@ -4,6 +4,9 @@
 
 use crate::header::Compression;
 
+/// A chunk of the data stream, which stores the compressed data of one or more files.
+///
+/// See the [`extraction`](crate::extraction) module for how chunks are laid out.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct Chunk {
     pub(crate) first_slice: u32,
@@ -16,6 +19,9 @@ pub struct Chunk {
 }
 
 impl Chunk {
+    /// The length of the `zlb\x1a` magic that precedes the data of each chunk.
+    pub const MAGIC_LEN: u64 = 4;
+
     /// Returns the first disk slice containing this chunk.
     #[must_use]
     #[inline]
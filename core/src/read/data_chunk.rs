@@ -1,6 +1,6 @@
 use std::{
     fmt,
-    io::{self, Error, ErrorKind, Read, Seek, SeekFrom, Take},
+    io::{self, Error, ErrorKind, Read, Take},
 };
 
 use flate2::read::ZlibDecoder;
@@ -79,20 +79,18 @@ fn read_lzma2_dict_size<R: Read>(reader: &mut R) -> io::Result<u32> {
     })
 }
 
-impl<R: Read + Seek> DataChunkReader<R> {
-    /// Open a data chunk for reading.
+impl<R: Read> DataChunkReader<R> {
+    /// Open a data chunk for reading from a reader that is already positioned at the start of the
+    /// chunk.
     ///
-    /// Seeks to `data_offset + chunk.start_offset()` in the reader, validates
-    /// the chunk magic, and sets up the appropriate decompression.
-    pub fn new(mut reader: R, data_offset: u64, chunk: &Chunk) -> InnoResult<Self> {
+    /// Validates the chunk magic and sets up the appropriate decompression. Returns
+    /// [`InnoError::Encrypted`] without reading anything if the chunk is encrypted.
+    pub fn from_reader(mut reader: R, chunk: &Chunk) -> InnoResult<Self> {
         if chunk.is_encrypted() {
             // We can't read encrypted chunks
             return Err(InnoError::Encrypted);
         }
 
-        // Seek to the chunk position in the data stream
-        reader.seek(SeekFrom::Start(data_offset + chunk.start_offset()))?;
-
         // Read and validate the magic
         ZlibID::try_read_from_io(&mut reader)?;
 
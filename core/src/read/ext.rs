@@ -5,23 +5,41 @@
 
 use crate::string::PascalString;
 
-/// Extends [`Read`] with methods for reading numbers. (For `std::io`.)
+/// Extends [`Read`] with methods for reading numbers and the length-prefixed strings used
+/// throughout Inno Setup's data structures.
 ///
-/// Most of the methods defined here have an unconstrained type parameter that
-/// must be explicitly instantiated. Typically, it is instantiated with either
-/// the [`BigEndian`] or [`LittleEndian`] types defined in this crate.
+/// The integer methods have an unconstrained type parameter that must be explicitly instantiated
+/// with a byte order from [`zerocopy`], such as [`LE`] or [`BE`]. Inno Setup stores all of its
+/// integers in little-endian byte order.
 ///
-/// [`BigEndian`]: enum.BigEndian.html
-/// [`LittleEndian`]: enum.LittleEndian.html
-/// [`Read`]: https://doc.rust-lang.org/std/io/trait.Read.html
+/// # Pascal strings
+///
+/// Inno Setup stores strings as a little-endian `u32` byte length followed by that many bytes. A
+/// length of zero represents an absent string, which is read as `None`. The bytes are encoded in
+/// UTF-16LE for Unicode installers, or in the installer's ANSI codepage otherwise. Bytes that are
+/// not valid in the given codepage decode to an empty string.
+///
+/// # Examples
+///
+/// ```
+/// use inno::{ReadBytesExt, zerocopy::LE};
+///
+/// let mut data: &[u8] = b"\x2A\x00\x00\x00\x05\x00\x00\x00Hello";
+///
+/// assert_eq!(data.read_u32::<LE>().unwrap(), 42);
+/// assert_eq!(
+///     data.read_decoded_pascal_string(encoding_rs::WINDOWS_1252).unwrap().as_deref(),
+///     Some("Hello")
+/// );
+/// ```
+///
+/// [`BE`]: zerocopy::BE
 pub trait ReadBytesExt: Read {
     /// Read a type that implements [`FromBytes`] from the underlying reader.
     ///
     /// # Errors
     ///
     /// This method returns the same errors as [`Read::read_exact`].
-    ///
-    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
     fn read_t<T: FromBytes>(&mut self) -> Result<T> {
         T::read_from_io(self)
@@ -35,8 +53,6 @@ fn read_t<T: FromBytes>(&mut self) -> Result<T> {
     /// # Errors
     ///
     /// This method returns the same errors as [`Read::read_exact`].
-    ///
-    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
     fn read_u8(&mut self) -> Result<u8> {
         u8::read_from_io(self)
@@ -47,8 +63,6 @@ fn read_u8(&mut self) -> Result<u8> {
     /// # Errors
     ///
     /// This method returns the same errors as [`Read::read_exact`].
-    ///
-    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
     fn read_u16<T: ByteOrder>(&mut self) -> Result<u16> {
         U16::<T>::read_from_io(self).map(U16::get)
@@ -59,8 +73,6 @@ fn read_u16<T: ByteOrder>(&mut self) -> Result<u16> {
     /// # Errors
     ///
     /// This method returns the same errors as [`Read::read_exact`].
-    ///
-    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
     fn read_i16<T: ByteOrder>(&mut self) -> Result<i16> {
         I16::<T>::read_from_io(self).map(I16::get)
@@ -71,8 +83,6 @@ fn read_i16<T: ByteOrder>(&mut self) -> Result<i16> {
     /// # Errors
     ///
     /// This method returns the same errors as [`Read::read_exact`].
-    ///
-    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
     fn read_u32<T: ByteOrder>(&mut self) -> Result<u32> {
         U32::<T>::read_from_io(self).map(U32::get)
@@ -83,8 +93,6 @@ fn read_u32<T: ByteOrder>(&mut self) -> Result<u32> {
     /// # Errors
     ///
     /// This method returns the same errors as [`Read::read_exact`].
-    ///
-    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
     fn read_i32<T: ByteOrder>(&mut self) -> Result<i32> {
         I32::<T>::read_from_io(self).map(I32::get)
@@ -95,8 +103,6 @@ fn read_i32<T: ByteOrder>(&mut self) -> Result<i32> {
     /// # Errors
     ///
     /// This method returns the same errors as [`Read::read_exact`].
-    ///
-    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
     fn read_u64<T: ByteOrder>(&mut self) -> Result<u64> {
         U64::<T>::read_from_io(self).map(U64::get)
@@ -107,16 +113,19 @@ fn read_u64<T: ByteOrder>(&mut self) -> Result<u64> {
     /// # Errors
     ///
     /// This method returns the same errors as [`Read::read_exact`].
-    ///
-    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
     fn read_i64<T: ByteOrder>(&mut self) -> Result<i64> {
         I64::<T>::read_from_io(self).map(I64::get)
     }
 
-    /// Reads a UCSD Pascal-style string from the underlying reader.
+    /// Reads a UCSD Pascal-style string from the underlying reader without decoding it.
     ///
-    /// Assumes the string is prefixed with a 32-bit length and encoded in the specified codepage.
+    /// Returns `None` if the string's length prefix is zero. See the
+    /// [trait documentation](Self#pascal-strings) for the format.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
     #[inline]
     fn read_pascal_string(&mut self) -> Result<Option<PascalString>> {
         PascalString::read(self)
@@ -124,6 +133,13 @@ fn read_pascal_string(&mut self) -> Result<Option<PascalString>> {
 
     /// Reads a UCSD Pascal-style string from the underlying reader and decodes it using the
     /// specified codepage.
+    ///
+    /// Returns `None` if the string's length prefix is zero. See the
+    /// [trait documentation](Self#pascal-strings) for the format.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
     fn read_decoded_pascal_string(
         &mut self,
         codepage: &'static Encoding,
@@ -133,6 +149,14 @@ fn read_decoded_pascal_string(
             .map(|pascal_string| pascal_string.decoded(codepage).into_string()))
     }
 
+    /// Reads a string of `size` bytes, which has no length prefix, from the underlying reader and
+    /// decodes it using the specified codepage.
+    ///
+    /// Returns `None` if `size` is zero.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
     fn read_sized_decoded_pascal_string(
         &mut self,
         size: u32,
@@ -141,6 +165,14 @@ fn read_sized_decoded_pascal_string(
         Ok(PascalString::read_sized_decoded(self, size, codepage)?.map(PascalString::into_string))
     }
 
+    /// Reads the raw bytes of a UCSD Pascal-style string from the underlying reader.
+    ///
+    /// Unlike [`read_pascal_string`](Self::read_pascal_string), an empty string is returned as an
+    /// empty [`Vec`] rather than `None`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
     fn read_raw_pascal_string(&mut self) -> Result<Vec<u8>> {
         let length = self.read_u32::<LittleEndian>()?;
 
@@ -151,6 +183,10 @@ fn read_raw_pascal_string(&mut self) -> Result<Vec<u8>> {
     }
 
     /// Discards a UCSD Pascal-style string from the underlying reader.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`io::copy`].
     fn discard_pascal_string(&mut self) -> Result<()> {
         let length = self.read_u32::<LE>()?;
 
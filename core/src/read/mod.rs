@@ -1,10 +1,14 @@
 mod block;
+mod borrow;
 pub mod chunk;
 pub mod crc32;
 #[cfg(feature = "extract")]
 pub mod data_chunk;
 mod decoder;
 mod ext;
+#[cfg(feature = "extract")]
+pub mod slice;
 pub mod stream;
 
+pub use borrow::BorrowBytesExt;
 pub use ext::ReadBytesExt;
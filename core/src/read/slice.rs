@@ -0,0 +1,244 @@
+use std::{
+    fmt,
+    io::{self, Read, Seek, SeekFrom},
+};
+
+use zerocopy::LE;
+
+use crate::{
+    ReadBytesExt,
+    error::{InnoError, InnoResult},
+};
+
+/// Magic bytes at the start of each external disk slice file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SliceID {
+    Bits16,
+    Bits32,
+}
+
+impl SliceID {
+    const BITS_16: [u8; 8] = *b"idska16\x1a";
+
+    const BITS_32: [u8; 8] = *b"idska32\x1a";
+
+    pub fn try_read_from_io<R>(mut src: R) -> InnoResult<Self>
+    where
+        R: Read,
+    {
+        let mut magic = [0; 8];
+        src.read_exact(&mut magic)?;
+        match magic {
+            Self::BITS_16 => Ok(Self::Bits16),
+            Self::BITS_32 => Ok(Self::Bits32),
+            _ => Err(InnoError::InvalidSliceMagic(magic)),
+        }
+    }
+
+    /// Returns the magic as a static string slice.
+    #[must_use]
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Bits16 => "idska16\u{1a}",
+            Self::Bits32 => "idska32\u{1a}",
+        }
+    }
+}
+
+impl fmt::Display for SliceID {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+/// A reader for a single slice file, so that slices can be read independently of the type of the
+/// setup executable's reader.
+pub trait SliceSource: Read + Seek {}
+
+impl<T: Read + Seek> SliceSource for T {}
+
+/// A reader over the data stream of a disk-spanned installer, whose data is stored in external
+/// slice files rather than in the setup executable.
+///
+/// Reads that reach the end of a slice continue from the start of the data in the next slice, so
+/// chunks that straddle a slice boundary can be read as one continuous stream.
+pub struct SliceReader<'open, R> {
+    open: Box<dyn FnMut(u32) -> io::Result<R> + 'open>,
+    reader: Option<R>,
+    slice: u32,
+    slice_size: u64,
+    position: u64,
+}
+
+impl<'open, R: Read + Seek> SliceReader<'open, R> {
+    /// The offset of the data within each slice, after the magic and the slice size.
+    const DATA_START: u64 = 12;
+
+    /// Creates a new slice reader that opens each slice with the given closure.
+    pub fn new<F>(open: F) -> Self
+    where
+        F: FnMut(u32) -> io::Result<R> + 'open,
+    {
+        Self {
+            open: Box::new(open),
+            reader: None,
+            slice: 0,
+            slice_size: 0,
+            position: 0,
+        }
+    }
+
+    /// Opens the given slice, validating its header, and positions the reader at the start of its
+    /// data.
+    fn open_slice(&mut self, slice: u32) -> InnoResult<()> {
+        self.reader = None;
+
+        let mut reader = (self.open)(slice)?;
+
+        SliceID::try_read_from_io(&mut reader)?;
+        let slice_size = reader.read_u32::<LE>()?;
+
+        let file_size = reader.seek(SeekFrom::End(0))?;
+        if u64::from(slice_size) > file_size {
+            return Err(InnoError::InvalidSliceSize {
+                slice,
+                expected: slice_size,
+                actual: file_size,
+            });
+        }
+
+        reader.seek(SeekFrom::Start(Self::DATA_START))?;
+
+        self.reader = Some(reader);
+        self.slice = slice;
+        self.slice_size = slice_size.into();
+        self.position = Self::DATA_START;
+
+        Ok(())
+    }
+
+    /// Seeks to the given offset within the given slice, opening the slice if it is not already
+    /// open.
+    pub fn seek(&mut self, slice: u32, offset: u64) -> InnoResult<()> {
+        if self.reader.is_none() || self.slice != slice {
+            self.open_slice(slice)?;
+        }
+
+        if offset < Self::DATA_START {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("offset {offset} is within the header of slice {slice}"),
+            )
+            .into());
+        }
+
+        if offset > self.slice_size {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!("offset {offset} is beyond the end of slice {slice}"),
+            )
+            .into());
+        }
+
+        if let Some(reader) = self.reader.as_mut() {
+            reader.seek(SeekFrom::Start(offset))?;
+        }
+        self.position = offset;
+
+        Ok(())
+    }
+}
+
+impl<R: Read + Seek> Read for SliceReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        // Continue into the next slice once the current one is exhausted
+        if self.position >= self.slice_size {
+            let next = self.slice.checked_add(1).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("slice {} is the last possible slice", self.slice),
+                )
+            })?;
+
+            self.open_slice(next).map_err(|err| match err {
+                InnoError::Io(err) => err,
+                err => io::Error::new(io::ErrorKind::InvalidData, err),
+            })?;
+        }
+
+        let Some(reader) = self.reader.as_mut() else {
+            return Ok(0);
+        };
+
+        let remaining = usize::try_from(self.slice_size - self.position).unwrap_or(usize::MAX);
+        let len = buf.len().min(remaining);
+
+        let read = reader.read(&mut buf[..len])?;
+        self.position += read as u64;
+
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, ErrorKind, Read};
+
+    use super::SliceReader;
+    use crate::error::InnoError;
+
+    fn slice(data: &[u8]) -> Cursor<Vec<u8>> {
+        let mut slice = b"idska32\x1a".to_vec();
+        slice.extend_from_slice(&(12 + data.len() as u32).to_le_bytes());
+        slice.extend_from_slice(data);
+        Cursor::new(slice)
+    }
+
+    #[test]
+    fn read_across_slices() {
+        let slices = [slice(b"abc"), slice(b"def"), slice(b"ghi")];
+        let mut reader = SliceReader::new(|index: u32| Ok(slices[index as usize].clone()));
+
+        reader.seek(0, 13).unwrap();
+
+        let mut data = Vec::new();
+        reader.by_ref().take(7).read_to_end(&mut data).unwrap();
+
+        assert_eq!(data, b"bcdefgh");
+    }
+
+    #[test]
+    fn invalid_magic() {
+        let mut reader = SliceReader::new(|_| Ok(Cursor::new(b"idska64\x1a\x0c\0\0\0".to_vec())));
+
+        assert!(matches!(
+            reader.seek(0, 12),
+            Err(InnoError::InvalidSliceMagic(_))
+        ));
+    }
+
+    #[test]
+    fn seek_into_header() {
+        let mut reader = SliceReader::new(|_| Ok(slice(b"abc")));
+
+        assert!(matches!(
+            reader.seek(0, 11),
+            Err(InnoError::Io(err)) if err.kind() == ErrorKind::InvalidInput
+        ));
+    }
+
+    #[test]
+    fn read_past_last_slice() {
+        let mut reader = SliceReader::new(|_| Ok(slice(b"abc")));
+        reader.seek(u32::MAX, 15).unwrap();
+
+        let err = reader.read(&mut [0; 1]).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+}
@@ -1,4 +1,4 @@
-use std::io::{Error, ErrorKind, Read, Result, Take};
+use std::io::{self, Error, ErrorKind, Read, Result, Seek, SeekFrom, Take};
 
 use zerocopy::LE;
 
@@ -6,7 +6,7 @@
     ReadBytesExt,
     compression::Compression,
     entry::checksum::ChecksumMismatchError,
-    error::InnoError,
+    error::{HeaderStream, InnoError, InnoResult},
     lzma_stream_header::LzmaStreamHeader,
     read::{
         block::{INNO_BLOCK_SIZE, InnoBlockReader},
@@ -20,6 +20,7 @@ pub struct InnoStreamReader<R: Read> {
     inner: Decoder<InnoBlockReader<Take<R>>>,
     compression: Compression,
     inno_version: InnoVersion,
+    position: u64,
 }
 
 impl<R: Read> InnoStreamReader<R> {
@@ -28,6 +29,9 @@ pub fn new(mut inner: R, version: InnoVersion) -> Result<Self> {
 
         let mut chunk_reader = InnoBlockReader::new(inner.take(compression.size()));
 
+        #[cfg(feature = "tracing")]
+        tracing::debug!(?compression, "reading header stream");
+
         Ok(Self {
             inner: match compression {
                 Compression::LZMA1(_) => {
@@ -39,9 +43,31 @@ pub fn new(mut inner: R, version: InnoVersion) -> Result<Self> {
             },
             compression,
             inno_version: version,
+            position: 0,
         })
     }
 
+    /// Reads past a stream without decompressing it, validating the CRC32 checksums of the stream
+    /// header and of each block.
+    ///
+    /// Returns the underlying reader, positioned at the end of the stream.
+    pub fn verify(mut inner: R, version: InnoVersion) -> Result<R> {
+        let compression = Self::read_header(&mut inner, version)?;
+
+        let mut block_reader = InnoBlockReader::new(inner.take(compression.size()));
+
+        io::copy(&mut block_reader, &mut io::sink())?;
+
+        if block_reader.total_in() as u64 != compression.size() {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "Unexpected end of Inno stream",
+            ));
+        }
+
+        Ok(block_reader.into_inner().into_inner())
+    }
+
     fn read_header(reader: &mut R, version: InnoVersion) -> Result<Compression> {
         let expected_crc32 = reader.read_u32::<LE>()?;
 
@@ -94,15 +120,63 @@ fn read_header(reader: &mut R, version: InnoVersion) -> Result<Compression> {
         Ok(compression)
     }
 
-    /// Consumes the stream reader and returns a new one.
-    pub fn reset(self) -> Result<Self> {
+    /// Skips the rest of the stream without decompressing or verifying it.
+    ///
+    /// Returns the underlying reader, positioned at the end of the stream.
+    pub fn skip(self) -> Result<R>
+    where
+        R: Seek,
+    {
+        let take = self
+            .into_inner() // Decoder<InnoBlockReader<Take<R>>>
+            .into_inner() // InnoBlockReader<Take<R>>
+            .into_inner(); // Take<R>
+        let remaining = take.limit();
+
+        let mut reader = take.into_inner();
+        reader.seek(SeekFrom::Current(
+            remaining.try_into().map_err(Error::other)?,
+        ))?;
+        Ok(reader)
+    }
+
+    /// Reads the rest of the stream, returning `true` if every remaining byte is zero.
+    ///
+    /// Some installers pad the end of a stream with zeros, which can be ignored.
+    pub fn skip_zero_padding(&mut self) -> Result<bool> {
+        let mut buffer = [0; INNO_BLOCK_SIZE as usize];
+        loop {
+            match self.read(&mut buffer)? {
+                0 => return Ok(self.is_end_of_stream()),
+                read if buffer[..read].iter().any(|&byte| byte != 0) => return Ok(false),
+                _ => {}
+            }
+        }
+    }
+
+    /// Consumes the stream reader and returns a new one for the secondary header stream, which
+    /// follows straight after this one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InnoError::InvalidStreamHeader`] with the byte offset of the secondary stream if
+    /// its block header could not be read.
+    pub fn reset(self) -> InnoResult<Self>
+    where
+        R: Seek,
+    {
         let version = self.inno_version;
-        let reader = self
+        let mut reader = self
             .into_inner() // Decoder<InnoBlockReader<Take<R>>>
             .into_inner() // InnoBlockReader<Take<R>>
             .into_inner() // Take<R>
             .into_inner(); // R
-        Self::new(reader, version)
+        let offset = reader.stream_position()?;
+        Self::new(reader, version).map_err(|source| InnoError::InvalidStreamHeader {
+            stream: HeaderStream::Secondary,
+            offset,
+            source,
+        })
     }
 
     /// Gets a reference to the underlying reader.
@@ -130,6 +204,13 @@ pub fn into_inner(self) -> Decoder<InnoBlockReader<Take<R>>> {
         self.inner
     }
 
+    /// Returns the number of decompressed bytes that have been read from the stream.
+    #[must_use]
+    #[inline]
+    pub const fn position(&self) -> u64 {
+        self.position
+    }
+
     /// Returns true if the reader is at the end of the stream.
     ///
     /// This means that the number of compressed bytes specified in the stream header has been read.
@@ -141,6 +222,110 @@ pub fn is_end_of_stream(&self) -> bool {
 
 impl<R: Read> Read for InnoStreamReader<R> {
     fn read(&mut self, dest: &mut [u8]) -> Result<usize> {
-        self.inner.read(dest)
+        let read = self.inner.read(dest)?;
+        self.position += read as u64;
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, ErrorKind, Read};
+
+    use rstest::rstest;
+
+    use super::InnoStreamReader;
+    use crate::{
+        error::{HeaderStream, InnoError},
+        test_support::stored_stream,
+        version::InnoVersion,
+    };
+
+    #[rstest]
+    #[case::valid(None, true)]
+    #[case::corrupt_header(Some(4), false)]
+    #[case::corrupt_block(Some(13), false)]
+    fn verify(#[case] corrupt_at: Option<usize>, #[case] expected: bool) {
+        let mut stream = stored_stream(b"Inno Setup");
+        if let Some(index) = corrupt_at {
+            stream[index] ^= 0xFF;
+        }
+
+        let result = InnoStreamReader::verify(Cursor::new(stream), InnoVersion::new(5, 5, 0, 0));
+
+        match result {
+            Ok(_) => assert!(expected),
+            Err(err) => {
+                assert!(!expected);
+                assert_eq!(err.kind(), ErrorKind::InvalidData);
+                assert!(
+                    err.get_ref()
+                        .and_then(|inner| inner.downcast_ref::<InnoError>())
+                        .is_some_and(|inner| matches!(inner, InnoError::ChecksumMismatch { .. }))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn skip() {
+        let version = InnoVersion::new(5, 5, 0, 0);
+
+        let mut streams = stored_stream(b"Inno Setup");
+        streams.extend(stored_stream(b"Secondary"));
+
+        let mut reader = InnoStreamReader::new(Cursor::new(streams), version).unwrap();
+        let mut buf = [0; 4];
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"Inno");
+
+        let mut reader = InnoStreamReader::new(reader.skip().unwrap(), version).unwrap();
+        let mut secondary = Vec::new();
+        reader.read_to_end(&mut secondary).unwrap();
+        assert_eq!(secondary, b"Secondary");
+    }
+
+    #[test]
+    fn reset_invalid_header() {
+        let version = InnoVersion::new(5, 5, 0, 0);
+
+        let mut streams = stored_stream(b"Inno Setup");
+        let offset = streams.len() as u64;
+        streams.extend(stored_stream(b"Secondary"));
+        streams[offset as usize] ^= 0xFF;
+
+        let mut reader = InnoStreamReader::new(Cursor::new(streams), version).unwrap();
+        reader.read_to_end(&mut Vec::new()).unwrap();
+
+        match reader.reset() {
+            Err(InnoError::InvalidStreamHeader {
+                stream: HeaderStream::Secondary,
+                offset: actual,
+                source,
+            }) => {
+                assert_eq!(actual, offset);
+                assert!(
+                    source
+                        .get_ref()
+                        .is_some_and(|inner| inner.is::<InnoError>())
+                );
+            }
+            _ => panic!("expected an invalid secondary stream header"),
+        }
+    }
+
+    #[rstest]
+    #[case::zeros(b"Inno\0\0\0\0", true)]
+    #[case::data(b"Inno\0\0\0\x01", false)]
+    #[case::none(b"Inno", true)]
+    fn skip_zero_padding(#[case] data: &[u8], #[case] expected: bool) {
+        let stream = stored_stream(data);
+
+        let mut reader =
+            InnoStreamReader::new(Cursor::new(stream), InnoVersion::new(5, 5, 0, 0)).unwrap();
+        let mut buf = [0; 4];
+        reader.read_exact(&mut buf).unwrap();
+
+        assert_eq!(reader.skip_zero_padding().unwrap(), expected);
     }
 }
@@ -0,0 +1,129 @@
+use crate::entry::{RegRoot, RegistryEntry};
+
+/// A key in a hierarchical view of the installer's registry entries.
+///
+/// The tree is created by [`Inno::registry_tree`](crate::Inno::registry_tree). Its root node is
+/// unnamed and has a child for each [`RegRoot`] that the installer writes to, which in turn have a
+/// child for each subkey. Each registry entry is attached to the node of its key.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RegistryNode<'inno> {
+    name: String,
+    children: Vec<RegistryNode<'inno>>,
+    entries: Vec<&'inno RegistryEntry>,
+}
+
+impl<'inno> RegistryNode<'inno> {
+    pub(crate) fn new<I>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = &'inno RegistryEntry>,
+    {
+        let mut tree = Self::default();
+
+        for entry in entries {
+            let mut node = tree.child_or_insert(entry.registry_root().as_str());
+
+            for segment in split_key(entry.key().unwrap_or_default()) {
+                node = node.child_or_insert(segment);
+            }
+
+            node.entries.push(entry);
+        }
+
+        tree
+    }
+
+    fn child_or_insert(&mut self, name: &str) -> &mut Self {
+        let index = self
+            .children
+            .iter()
+            .position(|child| child.name.eq_ignore_ascii_case(name))
+            .unwrap_or_else(|| {
+                self.children.push(Self {
+                    name: name.to_owned(),
+                    ..Self::default()
+                });
+                self.children.len() - 1
+            });
+
+        &mut self.children[index]
+    }
+
+    /// Returns the name of the key, which is the name of the [`RegRoot`] for the top-level keys,
+    /// or empty for the root of the tree.
+    #[must_use]
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the subkeys of the key, in the order that they first appear in the installer.
+    #[must_use]
+    #[inline]
+    pub const fn children(&self) -> &[Self] {
+        self.children.as_slice()
+    }
+
+    /// Returns the registry entries whose key is this key.
+    #[must_use]
+    #[inline]
+    pub const fn entries(&self) -> &[&'inno RegistryEntry] {
+        self.entries.as_slice()
+    }
+
+    /// Returns the subkey with the given name, compared case-insensitively as Windows does.
+    #[must_use]
+    pub fn child(&self, name: &str) -> Option<&Self> {
+        self.children
+            .iter()
+            .find(|child| child.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the top-level key for the given registry root.
+    #[must_use]
+    #[inline]
+    pub fn root(&self, root: RegRoot) -> Option<&Self> {
+        self.child(root.as_str())
+    }
+
+    /// Returns `true` if the key has no subkeys and no registry entries.
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.children.is_empty() && self.entries.is_empty()
+    }
+}
+
+/// Splits a registry key into its segments on backslashes, except for backslashes within braces
+/// so that constants such as `{reg:HKLM\Software\App,Path}` are kept as a single segment.
+fn split_key(key: &str) -> impl Iterator<Item = &str> {
+    let mut depth = 0usize;
+    key.split(move |char| {
+        match char {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        char == '\\' && depth == 0
+    })
+    .filter(|segment| !segment.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::split_key;
+
+    #[rstest]
+    #[case(r"Software\My Company\My Program", &["Software", "My Company", "My Program"])]
+    #[case(r"\Software\\App\", &["Software", "App"])]
+    #[case(r"Software\{app}\Key", &["Software", "{app}", "Key"])]
+    #[case(
+        r"{reg:HKLM\Software\App,Path|Default}\Sub",
+        &[r"{reg:HKLM\Software\App,Path|Default}", "Sub"]
+    )]
+    #[case("", &[])]
+    fn split(#[case] key: &str, #[case] expected: &[&str]) {
+        assert_eq!(split_key(key).collect::<Vec<_>>(), expected);
+    }
+}
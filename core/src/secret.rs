@@ -0,0 +1,152 @@
+//! Redaction of sensitive strings, such as passwords and serial numbers, in debug output.
+//!
+//! The [`Debug`](fmt::Debug) output of the header and entries prints `<redacted>` in place of
+//! sensitive strings, so that it can be logged or shared safely. This covers the
+//! [default serial](crate::Header::default_serial) of the header, the
+//! [download password](crate::entry::File::download_password) and
+//! [extract archive password](crate::entry::File::extract_archive_password) of files, and the KDF
+//! salt and password test of the [encryption header](crate::Inno::encryption_header). Empty strings
+//! are not redacted, as they reveal nothing.
+//!
+//! The getters always return the actual values. Tools that need them in debug output can opt in
+//! by wrapping the value in [`Revealed`]:
+//!
+//! ```
+//! use inno::secret::Revealed;
+//!
+//! # let inno: Option<inno::Inno<std::io::Cursor<Vec<u8>>>> = None;
+//! # if let Some(inno) = inno {
+//! let debug = format!("{:?}", Revealed(inno.header()));
+//! # }
+//! ```
+
+use std::{cell::Cell, fmt};
+
+thread_local! {
+    /// Whether a [`Revealed`] value is being formatted on this thread.
+    static REVEALED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Formats the wrapped value with sensitive strings revealed in its [`Debug`](fmt::Debug) output.
+///
+/// Sensitive strings are revealed at any depth within the wrapped value, but only while it is
+/// being formatted through this wrapper. Formatting the value directly, with any flags, always
+/// redacts them.
+#[derive(Clone, Copy)]
+pub struct Revealed<'a, T: ?Sized>(pub &'a T);
+
+impl<T: fmt::Debug + ?Sized> fmt::Debug for Revealed<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let _guard = RevealGuard::new();
+        self.0.fmt(f)
+    }
+}
+
+/// Reveals sensitive strings on this thread until it is dropped, restoring the previous state so
+/// that nested [`Revealed`] values don't end it early.
+struct RevealGuard(bool);
+
+impl RevealGuard {
+    fn new() -> Self {
+        Self(REVEALED.replace(true))
+    }
+}
+
+impl Drop for RevealGuard {
+    fn drop(&mut self) {
+        REVEALED.set(self.0);
+    }
+}
+
+/// Returns `true` if sensitive strings should be revealed, because they are within a [`Revealed`]
+/// value.
+#[inline]
+fn is_revealed() -> bool {
+    REVEALED.get()
+}
+
+/// A sensitive string, which is redacted in [`Debug`](fmt::Debug) output unless
+/// [revealed](Revealed).
+#[derive(Clone, Default, Eq, PartialEq)]
+pub(crate) struct Secret<T>(pub(crate) T);
+
+impl<T: AsRef<str>> Secret<T> {
+    /// Returns the secret as a string slice.
+    #[must_use]
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.0.as_ref()
+    }
+}
+
+impl<T: AsRef<str>> fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.as_str().is_empty() || is_revealed() {
+            self.as_str().fmt(f)
+        } else {
+            f.write_str("<redacted>")
+        }
+    }
+}
+
+/// A sensitive value that isn't a string, such as a salt, which is redacted in
+/// [`Debug`](fmt::Debug) output unless [revealed](Revealed).
+pub(crate) struct SecretValue<T>(pub(crate) T);
+
+impl<T: fmt::Debug> fmt::Debug for SecretValue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if is_revealed() {
+            self.0.fmt(f)
+        } else {
+            f.write_str("<redacted>")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Revealed, Secret, SecretValue};
+
+    #[derive(Debug)]
+    #[expect(dead_code, reason = "only read by Debug")]
+    struct Nested {
+        count: i32,
+        secret: Option<Secret<&'static str>>,
+    }
+
+    #[test]
+    fn redacted_unless_revealed() {
+        let secret = Secret("hunter2");
+
+        assert_eq!(format!("{secret:?}"), "<redacted>");
+        assert_eq!(format!("{:?}", Revealed(&secret)), r#""hunter2""#);
+        assert_eq!(format!("{secret:?}"), "<redacted>");
+        assert_eq!(format!("{secret:-?}"), "<redacted>");
+        assert_eq!(format!("{:?}", Secret("")), r#""""#);
+    }
+
+    #[test]
+    fn value_redacted_unless_revealed() {
+        let secret = SecretValue(0xDEAD_BEEF_u32);
+
+        assert_eq!(format!("{secret:?}"), "<redacted>");
+        assert_eq!(format!("{:?}", Revealed(&secret)), "3735928559");
+    }
+
+    #[test]
+    fn revealed_in_nested_values() {
+        let nested = vec![Nested {
+            count: -1,
+            secret: Some(Secret("hunter2")),
+        }];
+
+        assert_eq!(
+            format!("{:?}", Revealed(&nested)),
+            r#"[Nested { count: -1, secret: Some("hunter2") }]"#
+        );
+        assert_eq!(
+            format!("{:#?}", Revealed(&nested)),
+            format!("{nested:#?}").replace("<redacted>", r#""hunter2""#)
+        );
+    }
+}
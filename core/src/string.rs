@@ -5,6 +5,49 @@
 
 use crate::read::ReadBytesExt;
 
+/// A category of strings in an installer, whose codepage can be overridden with
+/// [`InnoBuilder::force_codepage_for`](crate::InnoBuilder::force_codepage_for).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum StringKind {
+    /// The app metadata and other strings of the [header](crate::Header), such as the app name and
+    /// default directory.
+    Metadata,
+    /// The license text and the info before and after texts of the header.
+    ///
+    /// These are always stored in a codepage, even in Unicode installers, and are decoded as
+    /// Windows-1252 by default.
+    InfoText,
+    /// The strings of the entries, such as the destinations of files and the values of registry
+    /// entries.
+    Entries,
+    /// The values of custom messages, which are otherwise decoded using the codepage of their
+    /// language.
+    Messages,
+}
+
+impl StringKind {
+    /// The number of string kinds.
+    pub const COUNT: usize = 4;
+
+    /// Returns the string kind as a static string.
+    #[must_use]
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Metadata => "Metadata",
+            Self::InfoText => "Info text",
+            Self::Entries => "Entries",
+            Self::Messages => "Messages",
+        }
+    }
+}
+
+impl fmt::Display for StringKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 /// A UCSD Pascal-style string.
 #[derive(Clone, Eq, PartialEq)]
 pub enum PascalString {
@@ -0,0 +1,114 @@
+use crate::{
+    entry::Language,
+    header::{Architecture, EntryCounts, Header},
+    version::InnoVersion,
+};
+
+/// The top-level metadata of an installer, without any of its entries.
+///
+/// This is created by [`Inno::summary`], or by [`Inno::parse_summary_only`] which stops reading
+/// after the header and languages. It is useful for cataloging many installers without holding
+/// every entry in memory.
+///
+/// [`Inno::summary`]: crate::Inno::summary
+/// [`Inno::parse_summary_only`]: crate::Inno::parse_summary_only
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InnoSummary {
+    version: InnoVersion,
+    app_name: Option<String>,
+    app_version: Option<String>,
+    app_publisher: Option<String>,
+    app_id: Option<String>,
+    languages: Vec<String>,
+    architectures_allowed: Architecture,
+    architectures_install_in_64_bit_mode: Architecture,
+    entry_counts: EntryCounts,
+}
+
+impl InnoSummary {
+    pub(crate) fn new(version: InnoVersion, header: &Header, languages: &[Language]) -> Self {
+        Self {
+            version,
+            app_name: header.app_name().map(str::to_owned),
+            app_version: header.app_version().map(str::to_owned),
+            app_publisher: header.app_publisher().map(str::to_owned),
+            app_id: header.app_id().map(str::to_owned),
+            languages: languages
+                .iter()
+                .map(|language| language.name().to_owned())
+                .collect(),
+            architectures_allowed: header.architectures_allowed(),
+            architectures_install_in_64_bit_mode: header.architectures_install_in_64_bit_mode(),
+            entry_counts: header.entry_counts(),
+        }
+    }
+
+    /// Returns the version of Inno Setup that created the installer.
+    #[must_use]
+    #[inline]
+    pub const fn version(&self) -> InnoVersion {
+        self.version
+    }
+
+    /// Returns the name of the application.
+    #[doc(alias = "AppName")]
+    #[must_use]
+    #[inline]
+    pub fn app_name(&self) -> Option<&str> {
+        self.app_name.as_deref()
+    }
+
+    /// Returns the version of the application.
+    #[doc(alias = "AppVersion")]
+    #[must_use]
+    #[inline]
+    pub fn app_version(&self) -> Option<&str> {
+        self.app_version.as_deref()
+    }
+
+    /// Returns the publisher of the application.
+    #[doc(alias = "AppPublisher")]
+    #[must_use]
+    #[inline]
+    pub fn app_publisher(&self) -> Option<&str> {
+        self.app_publisher.as_deref()
+    }
+
+    /// Returns the app ID, which identifies the application in the uninstall registry key.
+    #[doc(alias = "AppId")]
+    #[must_use]
+    #[inline]
+    pub fn app_id(&self) -> Option<&str> {
+        self.app_id.as_deref()
+    }
+
+    /// Returns the internal names of the installer's languages, such as `english`.
+    #[must_use]
+    #[inline]
+    pub const fn languages(&self) -> &[String] {
+        self.languages.as_slice()
+    }
+
+    /// Returns the architectures of the systems that the installer is allowed to install on.
+    #[doc(alias = "ArchitecturesAllowed")]
+    #[must_use]
+    #[inline]
+    pub const fn architectures_allowed(&self) -> Architecture {
+        self.architectures_allowed
+    }
+
+    /// Returns the architectures on which the installer enables 64-bit install mode.
+    #[doc(alias = "ArchitecturesInstallIn64BitMode")]
+    #[must_use]
+    #[inline]
+    pub const fn architectures_install_in_64_bit_mode(&self) -> Architecture {
+        self.architectures_install_in_64_bit_mode
+    }
+
+    /// Returns the number of entries in each section of the installer.
+    #[must_use]
+    #[inline]
+    pub const fn entry_counts(&self) -> EntryCounts {
+        self.entry_counts
+    }
+}
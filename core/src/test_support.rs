@@ -0,0 +1,115 @@
+//! Fixtures shared by the tests of several modules.
+
+use std::io::Cursor;
+
+use crate::{
+    Inno, InnoInner,
+    entry::{File, FileLocation},
+    read::chunk::Chunk,
+};
+#[cfg(feature = "extract")]
+use crate::{
+    entry::{self, Checksum},
+    header::Compression,
+    read::chunk::Encryption,
+};
+
+/// Builds a stored stream, as written by Inno Setup 4.0.9 and later, containing a single block.
+pub(crate) fn stored_stream(data: &[u8]) -> Vec<u8> {
+    let block_crc32 = crc32fast::hash(data).to_le_bytes();
+
+    let mut header = (size_of::<u32>() as u32 + data.len() as u32)
+        .to_le_bytes()
+        .to_vec();
+    header.push(0);
+
+    let mut stream = crc32fast::hash(&header).to_le_bytes().to_vec();
+    stream.extend_from_slice(&header);
+    stream.extend_from_slice(&block_crc32);
+    stream.extend_from_slice(data);
+    stream
+}
+
+/// Creates an installer from the given parsed data, with no data to read.
+pub(crate) fn inno(inner: InnoInner) -> Inno<Cursor<Vec<u8>>> {
+    Inno {
+        reader: Cursor::new(Vec::new()),
+        inner,
+    }
+}
+
+/// Creates an installer with the given files and file locations, and no data.
+pub(crate) fn inno_with_files(
+    files: Vec<File>,
+    file_locations: Vec<FileLocation>,
+) -> Inno<Cursor<Vec<u8>>> {
+    inno(InnoInner {
+        files,
+        file_locations,
+        ..InnoInner::default()
+    })
+}
+
+/// Creates a file location for a byte of data in the chunk at the given offset.
+#[cfg(feature = "extract")]
+pub(crate) fn location_in_chunk(start_offset: u64, encryption: Encryption) -> FileLocation {
+    FileLocation {
+        chunk: Chunk {
+            start_offset,
+            encryption,
+            ..Chunk::default()
+        },
+        file: entry::location::File {
+            size: 1,
+            ..entry::location::File::default()
+        },
+        ..FileLocation::default()
+    }
+}
+
+/// Creates a file whose data is at the given location.
+pub(crate) fn file_at(location: u32) -> File {
+    File {
+        location,
+        ..File::default()
+    }
+}
+
+/// Creates a file location of the given size in a chunk of the given compressed size.
+pub(crate) fn sized_location(start_offset: u64, chunk_size: u64, size: u64) -> FileLocation {
+    FileLocation {
+        chunk: Chunk {
+            start_offset,
+            original_size: chunk_size,
+            ..Chunk::default()
+        },
+        uncompressed_size: size,
+        ..FileLocation::default()
+    }
+}
+
+/// Creates a file location for `data` at the given offset within a stored chunk of the given
+/// size.
+#[cfg(feature = "extract")]
+pub(crate) fn stored_location(
+    start_offset: u64,
+    chunk_size: u64,
+    offset: u64,
+    data: &[u8],
+) -> FileLocation {
+    FileLocation {
+        chunk: Chunk {
+            start_offset,
+            original_size: chunk_size,
+            compression: Compression::Stored,
+            ..Chunk::default()
+        },
+        file: entry::location::File {
+            offset,
+            size: data.len() as u64,
+            checksum: Checksum::new_crc32(crc32fast::hash(data)),
+            ..entry::location::File::default()
+        },
+        ..FileLocation::default()
+    }
+}
@@ -1,11 +1,19 @@
 mod variant;
 pub mod windows_version;
 
-use std::{cmp::Ordering, fmt, io};
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    io,
+};
 
 pub use variant::VersionVariant;
 
-use crate::error::{InnoError, InnoResult};
+use crate::{
+    MAX_SUPPORTED_VERSION, MIN_SUPPORTED_VERSION,
+    error::{InnoError, InnoResult},
+};
 
 #[derive(Clone, Copy, Debug, Default, Eq)]
 pub struct InnoVersion {
@@ -20,6 +28,10 @@ impl InnoVersion {
     /// The raw length of the version string in bytes.
     const RAW_LEN: usize = 1 << 6;
 
+    /// The raw length of the legacy version string of Inno Setup 1.2.10 in bytes, such as
+    /// `i1.2.10--16\x1a`.
+    const LEGACY_LEN: usize = 12;
+
     /// Creates a new `InnoVersion` with the specified major, minor, patch, and revision.
     ///
     /// Inno Setup versions 6.3.0 and newer are always Unicode.
@@ -95,7 +107,13 @@ pub fn read<R>(mut reader: R) -> InnoResult<Self>
     {
         let mut raw_version = [0; Self::RAW_LEN];
 
-        reader.read_exact(&mut raw_version)?;
+        // Legacy version strings are shorter, so the stream starts straight after them
+        reader.read_exact(&mut raw_version[..Self::LEGACY_LEN])?;
+        if let Some(version) = Self::from_legacy_version(&raw_version[..Self::LEGACY_LEN]) {
+            return Ok(version);
+        }
+
+        reader.read_exact(&mut raw_version[Self::LEGACY_LEN..])?;
 
         Self::from_raw_version(&raw_version).ok_or_else(|| {
             InnoError::UnknownVersion(String::from_utf8_lossy(&raw_version).into_owned())
@@ -107,6 +125,10 @@ pub fn from_raw_version(mut raw_version: &[u8]) -> Option<Self> {
         const ISX: &[u8; 3] = b"ISX";
         const INNO_SETUP_EXTENSIONS: &[u8; 21] = b"Inno Setup Extensions";
 
+        if let Some(version) = Self::from_legacy_version(raw_version) {
+            return Some(version);
+        }
+
         // Trim trailing null bytes
         if let Some(null_pos) = raw_version.iter().rposition(|&byte| byte != b'\0') {
             raw_version = &raw_version[..=null_pos];
@@ -167,6 +189,29 @@ pub fn from_raw_version(mut raw_version: &[u8]) -> Option<Self> {
         })
     }
 
+    /// Parses a legacy version string of the form `i1.2.10--16\x1a`, where the number after the
+    /// dashes is the bitness of the installer.
+    fn from_legacy_version(raw_version: &[u8]) -> Option<Self> {
+        let raw_version = raw_version.strip_prefix(b"i")?.strip_suffix(b"\x1a")?;
+
+        let (version, bits) = std::str::from_utf8(raw_version).ok()?.split_once("--")?;
+
+        let mut parts = version.split('.').map(str::parse::<u8>);
+        let (major, minor, patch) = (
+            parts.next()?.ok()?,
+            parts.next()?.ok()?,
+            parts.next()?.ok()?,
+        );
+
+        let variant = match bits {
+            "16" => VersionVariant::BITS_16,
+            "32" => VersionVariant::empty(),
+            _ => return None,
+        };
+
+        Some(Self::new_with_variant(major, minor, patch, 0, variant))
+    }
+
     /// Returns the major version number.
     ///
     /// # Examples
@@ -294,6 +339,102 @@ pub const fn is_16_bit(&self) -> bool {
         self.variant.is_16_bit()
     }
 
+    /// Returns the version in the format that innoextract prints it, such as
+    /// `Inno Setup 6.4.0 (unicode)`.
+    ///
+    /// Unlike [`Display`](fmt::Display), the patch version is always included and each variant is
+    /// written as a parenthesised suffix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inno::version::{InnoVersion, VersionVariant};
+    ///
+    /// assert_eq!(
+    ///     InnoVersion::new(6, 4, 0, 0).setup_version_string(),
+    ///     "Inno Setup 6.4.0 (unicode)"
+    /// );
+    ///
+    /// assert_eq!(
+    ///     InnoVersion::new_with_variant(3, 0, 6, 1, VersionVariant::ISX).setup_version_string(),
+    ///     "Inno Setup 3.0.6.1 (isx)"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn setup_version_string(&self) -> String {
+        let mut version = format!("Inno Setup {}.{}.{}", self.major, self.minor, self.patch);
+
+        if self.revision != 0 {
+            version.push_str(&format!(".{}", self.revision));
+        }
+
+        if self.is_16_bit() {
+            version.push_str(" (16-bit)");
+        }
+
+        if self.is_unicode() {
+            version.push_str(" (unicode)");
+        }
+
+        if self.is_isx() {
+            version.push_str(" (isx)");
+        }
+
+        version
+    }
+
+    /// Returns the oldest innoextract release that is known to extract installers created by this
+    /// version, such as `1.9`.
+    ///
+    /// Returns `None` if no innoextract release is known to support this version. This is
+    /// advisory only, as a later release is recommended where a version was only partially
+    /// supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inno::version::InnoVersion;
+    ///
+    /// assert_eq!(InnoVersion::new(5, 5, 9, 0).recommended_innoextract(), Some("1.6"));
+    /// assert_eq!(InnoVersion::new(6, 1, 2, 0).recommended_innoextract(), Some("1.9"));
+    /// assert_eq!(InnoVersion::new(6, 4, 0, 0).recommended_innoextract(), None);
+    /// ```
+    #[must_use]
+    pub fn recommended_innoextract(&self) -> Option<&'static str> {
+        /// Each innoextract release, paired with the first Inno Setup version that it does not
+        /// support. New releases should be appended in order.
+        const INNOEXTRACT_RELEASES: [(InnoVersion, &str); 5] = [
+            (InnoVersion::new(5, 4, 3, 0), "1.0"),
+            (InnoVersion::new(5, 6, 0, 0), "1.6"),
+            (InnoVersion::new(6, 0, 0, 0), "1.7"),
+            (InnoVersion::new(6, 1, 0, 0), "1.8"),
+            (InnoVersion::new(6, 2, 0, 0), "1.9"),
+        ];
+
+        INNOEXTRACT_RELEASES
+            .iter()
+            .find(|&&(unsupported, _)| *self < unsupported)
+            .map(|&(_, release)| release)
+    }
+
+    /// Returns `true` if the version is within the range of versions supported by this library.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inno::version::InnoVersion;
+    ///
+    /// assert!(InnoVersion::new(6, 4, 0, 0).supported());
+    /// assert!(InnoVersion::new(1, 3, 3, 0).supported());
+    ///
+    /// assert!(!InnoVersion::new(0, 0, 0, 0).supported());
+    /// assert!(!InnoVersion::new(7, 0, 0, 0).supported());
+    /// ```
+    #[must_use]
+    pub fn supported(self) -> bool {
+        (MIN_SUPPORTED_VERSION..=MAX_SUPPORTED_VERSION).contains(&self)
+    }
+
     /// Returns `true` if the version is one that was not incremented since a previous Inno Setup
     /// version and therefore may not actually be the true version.
     #[must_use]
@@ -424,6 +565,22 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+impl From<(u8, u8, u8, u8)> for InnoVersion {
+    /// Creates a new `InnoVersion` from a tuple of (major, minor, patch, revision).
+    ///
+    /// This is equivalent to [`InnoVersion::new`].
+    fn from((major, minor, patch, revision): (u8, u8, u8, u8)) -> Self {
+        Self::new(major, minor, patch, revision)
+    }
+}
+
+impl From<(u8, u8, u8)> for InnoVersion {
+    /// Creates a new `InnoVersion` from a tuple of (major, minor, patch) with a revision of 0.
+    fn from((major, minor, patch): (u8, u8, u8)) -> Self {
+        Self::new(major, minor, patch, 0)
+    }
+}
+
 impl PartialEq for InnoVersion {
     fn eq(&self, other: &Self) -> bool {
         self.major == other.major
@@ -433,6 +590,13 @@ fn eq(&self, other: &Self) -> bool {
     }
 }
 
+impl Hash for InnoVersion {
+    /// Hashes the version numbers, but not the variant, to be consistent with [`PartialEq`].
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.major, self.minor, self.patch, self.revision).hash(state);
+    }
+}
+
 impl PartialOrd for InnoVersion {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -580,7 +744,10 @@ fn partial_cmp(&self, version: &InnoVersion) -> Option<Ordering> {
 
 #[cfg(test)]
 mod tests {
-    use std::cmp::Ordering;
+    use std::{
+        cmp::Ordering,
+        hash::{BuildHasher, RandomState},
+    };
 
     use rstest::rstest;
 
@@ -591,8 +758,45 @@ fn size() {
         assert_eq!(size_of::<InnoVersion>(), 5);
     }
 
+    #[rstest]
+    #[case(b"i1.2.10--16\x1a", true)]
+    #[case(b"i1.2.10--32\x1a", false)]
+    #[case(b"Inno Setup Setup Data (1.3.3)", false)]
+    fn is_16_bit(#[case] raw_version: &[u8], #[case] expected: bool) {
+        let version = InnoVersion::from_raw_version(raw_version).unwrap();
+        assert_eq!(version.is_16_bit(), expected);
+
+        // The stream starts straight after a legacy version string
+        let mut reader = [raw_version, b"stream"].concat();
+        reader.resize(InnoVersion::RAW_LEN, 0);
+        let mut reader = reader.as_slice();
+        assert_eq!(
+            InnoVersion::read(&mut reader).unwrap().is_16_bit(),
+            expected
+        );
+        if raw_version.starts_with(b"i") {
+            assert!(reader.starts_with(b"stream"));
+        }
+    }
+
+    #[test]
+    fn hash_ignores_variant() {
+        let ansi = InnoVersion::new_with_variant(5, 5, 9, 0, VersionVariant::empty());
+        let unicode = InnoVersion::new_with_variant(5, 5, 9, 0, VersionVariant::UNICODE);
+        assert_eq!(ansi, unicode);
+
+        let state = RandomState::new();
+        assert_eq!(state.hash_one(ansi), state.hash_one(unicode));
+        assert_ne!(
+            state.hash_one(ansi),
+            state.hash_one(InnoVersion::new(5, 5, 9, 1))
+        );
+    }
+
     #[rstest]
     #[case(b"", InnoVersion::new(0, 0, 0, 0))]
+    #[case(b"i1.2.10--16\x1a", InnoVersion::new(1, 2, 10, 0))]
+    #[case(b"i1.2.10--32\x1a", InnoVersion::new(1, 2, 10, 0))]
     #[case(b"Inno Setup Setup Data (1.3.3)", InnoVersion::new(1, 3, 3, 0))]
     #[case(
         b"Inno Setup Setup Data (1.3.12) with ISX (1.3.12.1)",
@@ -647,6 +851,62 @@ fn inno_version_from_bytes(#[case] input: &[u8], #[case] expected_inno_version:
         );
     }
 
+    #[rstest]
+    #[case(InnoVersion::new(6, 4, 0, 0), "Inno Setup 6.4.0 (unicode)")]
+    #[case(InnoVersion::new(6, 4, 0, 1), "Inno Setup 6.4.0.1 (unicode)")]
+    #[case(
+        InnoVersion::new_with_variant(5, 5, 7, 0, VersionVariant::UNICODE),
+        "Inno Setup 5.5.7 (unicode)"
+    )]
+    #[case(InnoVersion::new(5, 5, 7, 0), "Inno Setup 5.5.7")]
+    #[case(InnoVersion::new(6, 0, 0, 0), "Inno Setup 6.0.0")]
+    #[case(
+        InnoVersion::new_with_variant(3, 0, 6, 1, VersionVariant::ISX),
+        "Inno Setup 3.0.6.1 (isx)"
+    )]
+    #[case(
+        InnoVersion::new_with_variant(1, 2, 10, 0, VersionVariant::BITS_16),
+        "Inno Setup 1.2.10 (16-bit)"
+    )]
+    fn setup_version_string(#[case] version: InnoVersion, #[case] expected: &str) {
+        assert_eq!(version.setup_version_string(), expected);
+    }
+
+    #[rstest]
+    #[case(InnoVersion::new(1, 2, 10, 0), Some("1.0"))]
+    #[case(InnoVersion::new(5, 4, 2, 0), Some("1.0"))]
+    #[case(InnoVersion::new(5, 5, 0, 0), Some("1.6"))]
+    #[case(InnoVersion::new(5, 6, 1, 0), Some("1.7"))]
+    #[case(InnoVersion::new(6, 0, 5, 0), Some("1.8"))]
+    #[case(InnoVersion::new(6, 1, 2, 0), Some("1.9"))]
+    #[case(InnoVersion::new(6, 2, 0, 0), None)]
+    fn recommended_innoextract(#[case] version: InnoVersion, #[case] expected: Option<&str>) {
+        assert_eq!(version.recommended_innoextract(), expected);
+    }
+
+    #[rstest]
+    #[case(InnoVersion::new(0, 0, 0, 0), false)]
+    #[case(InnoVersion::new(1, 2, 10, 0), false)]
+    #[case(InnoVersion::new(1, 3, 2, 0), false)]
+    #[case(InnoVersion::new(1, 3, 3, 0), true)]
+    #[case(InnoVersion::new(6, 7, 1, 0), true)]
+    #[case(InnoVersion::new(6, 8, 0, 0), false)]
+    fn inno_version_supported(#[case] version: InnoVersion, #[case] expected: bool) {
+        assert_eq!(version.supported(), expected);
+    }
+
+    #[rstest]
+    #[case((5, 5, 7, 1).into(), InnoVersion::new(5, 5, 7, 1))]
+    #[case((5, 5, 7).into(), InnoVersion::new(5, 5, 7, 0))]
+    #[case(
+        (6, 3, 0).into(),
+        InnoVersion::new_with_variant(6, 3, 0, 0, VersionVariant::UNICODE)
+    )]
+    fn inno_version_from_tuple(#[case] version: InnoVersion, #[case] expected: InnoVersion) {
+        assert_eq!(version, expected);
+        assert_eq!(version.variant(), expected.variant());
+    }
+
     #[test]
     fn inno_version_equality() {
         let version = InnoVersion::new(1, 2, 3, 4);
@@ -32,6 +32,36 @@ pub const fn is_isx(&self) -> bool {
     pub const fn is_16_bit(&self) -> bool {
         self.contains(Self::BITS_16)
     }
+
+    /// Returns `true` if the variant does not have a Unicode flag, meaning that strings are
+    /// stored in a codepage.
+    #[must_use]
+    #[inline]
+    pub const fn is_ansi(&self) -> bool {
+        !self.is_unicode()
+    }
+}
+
+impl fmt::Display for VersionVariant {
+    /// Writes each flag of the variant, separated by spaces, such as `16-bit`, `ISX` or `(u)`.
+    ///
+    /// An empty variant is written as an empty string.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = [
+            (self.is_16_bit(), "16-bit"),
+            (self.is_isx(), "ISX"),
+            (self.is_unicode(), "(u)"),
+        ];
+
+        for (index, (_, name)) in names.iter().filter(|(flag, _)| *flag).enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(name)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl fmt::Debug for VersionVariant {
@@ -43,3 +73,20 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::VersionVariant;
+
+    #[rstest]
+    #[case(VersionVariant::empty(), "")]
+    #[case(VersionVariant::UNICODE, "(u)")]
+    #[case(VersionVariant::ISX, "ISX")]
+    #[case(VersionVariant::BITS_16, "16-bit")]
+    #[case(VersionVariant::ISX | VersionVariant::UNICODE, "ISX (u)")]
+    fn display(#[case] variant: VersionVariant, #[case] expected: &str) {
+        assert_eq!(variant.to_string(), expected);
+    }
+}
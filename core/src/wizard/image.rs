@@ -0,0 +1,184 @@
+use std::fmt;
+
+use crate::header::{Color, ImageAlphaFormat};
+
+/// The kind of image embedded in the [Wizard](super::Wizard).
+///
+/// | Kind | Directive | Stored since |
+/// |---|---|---|
+/// | [`Image`](Self::Image) | `WizardImageFile` | 1.x |
+/// | [`SmallImage`](Self::SmallImage) | `WizardSmallImageFile` | 2.0.0 (or ISX) |
+/// | [`BackImage`](Self::BackImage) | `WizardBackImageFile` | 6.7.0 |
+/// | [`ImageDynamicDark`](Self::ImageDynamicDark) | `WizardImageFileDynamicDark` | 6.6.0 |
+/// | [`SmallImageDynamicDark`](Self::SmallImageDynamicDark) | `WizardSmallImageFileDynamicDark` | 6.6.0 |
+/// | [`BackImageDynamicDark`](Self::BackImageDynamicDark) | `WizardBackImageFileDynamicDark` | 6.7.0 |
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum WizardImageKind {
+    Image,
+    SmallImage,
+    BackImage,
+    ImageDynamicDark,
+    SmallImageDynamicDark,
+    BackImageDynamicDark,
+}
+
+impl WizardImageKind {
+    /// Every kind of wizard image, in the order they are stored in the installer.
+    pub const ALL: [Self; 6] = [
+        Self::Image,
+        Self::SmallImage,
+        Self::BackImage,
+        Self::ImageDynamicDark,
+        Self::SmallImageDynamicDark,
+        Self::BackImageDynamicDark,
+    ];
+
+    /// Returns the wizard image kind as a static string.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Image => "Image",
+            Self::SmallImage => "Small image",
+            Self::BackImage => "Back image",
+            Self::ImageDynamicDark => "Image (dynamic dark)",
+            Self::SmallImageDynamicDark => "Small image (dynamic dark)",
+            Self::BackImageDynamicDark => "Back image (dynamic dark)",
+        }
+    }
+}
+
+impl fmt::Display for WizardImageKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// The file format of a wizard image, detected from its leading bytes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ImageFormat {
+    Bmp,
+    Png,
+    Unknown,
+}
+
+impl ImageFormat {
+    const BMP_MAGIC: &'static [u8] = b"BM";
+
+    const PNG_MAGIC: &'static [u8] = b"\x89PNG\r\n\x1A\n";
+
+    /// Detects the image format from the image's bytes.
+    #[must_use]
+    pub fn from_bytes(data: &[u8]) -> Self {
+        if data.starts_with(Self::PNG_MAGIC) {
+            Self::Png
+        } else if data.starts_with(Self::BMP_MAGIC) {
+            Self::Bmp
+        } else {
+            Self::Unknown
+        }
+    }
+
+    /// Returns the conventional file extension for the image format, if known.
+    #[must_use]
+    pub const fn extension(self) -> Option<&'static str> {
+        match self {
+            Self::Bmp => Some("bmp"),
+            Self::Png => Some("png"),
+            Self::Unknown => None,
+        }
+    }
+
+    /// Returns the image format as a static string.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Bmp => "BMP",
+            Self::Png => "PNG",
+            Self::Unknown => "Unknown",
+        }
+    }
+}
+
+impl fmt::Display for ImageFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// A raw image embedded in the [Wizard](super::Wizard), along with the header metadata needed to
+/// display it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WizardImage<'data> {
+    kind: WizardImageKind,
+    data: &'data [u8],
+    back_color: Option<Color>,
+    alpha_format: ImageAlphaFormat,
+}
+
+impl<'data> WizardImage<'data> {
+    pub(crate) const fn new(
+        kind: WizardImageKind,
+        data: &'data [u8],
+        back_color: Option<Color>,
+        alpha_format: ImageAlphaFormat,
+    ) -> Self {
+        Self {
+            kind,
+            data,
+            back_color,
+            alpha_format,
+        }
+    }
+
+    /// Returns the kind of wizard image.
+    #[must_use]
+    #[inline]
+    pub const fn kind(&self) -> WizardImageKind {
+        self.kind
+    }
+
+    /// Returns the raw bytes of the image.
+    #[must_use]
+    #[inline]
+    pub const fn data(&self) -> &'data [u8] {
+        self.data
+    }
+
+    /// Returns the format of the image.
+    #[must_use]
+    #[inline]
+    pub fn format(&self) -> ImageFormat {
+        ImageFormat::from_bytes(self.data)
+    }
+
+    /// Returns the background color used to fill the area around the image, if the image kind has
+    /// one.
+    #[must_use]
+    #[inline]
+    pub const fn back_color(&self) -> Option<Color> {
+        self.back_color
+    }
+
+    /// Returns how the alpha channel of a 32-bit BMP image should be interpreted.
+    #[must_use]
+    #[inline]
+    pub const fn alpha_format(&self) -> ImageAlphaFormat {
+        self.alpha_format
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::ImageFormat;
+
+    #[rstest]
+    #[case(b"BM\x36\x00\x00\x00", ImageFormat::Bmp)]
+    #[case(b"\x89PNG\r\n\x1A\n\x00\x00\x00\x0DIHDR", ImageFormat::Png)]
+    #[case(b"", ImageFormat::Unknown)]
+    #[case(b"GIF89a", ImageFormat::Unknown)]
+    fn image_format(#[case] data: &[u8], #[case] expected: ImageFormat) {
+        assert_eq!(ImageFormat::from_bytes(data), expected);
+    }
+}
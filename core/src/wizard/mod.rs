@@ -1,5 +1,8 @@
+mod image;
+
 use std::{fmt, io};
 
+pub use image::{ImageFormat, WizardImage, WizardImageKind};
 use zerocopy::LE;
 
 use super::{
@@ -8,6 +11,24 @@
     version::InnoVersion,
 };
 
+/// The images and DLLs that Setup needs to show its wizard, which are stored in the primary header
+/// stream.
+///
+/// The wizard's pages and captions aren't stored here. Setup builds its pages itself, and their
+/// text comes from the messages compiled into the [data](crate::entry::Language::data) of each
+/// language.
+///
+/// The format differs between versions:
+///
+/// - Before Inno Setup 4.0.0, the wizard is stored directly after the header and languages, before
+///   any of the entries. From 4.0.0, it is stored after the last entry section.
+/// - Before Inno Setup 5.6.0, at most one image of each kind is stored. Newer versions may store
+///   several images of each kind so that Setup can pick the best match for the display's DPI.
+/// - Small images are stored from Inno Setup 2.0.0, dynamic dark images from 6.6.0, and back
+///   images from 6.7.0. The images of kinds that a version doesn't store are empty.
+///
+/// See [`Inno::wizard_images`](crate::Inno::wizard_images) for the images along with their
+/// background colors and alpha formats.
 #[derive(Default)]
 pub struct Wizard {
     images: Vec<Vec<u8>>,
@@ -128,6 +149,31 @@ pub fn back_images_dynamic_dark(&self) -> &[Vec<u8>] {
         &self.back_images_dynamic_dark
     }
 
+    /// Returns the images of the given kind used in the [Wizard].
+    ///
+    /// Before Inno Setup 5.6.0, at most one image of each kind is stored. Newer versions may store
+    /// several images of each kind so that Setup can pick the best match for the display's DPI.
+    #[must_use]
+    pub fn images_of(&self, kind: WizardImageKind) -> &[Vec<u8>] {
+        match kind {
+            WizardImageKind::Image => self.images(),
+            WizardImageKind::SmallImage => self.small_images(),
+            WizardImageKind::BackImage => self.back_images(),
+            WizardImageKind::ImageDynamicDark => self.images_dynamic_dark(),
+            WizardImageKind::SmallImageDynamicDark => self.small_images_dynamic_dark(),
+            WizardImageKind::BackImageDynamicDark => self.back_images_dynamic_dark(),
+        }
+    }
+
+    /// Returns the total number of images of every kind.
+    #[must_use]
+    pub fn image_count(&self) -> usize {
+        WizardImageKind::ALL
+            .into_iter()
+            .map(|kind| self.images_of(kind).len())
+            .sum()
+    }
+
     /// Returns the decompressor DLL, if present.
     #[must_use]
     #[inline]
@@ -188,3 +234,62 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use rstest::rstest;
+
+    use super::{Wizard, WizardImageKind};
+    use crate::{Inno, InnoInner, test_support::inno};
+
+    /// Creates an installer with two images, one small image and one dynamic dark back image.
+    fn inno_with_images() -> Inno<Cursor<Vec<u8>>> {
+        inno(InnoInner {
+            wizard: Wizard {
+                images: vec![b"image 1".to_vec(), b"image 2".to_vec()],
+                small_images: vec![b"small".to_vec()],
+                back_images_dynamic_dark: vec![b"back".to_vec()],
+                ..Wizard::default()
+            },
+            ..InnoInner::default()
+        })
+    }
+
+    #[rstest]
+    #[case(WizardImageKind::Image, &["image 1", "image 2"])]
+    #[case(WizardImageKind::SmallImage, &["small"])]
+    #[case(WizardImageKind::BackImage, &[])]
+    #[case(WizardImageKind::ImageDynamicDark, &[])]
+    #[case(WizardImageKind::SmallImageDynamicDark, &[])]
+    #[case(WizardImageKind::BackImageDynamicDark, &["back"])]
+    fn images_of(#[case] kind: WizardImageKind, #[case] expected: &[&str]) {
+        assert_eq!(
+            inno_with_images().wizard().images_of(kind),
+            expected
+                .iter()
+                .map(|image| image.as_bytes())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn image_count() {
+        let inno = inno_with_images();
+
+        assert_eq!(inno.wizard().image_count(), 4);
+        assert_eq!(inno.wizard_images().count(), 4);
+        assert_eq!(
+            inno.wizard_images()
+                .map(|image| (image.kind(), image.back_color().is_some()))
+                .collect::<Vec<_>>(),
+            [
+                (WizardImageKind::Image, true),
+                (WizardImageKind::Image, true),
+                (WizardImageKind::SmallImage, true),
+                (WizardImageKind::BackImageDynamicDark, false),
+            ]
+        );
+    }
+}
@@ -0,0 +1,106 @@
+//! Counts the allocations made reading the strings of a header into owned strings, as
+//! [`Header::read`](inno::Header::read) does, compared with borrowing them with [`HeaderRef`].
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    io::Cursor,
+};
+
+use encoding_rs::{UTF_16LE, WINDOWS_1252};
+use inno::{
+    ReadBytesExt,
+    header::{HeaderRef, HeaderString},
+    version::{InnoVersion, VersionVariant},
+};
+
+/// Counts the allocations made on each thread, so that tests running in parallel don't affect
+/// each other's counts.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+// SAFETY: Every method forwards to the system allocator.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::alloc`.
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::dealloc`.
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::realloc`.
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the value returned by `f` and the number of allocations it made on this thread.
+fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.get();
+    let value = f();
+    (value, ALLOCATIONS.get() - before)
+}
+
+const VERSION: InnoVersion = InnoVersion::new_with_variant(6, 7, 0, 0, VersionVariant::UNICODE);
+
+/// Builds the string section of a 6.7 Unicode header, where each field's value is its directive
+/// name.
+fn string_section() -> Vec<u8> {
+    let mut data = Vec::new();
+    for field in HeaderString::layout(VERSION) {
+        let value = if field.is_always_ansi() {
+            field.as_str().as_bytes().to_vec()
+        } else {
+            field
+                .as_str()
+                .encode_utf16()
+                .flat_map(u16::to_le_bytes)
+                .collect()
+        };
+        data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        data.extend_from_slice(&value);
+    }
+    data
+}
+
+#[test]
+fn header_strings() {
+    let data = string_section();
+    let fields = HeaderString::layout(VERSION).count();
+
+    // Each string is read into a buffer and then decoded into a new string
+    let mut owned = Vec::with_capacity(fields);
+    let ((), owned_allocations) = count_allocations(|| {
+        let mut reader = Cursor::new(data.as_slice());
+        for field in HeaderString::layout(VERSION) {
+            let codepage = if field.is_always_ansi() {
+                WINDOWS_1252
+            } else {
+                UTF_16LE
+            };
+            owned.push(reader.read_decoded_pascal_string(codepage).unwrap());
+        }
+    });
+
+    let (borrowed, borrowed_allocations) =
+        count_allocations(|| HeaderRef::read(&mut data.as_slice(), VERSION).unwrap());
+
+    assert_eq!(owned_allocations, fields * 2);
+    assert_eq!(borrowed_allocations, 0);
+
+    // Decoding a UTF-16 string to UTF-8 still allocates, but only for the fields that are read
+    let (app_name, decode_allocations) = count_allocations(|| borrowed.app_name());
+    assert_eq!(app_name.as_deref(), owned[0].as_deref());
+    assert_eq!(decode_allocations, 1);
+}
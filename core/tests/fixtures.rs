@@ -0,0 +1,160 @@
+//! Parses directories of fixture installers, such as one installer per supported range of Inno
+//! Setup versions, to catch regressions when the version-specific parsing is changed.
+//!
+//! Each directory has a `manifest.txt` with the expected version, app name, language count and
+//! file count of each `.exe` in it. Every installer must have a line in the manifest, and its
+//! [summary](Inno::summary) must be the same when only the summary is parsed. The fixtures
+//! committed in `tests/fixtures` are always checked. A directory of further fixtures, such as real
+//! installers that can't be committed, can be given by the `INNO_FIXTURES` environment variable:
+//!
+//! ```sh
+//! INNO_FIXTURES=path/to/fixtures cargo test -p inno --test fixtures -- --ignored
+//! ```
+//!
+//! If a `<name>.exe.debug` snapshot exists alongside an installer, the installer's
+//! [debug output](Inno::write_debug) must match it. Set `INNO_UPDATE_SNAPSHOTS` to write the
+//! snapshots instead.
+
+use std::{
+    collections::BTreeMap,
+    env,
+    error::Error,
+    fs::{self, File},
+    io::BufReader,
+    path::{Path, PathBuf},
+};
+
+use inno::{Inno, InnoBuilder};
+
+/// The expected values of a fixture installer, from a line of the manifest.
+#[derive(Debug, Eq, PartialEq)]
+struct Expected {
+    version: String,
+    app_name: String,
+    languages: usize,
+    files: usize,
+}
+
+/// Reads the manifest of the given directory, returning the expected values by file name.
+fn read_manifest(directory: &Path) -> Result<BTreeMap<String, Expected>, Box<dyn Error>> {
+    let manifest = fs::read_to_string(directory.join("manifest.txt"))?;
+
+    manifest
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let fields = line.split('|').map(str::trim).collect::<Vec<_>>();
+            let [name, version, app_name, languages, files] = fields.as_slice() else {
+                return Err(format!("expected 5 fields in manifest line: {line}").into());
+            };
+
+            Ok((
+                (*name).to_owned(),
+                Expected {
+                    version: (*version).to_owned(),
+                    app_name: (*app_name).to_owned(),
+                    languages: languages.parse()?,
+                    files: files.parse()?,
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Parses the installer at the given path, checking it against its expected values and its
+/// snapshot if one exists.
+fn check_fixture(
+    path: &Path,
+    expected: &Expected,
+    update_snapshots: bool,
+) -> Result<(), Box<dyn Error>> {
+    let inno = Inno::new(BufReader::new(File::open(path)?))?;
+
+    let actual = Expected {
+        version: inno.version().to_string(),
+        app_name: inno.header().app_name().unwrap_or_default().to_owned(),
+        languages: inno.languages().len(),
+        files: inno.file_entries().len(),
+    };
+    if &actual != expected {
+        return Err(format!("expected {expected:?} but parsed {actual:?}").into());
+    }
+
+    let summary = InnoBuilder::new().build_summary(BufReader::new(File::open(path)?))?;
+    if summary != inno.summary() {
+        return Err(format!("summary only parsed {summary:?}").into());
+    }
+
+    let mut debug = Vec::new();
+    inno.write_debug(&mut debug)?;
+
+    let mut snapshot_path = path.as_os_str().to_owned();
+    snapshot_path.push(".debug");
+    let snapshot_path = PathBuf::from(snapshot_path);
+
+    if update_snapshots {
+        fs::write(snapshot_path, debug)?;
+    } else if snapshot_path.exists() && fs::read(&snapshot_path)? != debug {
+        return Err(format!("debug output differs from {}", snapshot_path.display()).into());
+    }
+
+    Ok(())
+}
+
+/// Checks every installer in the given directory against its manifest.
+fn check_directory(directory: &Path) -> Result<(), Box<dyn Error>> {
+    let update_snapshots = env::var_os("INNO_UPDATE_SNAPSHOTS").is_some();
+    let mut manifest = read_manifest(directory)?;
+
+    let mut paths = fs::read_dir(directory)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    paths.retain(|path| {
+        path.extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("exe"))
+    });
+    paths.sort();
+
+    let mut failed = 0;
+    for path in &paths {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let result = manifest
+            .remove(name.as_ref())
+            .ok_or_else(|| "no expected values in manifest.txt".into())
+            .and_then(|expected| check_fixture(path, &expected, update_snapshots));
+
+        match result {
+            Ok(()) => println!("pass {}", path.display()),
+            Err(err) => {
+                println!("FAIL {}: {err}", path.display());
+                failed += 1;
+            }
+        }
+    }
+
+    for name in manifest.keys() {
+        println!("FAIL {name}: listed in manifest.txt but not found");
+        failed += 1;
+    }
+
+    assert_eq!(failed, 0, "{failed} fixtures failed");
+
+    Ok(())
+}
+
+#[test]
+fn fixtures() -> Result<(), Box<dyn Error>> {
+    check_directory(&Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures"))
+}
+
+#[test]
+#[ignore]
+fn external_fixtures() -> Result<(), Box<dyn Error>> {
+    let Some(directory) = env::var_os("INNO_FIXTURES") else {
+        eprintln!("Skipping external fixtures as INNO_FIXTURES is not set");
+        return Ok(());
+    };
+
+    check_directory(Path::new(&directory))
+}
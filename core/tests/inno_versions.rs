@@ -208,3 +208,37 @@ fn inno_6_7_1() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[test]
+#[ignore]
+fn summary_only() -> Result<(), Box<dyn Error>> {
+    let inno_bytes = download_inno_version("6.7.1")?;
+
+    let summary = Inno::parse_summary_only(Cursor::new(inno_bytes.clone()))?;
+    let inno = Inno::new(Cursor::new(inno_bytes))?;
+
+    assert_eq!(summary, inno.summary());
+    assert_eq!(
+        summary.entry_counts().file(),
+        inno.file_entries().len() as u32
+    );
+
+    Ok(())
+}
+
+#[test]
+#[ignore]
+fn stream_ranges() -> Result<(), Box<dyn Error>> {
+    let inno_bytes = download_inno_version("6.7.1")?;
+    let len = inno_bytes.len() as u64;
+    let inno = Inno::new(Cursor::new(inno_bytes))?;
+
+    // The data stream is written straight after the header streams
+    assert_eq!(
+        inno.header_stream_range().end,
+        inno.data_stream_range().start
+    );
+    assert!(inno.data_stream_range().end <= len);
+
+    Ok(())
+}